@@ -0,0 +1,231 @@
+// Copyright 2019 The Exonum Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Benchmarks of the iteration over a map index through the native functions of its iterators.
+//!
+//! The benchmarks compare fetching the elements in batches of up to `MAX_BATCH_SIZE` elements
+//! with fetching them one at a time, i.e., in batches of a single element. They call
+//! the native functions directly from Rust, therefore, they measure the cost of the iteration
+//! and of the creation of the Java arrays, but not the cost of the Java-to-native transitions,
+//! which the batching primarily saves, nor the decoding of the elements in Java.
+
+#![feature(test)]
+
+extern crate integration_tests;
+extern crate java_bindings;
+#[macro_use]
+extern crate lazy_static;
+extern crate test;
+
+use integration_tests::vm::create_vm_for_benchmarks_with_fakes;
+use java_bindings::{
+    jni::{
+        objects::{JClass, JObject},
+        sys::jint,
+        JNIEnv, JavaVM,
+    },
+    Executor, Handle, Java_com_exonum_binding_core_storage_database_TemporaryDb_nativeCreate,
+    Java_com_exonum_binding_core_storage_database_TemporaryDb_nativeCreateFork,
+    Java_com_exonum_binding_core_storage_database_TemporaryDb_nativeFree,
+    Java_com_exonum_binding_core_storage_database_Views_nativeFree,
+    Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeCreate,
+    Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeCreateEntriesIter,
+    Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeCreateKeysIter,
+    Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeEntriesIterFree,
+    Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeEntriesIterNextBatch,
+    Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeFree,
+    Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeKeysIterFree,
+    Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeKeysIterNextBatch,
+    Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativePut,
+};
+
+use std::sync::Arc;
+use test::{black_box, Bencher};
+
+lazy_static! {
+    pub static ref VM: Arc<JavaVM> = create_vm_for_benchmarks_with_fakes();
+    pub static ref EXECUTOR: Executor = Executor::new(VM.clone());
+}
+
+const MAP_SIZE: u32 = 10_000;
+const VALUE_SIZE: usize = 64;
+const MAX_BATCH_SIZE: jint = 256;
+const MAX_BATCH_BYTES: jint = 512 * 1024;
+
+/// A map index of `MAP_SIZE` entries in a fork of a temporary database.
+struct TestMap {
+    db_handle: Handle,
+    view_handle: Handle,
+    map_handle: Handle,
+}
+
+impl TestMap {
+    fn new(env: &JNIEnv) -> Self {
+        let db_handle =
+            Java_com_exonum_binding_core_storage_database_TemporaryDb_nativeCreate(*env, class());
+        let view_handle =
+            Java_com_exonum_binding_core_storage_database_TemporaryDb_nativeCreateFork(
+                *env,
+                JObject::null(),
+                db_handle,
+            );
+        let name = env.new_string("test_map").unwrap();
+        let map_handle = Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeCreate(
+            *env,
+            class(),
+            name,
+            view_handle,
+        );
+        for i in 0..MAP_SIZE {
+            let key = env.byte_array_from_slice(&i.to_be_bytes()).unwrap();
+            let value = env.byte_array_from_slice(&[i as u8; VALUE_SIZE]).unwrap();
+            Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativePut(
+                *env,
+                JObject::null(),
+                map_handle,
+                key,
+                value,
+            );
+            env.delete_local_ref(key.into()).unwrap();
+            env.delete_local_ref(value.into()).unwrap();
+        }
+        TestMap {
+            db_handle,
+            view_handle,
+            map_handle,
+        }
+    }
+
+    fn free(self, env: &JNIEnv) {
+        Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeFree(
+            *env,
+            class(),
+            self.map_handle,
+        );
+        Java_com_exonum_binding_core_storage_database_Views_nativeFree(
+            *env,
+            class(),
+            self.view_handle,
+        );
+        Java_com_exonum_binding_core_storage_database_TemporaryDb_nativeFree(
+            *env,
+            class(),
+            self.db_handle,
+        );
+    }
+}
+
+fn class<'a>() -> JClass<'a> {
+    JClass::from(JObject::null())
+}
+
+// Iterates over the entries, taking a batch of at most `max_count` keys and values
+// per native call.
+fn iterate_entries(env: &JNIEnv, map_handle: Handle, max_count: jint) -> usize {
+    let iter = Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeCreateEntriesIter(
+        *env,
+        JObject::null(),
+        map_handle,
+    );
+    let mut count = 0;
+    loop {
+        let batch =
+            Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeEntriesIterNextBatch(
+                *env,
+                JObject::null(),
+                iter,
+                max_count,
+                MAX_BATCH_BYTES,
+            );
+        let length = env.get_array_length(batch).unwrap();
+        env.delete_local_ref(batch.into()).unwrap();
+        if length == 0 {
+            break;
+        }
+        count += length as usize / 2;
+    }
+    Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeEntriesIterFree(
+        *env,
+        JObject::null(),
+        iter,
+    );
+    count
+}
+
+// Iterates over the keys, taking a batch of at most `max_count` keys per native call.
+fn iterate_keys(env: &JNIEnv, map_handle: Handle, max_count: jint) -> usize {
+    let iter = Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeCreateKeysIter(
+        *env,
+        JObject::null(),
+        map_handle,
+    );
+    let mut count = 0;
+    loop {
+        let batch =
+            Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeKeysIterNextBatch(
+                *env,
+                JObject::null(),
+                iter,
+                max_count,
+                MAX_BATCH_BYTES,
+            );
+        let length = env.get_array_length(batch).unwrap();
+        env.delete_local_ref(batch.into()).unwrap();
+        if length == 0 {
+            break;
+        }
+        count += length as usize;
+    }
+    Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeKeysIterFree(
+        *env,
+        JObject::null(),
+        iter,
+    );
+    count
+}
+
+fn bench_scan(b: &mut Bencher, scan: fn(&JNIEnv, Handle, jint) -> usize, max_count: jint) {
+    EXECUTOR
+        .with_attached(|env| {
+            let map = TestMap::new(env);
+            b.iter(|| {
+                let count = scan(env, map.map_handle, max_count);
+                assert_eq!(count, MAP_SIZE as usize);
+                black_box(count)
+            });
+            map.free(env);
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[bench]
+pub fn map_entries_scan(b: &mut Bencher) {
+    bench_scan(b, iterate_entries, 1);
+}
+
+#[bench]
+pub fn map_entries_scan_batched(b: &mut Bencher) {
+    bench_scan(b, iterate_entries, MAX_BATCH_SIZE);
+}
+
+#[bench]
+pub fn map_keys_scan(b: &mut Bencher) {
+    bench_scan(b, iterate_keys, 1);
+}
+
+#[bench]
+pub fn map_keys_scan_batched(b: &mut Bencher) {
+    bench_scan(b, iterate_keys, MAX_BATCH_SIZE);
+}
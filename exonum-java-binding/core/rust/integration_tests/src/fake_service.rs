@@ -12,15 +12,82 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use exonum_testkit::{TestKit, TestKitBuilder};
 use java_bindings::{
+    create_service_runtime,
+    exonum::{
+        blockchain::config::InstanceInitParams,
+        runtime::{ArtifactId, InstanceId, InstanceSpec},
+    },
     jni::objects::{JObject, JValue},
     utils::{panic_on_exception, unwrap_jni},
-    Executor,
+    Executor, JavaRuntimeProxy, RuntimeConfig, JAVA_RUNTIME_ID,
 };
 use tempfile::{self, TempPath};
+use vm::{deploy_arguments, fake_service_artifact_name, fake_service_artifact_path, log4j_path};
+
+use std::path::Path;
 
 const NATIVE_FACADE_CLASS: &str = "com/exonum/binding/fakes/NativeFacade";
 
+/// The id of the fake service instance created by `create_testkit_with_fake_service`.
+pub const FAKE_SERVICE_ID: InstanceId = 100;
+const FAKE_SERVICE_NAME: &str = "fake-service";
+
+/// Returns the configuration of a Java runtime loading the artifacts from the directory
+/// of the fake service artifact.
+pub fn fake_service_runtime_config() -> RuntimeConfig {
+    let artifact_path = fake_service_artifact_path();
+    RuntimeConfig {
+        artifacts_path: Path::new(&artifact_path).parent().unwrap().to_owned(),
+        log_config_path: log4j_path(),
+        // Let the runtime pick a free port
+        port: 0,
+        override_system_lib_path: None,
+        deploy_timeout: None,
+        metrics_address: None,
+        execution_timeout: None,
+    }
+}
+
+/// Creates a Java runtime with the given configuration.
+pub fn create_runtime_for_fake_service(
+    executor: Executor,
+    runtime_config: &RuntimeConfig,
+) -> JavaRuntimeProxy {
+    create_service_runtime(executor, runtime_config)
+}
+
+/// Creates a testkit with the given Java runtime and an instance of the fake service
+/// with `FAKE_SERVICE_ID`.
+pub fn create_testkit_with_fake_service(runtime: JavaRuntimeProxy) -> TestKit {
+    let artifact = ArtifactId {
+        runtime_id: JAVA_RUNTIME_ID,
+        name: fake_service_artifact_name(),
+    };
+    let instance = InstanceInitParams {
+        instance_spec: InstanceSpec {
+            id: FAKE_SERVICE_ID,
+            name: FAKE_SERVICE_NAME.to_owned(),
+            artifact: artifact.clone(),
+        },
+        constructor: vec![],
+    };
+
+    TestKitBuilder::validator()
+        .with_additional_runtime(runtime)
+        .with_parametric_artifact(artifact, fake_service_deploy_arguments())
+        .with_instance(instance)
+        .create()
+}
+
+/// Returns the deploy arguments of the fake service artifact.
+pub fn fake_service_deploy_arguments() -> Vec<u8> {
+    let artifact_path = fake_service_artifact_path();
+    let artifact_filename = Path::new(&artifact_path).file_name().unwrap();
+    deploy_arguments(artifact_filename.to_str().unwrap())
+}
+
 /// Creates valid service artifact.
 pub fn create_service_artifact_valid(executor: &Executor) -> TempPath {
     create_service_artifact(executor, "createValidServiceArtifact")
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+extern crate exonum_testkit;
 extern crate java_bindings;
 extern crate lazy_static;
 extern crate tempfile;
@@ -170,6 +170,24 @@ pub fn fake_service_artifact_path() -> String {
         .to_owned()
 }
 
+/// Returns the name of the fake service artifact. The artifact has the same version
+/// as this project.
+pub fn fake_service_artifact_name() -> String {
+    format!(
+        "com.exonum.binding:fake-service:{}",
+        env!("CARGO_PKG_VERSION")
+    )
+}
+
+/// Serializes the `DeployArguments` protobuf message of the Java runtime.
+pub fn deploy_arguments(artifact_filename: &str) -> Vec<u8> {
+    assert!(artifact_filename.len() < 128, "Long artifact filename");
+    // The tag of the length-delimited field 1 (`artifact_filename`), and the length
+    let mut message = vec![0x0a, artifact_filename.len() as u8];
+    message.extend_from_slice(artifact_filename.as_bytes());
+    message
+}
+
 fn java_binding_parent_root_dir() -> PathBuf {
     rust_project_root_dir()
         .join("../..")
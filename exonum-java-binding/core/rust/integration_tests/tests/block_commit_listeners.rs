@@ -0,0 +1,117 @@
+/*
+ * Copyright 2019 The Exonum Team
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+extern crate integration_tests;
+extern crate java_bindings;
+#[macro_use]
+extern crate lazy_static;
+
+use integration_tests::vm::create_vm_for_tests_with_fake_classes;
+use java_bindings::{
+    exonum::{crypto::Hash, helpers::Height},
+    jni::{objects::JObject, JNIEnv, JavaVM},
+    BlockCommitListeners, Executor, JniResult,
+};
+
+use std::sync::Arc;
+
+lazy_static! {
+    static ref VM: Arc<JavaVM> = create_vm_for_tests_with_fake_classes();
+    static ref EXECUTOR: Executor = Executor::new(VM.clone());
+}
+
+const LISTENER_CLASS: &str = "com/exonum/binding/fakes/mocks/RecordingBlockCommitListener";
+const NATIVE_LISTENER_CLASS: &str = "com/exonum/binding/core/service/NativeBlockCommitListener";
+const NOTIFICATION_TIMEOUT_MILLIS: i64 = 10_000;
+
+#[test]
+fn listener_is_notified() {
+    EXECUTOR
+        .with_attached(|env| {
+            let listeners = BlockCommitListeners::default();
+            let listener = env.new_object(LISTENER_CLASS, "()V", &[])?;
+            listeners.add(env, create_native_listener(env, listener)?)?;
+
+            listeners.notify(Height(1), Hash::zero());
+
+            assert_eq!(poll_height(env, listener, NOTIFICATION_TIMEOUT_MILLIS)?, 1);
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn removed_listener_is_not_notified() {
+    EXECUTOR
+        .with_attached(|env| {
+            let listeners = BlockCommitListeners::default();
+            let removed = env.new_object(LISTENER_CLASS, "()V", &[])?;
+            let removed_id = listeners.add(env, create_native_listener(env, removed)?)?;
+            let retained = env.new_object(LISTENER_CLASS, "()V", &[])?;
+            listeners.add(env, create_native_listener(env, retained)?)?;
+
+            assert!(listeners.remove(removed_id));
+            listeners.notify(Height(1), Hash::zero());
+
+            // The listeners are notified in the order of registration, therefore, once
+            // the retained listener is notified, the removed one would have been as well.
+            assert_eq!(poll_height(env, retained, NOTIFICATION_TIMEOUT_MILLIS)?, 1);
+            assert_eq!(poll_height(env, removed, 0)?, -1);
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn listener_added_after_removal_of_all_listeners_is_notified() {
+    EXECUTOR
+        .with_attached(|env| {
+            let listeners = BlockCommitListeners::default();
+            let removed = env.new_object(LISTENER_CLASS, "()V", &[])?;
+            let removed_id = listeners.add(env, create_native_listener(env, removed)?)?;
+            // Stops the delivery thread
+            assert!(listeners.remove(removed_id));
+
+            let added = env.new_object(LISTENER_CLASS, "()V", &[])?;
+            listeners.add(env, create_native_listener(env, added)?)?;
+            listeners.notify(Height(1), Hash::zero());
+
+            assert_eq!(poll_height(env, added, NOTIFICATION_TIMEOUT_MILLIS)?, 1);
+            assert_eq!(poll_height(env, removed, 0)?, -1);
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn remove_unknown_listener() {
+    let listeners = BlockCommitListeners::default();
+
+    assert!(!listeners.remove(0));
+}
+
+fn create_native_listener<'e>(env: &JNIEnv<'e>, listener: JObject<'e>) -> JniResult<JObject<'e>> {
+    env.new_object(
+        NATIVE_LISTENER_CLASS,
+        "(Lcom/exonum/binding/core/service/BlockCommitListener;)V",
+        &[listener.into()],
+    )
+}
+
+fn poll_height(env: &JNIEnv, listener: JObject, timeout_millis: i64) -> JniResult<i64> {
+    env.call_method(listener, "pollHeight", "(J)J", &[timeout_millis.into()])?
+        .j()
+}
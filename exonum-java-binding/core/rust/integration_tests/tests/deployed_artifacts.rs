@@ -0,0 +1,87 @@
+/*
+ * Copyright 2019 The Exonum Team
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+extern crate futures;
+extern crate integration_tests;
+extern crate java_bindings;
+#[macro_use]
+extern crate lazy_static;
+
+use futures::Future;
+use integration_tests::{
+    fake_service::{
+        create_runtime_for_fake_service, fake_service_deploy_arguments, fake_service_runtime_config,
+    },
+    vm::{create_vm_for_tests_with_fake_classes, deploy_arguments, fake_service_artifact_name},
+};
+use java_bindings::{
+    exonum::runtime::{ArtifactId, Runtime},
+    jni::JavaVM,
+    Executor, JAVA_RUNTIME_ID,
+};
+
+use std::sync::Arc;
+
+lazy_static! {
+    static ref VM: Arc<JavaVM> = create_vm_for_tests_with_fake_classes();
+    static ref EXECUTOR: Executor = Executor::new(VM.clone());
+}
+
+#[test]
+fn deployed_artifact_is_cached() {
+    let mut runtime =
+        create_runtime_for_fake_service(EXECUTOR.clone(), &fake_service_runtime_config());
+    let artifact = java_artifact(&fake_service_artifact_name());
+
+    runtime
+        .deploy_artifact(artifact.clone(), fake_service_deploy_arguments())
+        .wait()
+        .unwrap();
+
+    assert!(runtime.is_artifact_deployed(&artifact));
+}
+
+#[test]
+fn unknown_artifact_is_not_deployed() {
+    let runtime = create_runtime_for_fake_service(EXECUTOR.clone(), &fake_service_runtime_config());
+    let artifact = java_artifact("com.acme:unknown:1.0.0");
+
+    assert!(!runtime.is_artifact_deployed(&artifact));
+}
+
+#[test]
+fn failed_deploy_is_not_cached() {
+    let mut runtime =
+        create_runtime_for_fake_service(EXECUTOR.clone(), &fake_service_runtime_config());
+    let artifact = java_artifact(&fake_service_artifact_name());
+
+    let result = runtime
+        .deploy_artifact(
+            artifact.clone(),
+            deploy_arguments("nonexistent-artifact.jar"),
+        )
+        .wait();
+
+    assert!(result.is_err());
+    assert!(!runtime.is_artifact_deployed(&artifact));
+}
+
+fn java_artifact(name: &str) -> ArtifactId {
+    ArtifactId {
+        runtime_id: JAVA_RUNTIME_ID,
+        name: name.to_owned(),
+    }
+}
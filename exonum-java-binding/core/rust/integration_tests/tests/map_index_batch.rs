@@ -0,0 +1,127 @@
+/*
+ * Copyright 2019 The Exonum Team
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+extern crate integration_tests;
+extern crate java_bindings;
+#[macro_use]
+extern crate lazy_static;
+
+use integration_tests::vm::create_vm_for_tests;
+use java_bindings::{
+    jni::{
+        objects::{JClass, JObject},
+        sys::{jobjectArray, JNI_FALSE},
+        JNIEnv, JavaVM,
+    },
+    utils::{get_and_clear_java_exception, get_class_name},
+    Executor, Java_com_exonum_binding_core_storage_database_TemporaryDb_nativeCreate,
+    Java_com_exonum_binding_core_storage_database_TemporaryDb_nativeCreateFork,
+    Java_com_exonum_binding_core_storage_database_TemporaryDb_nativeFree,
+    Java_com_exonum_binding_core_storage_database_Views_nativeFree,
+    Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeContainsKey,
+    Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeCreate,
+    Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeFree,
+    Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativePutAll, JniResult,
+};
+
+use std::sync::Arc;
+
+lazy_static! {
+    static ref VM: Arc<JavaVM> = create_vm_for_tests();
+    pub static ref EXECUTOR: Executor = Executor::new(VM.clone());
+}
+
+#[test]
+fn put_all_with_different_number_of_keys_and_values() {
+    EXECUTOR
+        .with_attached(|env| {
+            let db_handle = Java_com_exonum_binding_core_storage_database_TemporaryDb_nativeCreate(
+                *env,
+                class(),
+            );
+            let view_handle =
+                Java_com_exonum_binding_core_storage_database_TemporaryDb_nativeCreateFork(
+                    *env,
+                    JObject::null(),
+                    db_handle,
+                );
+            let name = env.new_string("test_map")?;
+            let map_handle =
+                Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeCreate(
+                    *env,
+                    class(),
+                    name,
+                    view_handle,
+                );
+            let keys = byte_arrays(env, &[&[1], &[2]])?;
+            let values = byte_arrays(env, &[&[10]])?;
+
+            Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativePutAll(
+                *env,
+                JObject::null(),
+                map_handle,
+                keys,
+                values,
+            );
+
+            let exception = get_and_clear_java_exception(env);
+            assert_eq!(
+                get_class_name(env, exception)?,
+                "java.lang.IllegalArgumentException"
+            );
+            // No entry must be put
+            let key = env.byte_array_from_slice(&[1])?;
+            let contains =
+                Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeContainsKey(
+                    *env,
+                    JObject::null(),
+                    map_handle,
+                    key,
+                );
+            assert_eq!(contains, JNI_FALSE);
+
+            Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeFree(
+                *env,
+                class(),
+                map_handle,
+            );
+            Java_com_exonum_binding_core_storage_database_Views_nativeFree(
+                *env,
+                class(),
+                view_handle,
+            );
+            Java_com_exonum_binding_core_storage_database_TemporaryDb_nativeFree(
+                *env,
+                class(),
+                db_handle,
+            );
+            Ok(())
+        })
+        .unwrap();
+}
+
+fn byte_arrays(env: &JNIEnv, arrays: &[&[u8]]) -> JniResult<jobjectArray> {
+    let result = env.new_object_array(arrays.len() as i32, "[B", JObject::null())?;
+    for (i, array) in arrays.iter().enumerate() {
+        let element = env.byte_array_from_slice(array)?;
+        env.set_object_array_element(result, i as i32, element.into())?;
+    }
+    Ok(result)
+}
+
+fn class<'a>() -> JClass<'a> {
+    JClass::from(JObject::null())
+}
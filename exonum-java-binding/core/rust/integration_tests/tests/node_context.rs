@@ -14,30 +14,59 @@
  * limitations under the License.
  */
 
+extern crate exonum_testkit;
 extern crate futures;
 extern crate integration_tests;
 extern crate java_bindings;
 #[macro_use]
 extern crate lazy_static;
 extern crate failure;
+extern crate protobuf;
 
 use std::sync::Arc;
 
+use exonum_testkit::TestKit;
 use futures::{
     sync::mpsc::{self, Receiver},
     Stream,
 };
-use integration_tests::vm::create_vm_for_tests_with_fake_classes;
+use integration_tests::{
+    fake_service::{
+        create_runtime_for_fake_service, create_testkit_with_fake_service,
+        fake_service_runtime_config, FAKE_SERVICE_ID,
+    },
+    vm::create_vm_for_tests_with_fake_classes,
+};
 use java_bindings::{
     exonum::{
-        blockchain::Blockchain,
-        crypto::{gen_keypair, PublicKey, SecretKey},
+        blockchain::{Block, BlockProof, Blockchain, ConsensusConfig, TxLocation},
+        crypto::{gen_keypair, verify, Hash, PublicKey, SecretKey},
+        helpers::Height,
+        messages::Verified,
         node::{ApiSender, ExternalMessage},
-        runtime::{AnyTx, CallInfo},
+        runtime::{AnyTx, CallInfo, ExecutionStatus, SnapshotExt},
     },
-    exonum_merkledb::TemporaryDB,
-    jni::JavaVM,
-    Executor, Node,
+    exonum_merkledb::{BinaryValue, ObjectHash, TemporaryDB},
+    jni::{
+        objects::{JClass, JObject},
+        sys::{jbyteArray, JNI_TRUE},
+        JNIEnv, JavaVM,
+    },
+    proto, to_handle,
+    utils::{get_and_clear_java_exception, get_class_name},
+    Executor, Handle, Java_com_exonum_binding_core_service_NodeProxy_nativeFree,
+    Java_com_exonum_binding_core_service_NodeProxy_nativeGetBlock,
+    Java_com_exonum_binding_core_service_NodeProxy_nativeGetBlockProof,
+    Java_com_exonum_binding_core_service_NodeProxy_nativeGetBlockTransactions,
+    Java_com_exonum_binding_core_service_NodeProxy_nativeGetConsensusConfiguration,
+    Java_com_exonum_binding_core_service_NodeProxy_nativeGetHeight,
+    Java_com_exonum_binding_core_service_NodeProxy_nativeGetPendingTransaction,
+    Java_com_exonum_binding_core_service_NodeProxy_nativeGetTransactionStatus,
+    Java_com_exonum_binding_core_service_NodeProxy_nativeGetTxLocation,
+    Java_com_exonum_binding_core_service_NodeProxy_nativeGetTxResult,
+    Java_com_exonum_binding_core_service_NodeProxy_nativeIsInTransactionPool,
+    Java_com_exonum_binding_core_service_NodeProxy_nativeSubmitBatch, JniResult, Node,
+    SubmissionError,
 };
 
 lazy_static! {
@@ -82,6 +111,538 @@ fn submit_transaction_to_missing_service() {
     assert!(res.is_err());
 }
 
+#[test]
+fn check_transaction_to_unknown_service() {
+    let (node, _) = create_node(gen_keypair());
+    // no services are deployed in the blockchain
+    let service_id = 1;
+    let raw_transaction = create_raw_transaction(service_id);
+
+    let err = node.check_transaction(&raw_transaction).unwrap_err();
+    match err {
+        SubmissionError::UnknownService(id) => assert_eq!(id, service_id),
+        _ => panic!("Unexpected error: {}", err),
+    }
+    assert_eq!(err.code(), 1);
+}
+
+#[test]
+fn check_transaction_with_too_large_arguments() {
+    let testkit = create_testkit_with_fake_service(create_runtime_for_fake_service(
+        EXECUTOR.clone(),
+        &fake_service_runtime_config(),
+    ));
+    let node = Node::new(testkit.blockchain());
+    let max_length = testkit
+        .snapshot()
+        .for_core()
+        .consensus_config()
+        .max_message_len as usize;
+    let mut raw_transaction = create_raw_transaction(FAKE_SERVICE_ID);
+    raw_transaction.arguments = vec![0; max_length + 1];
+
+    let err = node.check_transaction(&raw_transaction).unwrap_err();
+    match err {
+        SubmissionError::ArgumentsTooLarge { length, .. } => assert_eq!(length, max_length + 1),
+        _ => panic!("Unexpected error: {}", err),
+    }
+    assert_eq!(err.code(), 3);
+}
+
+#[test]
+fn submit_transaction_batch() {
+    let mut testkit = create_testkit_with_fake_service(create_runtime_for_fake_service(
+        EXECUTOR.clone(),
+        &fake_service_runtime_config(),
+    ));
+    let node = Node::new(testkit.blockchain());
+    let valid_transaction = create_raw_transaction(FAKE_SERVICE_ID);
+    let unknown_service_id = FAKE_SERVICE_ID + 1;
+    let invalid_transaction = create_raw_transaction(unknown_service_id);
+
+    let results = node.submit_batch(vec![
+        valid_transaction.clone(),
+        invalid_transaction,
+        valid_transaction,
+    ]);
+
+    assert_eq!(results.len(), 3);
+    match results[1] {
+        Err(SubmissionError::UnknownService(id)) => assert_eq!(id, unknown_service_id),
+        ref result => panic!("Unexpected result: {:?}", result),
+    }
+    let tx_hash = *results[0].as_ref().unwrap();
+    assert_eq!(*results[2].as_ref().unwrap(), tx_hash);
+
+    // The valid transaction must be broadcast, signed with the service key of the node
+    testkit.poll_events();
+    let snapshot = testkit.snapshot();
+    let schema = snapshot.for_core();
+    assert!(schema.transactions_pool().contains(&tx_hash));
+    let sent = schema.transactions().get(&tx_hash).unwrap();
+    assert_eq!(sent.author(), node.public_key());
+}
+
+#[test]
+fn submit_transaction_batch_with_arrays_of_different_lengths() {
+    let mut testkit = create_testkit_with_fake_service(create_runtime_for_fake_service(
+        EXECUTOR.clone(),
+        &fake_service_runtime_config(),
+    ));
+    let node = Node::new(testkit.blockchain());
+
+    EXECUTOR
+        .with_attached(|env| {
+            let node_handle = to_handle(node);
+            let instance_ids = env.new_int_array(2)?;
+            env.set_int_array_region(instance_ids, 0, &[FAKE_SERVICE_ID as i32; 2])?;
+            // One method id for two transactions
+            let method_ids = env.new_int_array(1)?;
+            let arguments = env.new_object_array(2, "[B", JObject::null())?;
+            for i in 0..2 {
+                let args = env.byte_array_from_slice(TEST_TRANSACTION_PAYLOAD)?;
+                env.set_object_array_element(arguments, i, args.into())?;
+            }
+            let errors = env.new_object_array(2, "java/lang/String", JObject::null())?;
+            let error_codes = env.new_int_array(2)?;
+
+            let hashes = Java_com_exonum_binding_core_service_NodeProxy_nativeSubmitBatch(
+                *env,
+                class(),
+                node_handle,
+                instance_ids,
+                method_ids,
+                arguments,
+                errors,
+                error_codes,
+            );
+
+            assert!(JObject::from(hashes).is_null());
+            let exception = get_and_clear_java_exception(env);
+            assert_eq!(
+                get_class_name(env, exception)?,
+                "java.lang.IllegalArgumentException"
+            );
+
+            Java_com_exonum_binding_core_service_NodeProxy_nativeFree(*env, class(), node_handle);
+            Ok(())
+        })
+        .unwrap();
+
+    // No transaction must be submitted
+    testkit.poll_events();
+    assert!(testkit
+        .snapshot()
+        .for_core()
+        .transactions_pool()
+        .iter()
+        .next()
+        .is_none());
+}
+
+#[test]
+fn submit_externally_signed_transaction() {
+    let (node, app_rx) = create_node(gen_keypair());
+    let (tx_author, secret_key) = gen_keypair();
+    let raw_transaction = create_raw_transaction(0);
+    let verified = Verified::from_value(raw_transaction.clone(), tx_author, &secret_key);
+    let expected_hash = verified.object_hash();
+
+    let tx_hash = node.submit_verified(verified).unwrap();
+    assert_eq!(tx_hash, expected_hash);
+
+    let sent_message = app_rx.wait().next().unwrap().unwrap();
+    match sent_message {
+        ExternalMessage::Transaction(sent) => {
+            assert_eq!(&raw_transaction, sent.payload());
+            assert_eq!(sent.author(), tx_author);
+        }
+        _ => panic!("Message is not Transaction"),
+    }
+}
+
+#[test]
+fn sign_and_verify() {
+    let keypair = gen_keypair();
+    let public_key = keypair.0;
+    let (node, _) = create_node(keypair);
+    let data = [1, 2, 3];
+
+    let signature = node.sign(&data);
+
+    assert!(verify(&signature, &data, &public_key));
+    assert!(node.verify(&data, &signature));
+    assert!(!node.verify(&[1, 2, 4], &signature));
+}
+
+#[test]
+fn is_in_transaction_pool() {
+    let (_testkit, node, committed_tx_hash, pending_tx_hash) = create_node_with_transactions();
+
+    EXECUTOR
+        .with_attached(|env| {
+            let node_handle = to_handle(node);
+
+            assert!(is_in_transaction_pool(env, node_handle, &pending_tx_hash)?);
+            assert!(!is_in_transaction_pool(
+                env,
+                node_handle,
+                &committed_tx_hash
+            )?);
+            assert!(!is_in_transaction_pool(env, node_handle, &Hash::zero())?);
+            assert!(!env.exception_check()?);
+
+            Java_com_exonum_binding_core_service_NodeProxy_nativeFree(*env, class(), node_handle);
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn get_pending_transaction() {
+    let (testkit, node, committed_tx_hash, pending_tx_hash) = create_node_with_transactions();
+
+    EXECUTOR
+        .with_attached(|env| {
+            let node_handle = to_handle(node);
+
+            let pending_tx = get_pending_transaction(env, node_handle, &pending_tx_hash)?;
+            let expected_tx = testkit
+                .snapshot()
+                .for_core()
+                .transactions()
+                .get(&pending_tx_hash)
+                .unwrap();
+            assert_eq!(pending_tx, Some(expected_tx.to_bytes()));
+            assert_eq!(
+                get_pending_transaction(env, node_handle, &committed_tx_hash)?,
+                None
+            );
+            assert_eq!(
+                get_pending_transaction(env, node_handle, &Hash::zero())?,
+                None
+            );
+            assert!(!env.exception_check()?);
+
+            Java_com_exonum_binding_core_service_NodeProxy_nativeFree(*env, class(), node_handle);
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn get_height() {
+    let (_testkit, node, _, _) = create_node_with_transactions();
+
+    with_node_handle(node, |env, node_handle| {
+        let height = Java_com_exonum_binding_core_service_NodeProxy_nativeGetHeight(
+            *env,
+            class(),
+            node_handle,
+        );
+        assert_eq!(height, 1);
+        Ok(())
+    });
+}
+
+#[test]
+fn get_block() {
+    let (testkit, node, _, _) = create_node_with_transactions();
+
+    with_node_handle(node, |env, node_handle| {
+        let block = Java_com_exonum_binding_core_service_NodeProxy_nativeGetBlock(
+            *env,
+            class(),
+            node_handle,
+            1,
+        );
+        let block = Block::from_bytes(to_bytes(env, block)?.unwrap().into()).unwrap();
+        let snapshot = testkit.snapshot();
+        let schema = snapshot.for_core();
+        let block_hash = schema.block_hash_by_height(Height(1)).unwrap();
+        assert_eq!(block, schema.blocks().get(&block_hash).unwrap());
+
+        let missing_block = Java_com_exonum_binding_core_service_NodeProxy_nativeGetBlock(
+            *env,
+            class(),
+            node_handle,
+            2,
+        );
+        assert!(JObject::from(missing_block).is_null());
+        Ok(())
+    });
+}
+
+#[test]
+fn get_block_proof() {
+    let (testkit, node, _, _) = create_node_with_transactions();
+
+    with_node_handle(node, |env, node_handle| {
+        let block_proof = Java_com_exonum_binding_core_service_NodeProxy_nativeGetBlockProof(
+            *env,
+            class(),
+            node_handle,
+            1,
+        );
+        let block_proof =
+            BlockProof::from_bytes(to_bytes(env, block_proof)?.unwrap().into()).unwrap();
+        let expected_block_proof = testkit
+            .snapshot()
+            .for_core()
+            .block_and_precommits(Height(1))
+            .unwrap();
+        assert_eq!(block_proof, expected_block_proof);
+
+        let missing_block_proof =
+            Java_com_exonum_binding_core_service_NodeProxy_nativeGetBlockProof(
+                *env,
+                class(),
+                node_handle,
+                2,
+            );
+        assert!(JObject::from(missing_block_proof).is_null());
+        Ok(())
+    });
+}
+
+#[test]
+fn get_block_transactions() {
+    let (_testkit, node, committed_tx_hash, _) = create_node_with_transactions();
+
+    with_node_handle(node, |env, node_handle| {
+        let tx_hashes = Java_com_exonum_binding_core_service_NodeProxy_nativeGetBlockTransactions(
+            *env,
+            class(),
+            node_handle,
+            1,
+        );
+        assert_eq!(env.get_array_length(tx_hashes)?, 1);
+        let tx_hash = env.get_object_array_element(tx_hashes, 0)?;
+        assert_eq!(
+            env.convert_byte_array(tx_hash.into_inner())?,
+            committed_tx_hash.as_ref().to_vec()
+        );
+
+        let missing_tx_hashes =
+            Java_com_exonum_binding_core_service_NodeProxy_nativeGetBlockTransactions(
+                *env,
+                class(),
+                node_handle,
+                2,
+            );
+        assert!(JObject::from(missing_tx_hashes).is_null());
+        Ok(())
+    });
+}
+
+#[test]
+fn get_tx_location() {
+    let (testkit, node, committed_tx_hash, pending_tx_hash) = create_node_with_transactions();
+
+    with_node_handle(node, |env, node_handle| {
+        let get_tx_location = |tx_hash: &Hash| -> JniResult<Option<Vec<u8>>> {
+            let tx_hash = env.byte_array_from_slice(tx_hash.as_ref())?;
+            let location = Java_com_exonum_binding_core_service_NodeProxy_nativeGetTxLocation(
+                *env,
+                class(),
+                node_handle,
+                tx_hash,
+            );
+            to_bytes(env, location)
+        };
+
+        let location = get_tx_location(&committed_tx_hash)?.unwrap();
+        let location = TxLocation::from_bytes(location.into()).unwrap();
+        let expected_location = testkit
+            .snapshot()
+            .for_core()
+            .transactions_locations()
+            .get(&committed_tx_hash)
+            .unwrap();
+        assert_eq!(location, expected_location);
+        assert_eq!(location.block_height(), Height(1));
+
+        assert_eq!(get_tx_location(&pending_tx_hash)?, None);
+        assert_eq!(get_tx_location(&Hash::zero())?, None);
+        Ok(())
+    });
+}
+
+#[test]
+fn get_tx_result() {
+    let (testkit, node, committed_tx_hash, pending_tx_hash) = create_node_with_transactions();
+
+    with_node_handle(node, |env, node_handle| {
+        let get_tx_result = |tx_hash: &Hash| -> JniResult<Option<Vec<u8>>> {
+            let tx_hash = env.byte_array_from_slice(tx_hash.as_ref())?;
+            let result = Java_com_exonum_binding_core_service_NodeProxy_nativeGetTxResult(
+                *env,
+                class(),
+                node_handle,
+                tx_hash,
+            );
+            to_bytes(env, result)
+        };
+
+        let result = get_tx_result(&committed_tx_hash)?.unwrap();
+        let result = ExecutionStatus::from_bytes(result.into()).unwrap();
+        let expected_result = testkit
+            .snapshot()
+            .for_core()
+            .transaction_results()
+            .get(&committed_tx_hash)
+            .unwrap();
+        assert_eq!(result, expected_result);
+
+        assert_eq!(get_tx_result(&pending_tx_hash)?, None);
+        assert_eq!(get_tx_result(&Hash::zero())?, None);
+        Ok(())
+    });
+}
+
+#[test]
+fn get_consensus_configuration() {
+    let (testkit, node, _, _) = create_node_with_transactions();
+
+    with_node_handle(node, |env, node_handle| {
+        let config = Java_com_exonum_binding_core_service_NodeProxy_nativeGetConsensusConfiguration(
+            *env,
+            class(),
+            node_handle,
+        );
+        let config = ConsensusConfig::from_bytes(to_bytes(env, config)?.unwrap().into()).unwrap();
+        assert_eq!(config, testkit.snapshot().for_core().consensus_config());
+        Ok(())
+    });
+}
+
+#[test]
+fn get_transaction_status() {
+    let (testkit, node, committed_tx_hash, pending_tx_hash) = create_node_with_transactions();
+
+    with_node_handle(node, |env, node_handle| {
+        let get_status = |tx_hash: &Hash| -> JniResult<proto::TransactionStatus> {
+            let tx_hash = env.byte_array_from_slice(tx_hash.as_ref())?;
+            let status = Java_com_exonum_binding_core_service_NodeProxy_nativeGetTransactionStatus(
+                *env,
+                class(),
+                node_handle,
+                tx_hash,
+            );
+            let status = to_bytes(env, status)?.unwrap();
+            Ok(protobuf::parse_from_bytes(&status).unwrap())
+        };
+
+        // Unknown transaction
+        let status = get_status(&Hash::zero())?;
+        assert!(!status.has_in_pool());
+        assert!(!status.has_committed());
+
+        // Transaction in the pool
+        let status = get_status(&pending_tx_hash)?;
+        assert!(status.has_in_pool());
+
+        // Committed transaction
+        let status = get_status(&committed_tx_hash)?;
+        assert!(status.has_committed());
+        let committed = status.get_committed();
+        assert_eq!(committed.get_height(), 1);
+        assert_eq!(committed.get_position_in_block(), 0);
+        let execution_status =
+            ExecutionStatus::from_bytes(committed.get_execution_status().to_vec().into()).unwrap();
+        let expected_execution_status = testkit
+            .snapshot()
+            .for_core()
+            .transaction_results()
+            .get(&committed_tx_hash)
+            .unwrap();
+        assert_eq!(execution_status, expected_execution_status);
+        Ok(())
+    });
+}
+
+/// Creates a testkit with the fake service and a node with one committed transaction
+/// and one transaction in the pool, returning the hashes of these transactions.
+fn create_node_with_transactions() -> (TestKit, Node, Hash, Hash) {
+    let mut testkit = create_testkit_with_fake_service(create_runtime_for_fake_service(
+        EXECUTOR.clone(),
+        &fake_service_runtime_config(),
+    ));
+    let node = Node::new(testkit.blockchain());
+
+    let committed_tx_hash = node
+        .submit(create_raw_transaction(FAKE_SERVICE_ID))
+        .unwrap();
+    testkit.poll_events();
+    testkit.create_block();
+
+    let mut pending_tx = create_raw_transaction(FAKE_SERVICE_ID);
+    pending_tx.arguments = vec![4, 5, 6];
+    let pending_tx_hash = node.submit(pending_tx).unwrap();
+    testkit.poll_events();
+
+    (testkit, node, committed_tx_hash, pending_tx_hash)
+}
+
+fn is_in_transaction_pool(env: &JNIEnv, node_handle: Handle, tx_hash: &Hash) -> JniResult<bool> {
+    let tx_hash = env.byte_array_from_slice(tx_hash.as_ref())?;
+    let in_pool = Java_com_exonum_binding_core_service_NodeProxy_nativeIsInTransactionPool(
+        *env,
+        class(),
+        node_handle,
+        tx_hash,
+    );
+    Ok(in_pool == JNI_TRUE)
+}
+
+fn get_pending_transaction(
+    env: &JNIEnv,
+    node_handle: Handle,
+    tx_hash: &Hash,
+) -> JniResult<Option<Vec<u8>>> {
+    let tx_hash = env.byte_array_from_slice(tx_hash.as_ref())?;
+    let transaction = Java_com_exonum_binding_core_service_NodeProxy_nativeGetPendingTransaction(
+        *env,
+        class(),
+        node_handle,
+        tx_hash,
+    );
+    if JObject::from(transaction).is_null() {
+        Ok(None)
+    } else {
+        env.convert_byte_array(transaction).map(Some)
+    }
+}
+
+/// Passes the handle of the node to `f`, destroying the handle afterwards.
+fn with_node_handle<F>(node: Node, f: F)
+where
+    F: FnOnce(&JNIEnv, Handle) -> JniResult<()>,
+{
+    EXECUTOR
+        .with_attached(|env| {
+            let node_handle = to_handle(node);
+            f(env, node_handle)?;
+            assert!(!env.exception_check()?);
+            Java_com_exonum_binding_core_service_NodeProxy_nativeFree(*env, class(), node_handle);
+            Ok(())
+        })
+        .unwrap();
+}
+
+/// Converts the Java byte array into a vector, or `None` if the array is `null`.
+fn to_bytes(env: &JNIEnv, array: jbyteArray) -> JniResult<Option<Vec<u8>>> {
+    if JObject::from(array).is_null() {
+        Ok(None)
+    } else {
+        env.convert_byte_array(array).map(Some)
+    }
+}
+
+fn class<'a>() -> JClass<'a> {
+    JClass::from(JObject::null())
+}
+
 fn create_raw_transaction(instance_id: u32) -> AnyTx {
     AnyTx {
         call_info: CallInfo {
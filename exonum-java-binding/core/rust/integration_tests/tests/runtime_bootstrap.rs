@@ -17,12 +17,13 @@
 extern crate integration_tests;
 extern crate java_bindings;
 
-use integration_tests::vm::{fakes_classpath, java_library_path, log4j_path};
-use java_bindings::{
-    create_java_vm, create_service_runtime, Executor, InternalConfig, JvmConfig, RuntimeConfig,
+use integration_tests::{
+    fake_service::{create_runtime_for_fake_service, fake_service_runtime_config},
+    vm::{fakes_classpath, java_library_path},
 };
+use java_bindings::{create_java_vm, Executor, InternalConfig, JvmConfig, RuntimeConfig};
 
-use std::{path::PathBuf, sync::Arc};
+use std::sync::Arc;
 
 #[test]
 // Fails on Java 12. Ignored until [ECR-3133] is fixed because the cause of the issue also prevents
@@ -36,11 +37,8 @@ fn bootstrap() {
     };
 
     let runtime_config = RuntimeConfig {
-        artifacts_path: PathBuf::from("/tmp/"),
-        // Pass log4j path to avoid error messages of mis-configuration
-        log_config_path: log4j_path(),
         port: 6300,
-        override_system_lib_path: None,
+        ..fake_service_runtime_config()
     };
 
     let internal_config = InternalConfig {
@@ -51,5 +49,5 @@ fn bootstrap() {
     let java_vm = create_java_vm(&jvm_config, &runtime_config, internal_config);
     let executor = Executor::new(Arc::new(java_vm));
 
-    let _runtime = create_service_runtime(executor, &runtime_config);
+    let _runtime = create_runtime_for_fake_service(executor, &runtime_config);
 }
@@ -21,10 +21,13 @@ extern crate lazy_static;
 
 use integration_tests::vm::create_vm_for_tests_with_fake_classes;
 use java_bindings::{
-    jni::{objects::JThrowable, JNIEnv, JavaVM},
+    jni::{
+        objects::{JObject, JThrowable, JValue},
+        JNIEnv, JavaVM,
+    },
     utils::{
-        check_error_on_exception, get_and_clear_java_exception, get_class_name,
-        get_exception_message, panic_on_exception,
+        check_error_on_exception, describe_java_exception_verbose, get_and_clear_java_exception,
+        get_class_name, get_exception_cause, get_exception_message, panic_on_exception,
     },
     Executor, JniErrorKind, JniResult,
 };
@@ -35,6 +38,7 @@ const OOM_ERROR_CLASS: &str = "java/lang/OutOfMemoryError";
 const EXCEPTION_CLASS: &str = "java/lang/Exception";
 const ARITHMETIC_EXCEPTION_CLASS: &str = "java/lang/ArithmeticException";
 const ARITHMETIC_EXCEPTION_CLASS_FQN: &str = "java.lang.ArithmeticException";
+const RUNTIME_EXCEPTION_CLASS: &str = "java/lang/RuntimeException";
 const CUSTOM_EXCEPTION_MESSAGE: &str = "Test exception message";
 
 lazy_static! {
@@ -192,6 +196,48 @@ fn get_exception_message_with_message() {
         .unwrap();
 }
 
+#[test]
+fn get_exception_cause_without_cause() {
+    EXECUTOR
+        .with_attached(|env: &JNIEnv| {
+            let exception = env.new_object(ARITHMETIC_EXCEPTION_CLASS, "()V", &[])?;
+            assert!(get_exception_cause(env, exception)?.is_none());
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn get_exception_cause_with_cause() {
+    EXECUTOR
+        .with_attached(|env: &JNIEnv| {
+            let exception = new_exception_with_cause(env)?;
+            let cause = get_exception_cause(env, exception)?.expect("No cause");
+            assert_eq!(get_class_name(env, cause)?, ARITHMETIC_EXCEPTION_CLASS_FQN);
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn describe_java_exception_verbose_includes_causes() {
+    EXECUTOR
+        .with_attached(|env: &JNIEnv| {
+            let exception = new_exception_with_cause(env)?;
+            let description = describe_java_exception_verbose(env, exception);
+            assert!(description.starts_with(&format!(
+                "Java exception: java.lang.RuntimeException; Some(\"{}\")",
+                CUSTOM_EXCEPTION_MESSAGE
+            )));
+            assert!(description.contains(&format!(
+                "\nCaused by: {}; None",
+                ARITHMETIC_EXCEPTION_CLASS_FQN
+            )));
+            Ok(())
+        })
+        .unwrap();
+}
+
 #[test]
 #[should_panic(expected = "No exception thrown")]
 fn get_and_clear_java_exception_if_no_exception_occurred() {
@@ -214,6 +260,16 @@ fn throw_with_message(env: &JNIEnv, exception_class: &str, message: &str) -> Jni
     Err(JniErrorKind::JavaException.into())
 }
 
+fn new_exception_with_cause<'e>(env: &JNIEnv<'e>) -> JniResult<JObject<'e>> {
+    let cause = env.new_object(ARITHMETIC_EXCEPTION_CLASS, "()V", &[])?;
+    let message: JObject = env.new_string(CUSTOM_EXCEPTION_MESSAGE)?.into();
+    env.new_object(
+        RUNTIME_EXCEPTION_CLASS,
+        "(Ljava/lang/String;Ljava/lang/Throwable;)V",
+        &[JValue::from(message), JValue::from(cause)],
+    )
+}
+
 fn make_jni_error() -> JniResult<()> {
     Err(JniErrorKind::Msg("Custom test error".to_string()).into())
 }
@@ -18,7 +18,7 @@ use exonum_cli::command::{run::Run as StandardRun, ExonumCommand, StandardResult
 use failure;
 use structopt::StructOpt;
 
-use std::path::PathBuf;
+use std::{net::SocketAddr, path::PathBuf, time::Duration};
 
 use {executable_directory, Config, EjbCommand, EjbCommandResult, JvmConfig, RuntimeConfig};
 
@@ -47,6 +47,24 @@ pub struct Run {
     /// Mostly for internal usage.
     #[structopt(long)]
     pub ejb_override_java_library_path: Option<PathBuf>,
+    /// The maximum time in seconds the deployment of a single Java service artifact may take.
+    ///
+    /// The default is 60 seconds.
+    #[structopt(long)]
+    pub ejb_deploy_timeout: Option<u64>,
+    /// Socket address of the HTTP endpoint exporting the Java runtime metrics
+    /// in the Prometheus text format.
+    ///
+    /// For example, `127.0.0.1:9100`. The metrics are not exported if not specified.
+    #[structopt(long)]
+    pub ejb_metrics_address: Option<SocketAddr>,
+    /// The maximum time in milliseconds the execution of a single Java service
+    /// method may take.
+    ///
+    /// The node is stopped if a method exceeds the limit, therefore, it must be well above
+    /// the time any transaction takes. There is no limit if not specified.
+    #[structopt(long)]
+    pub ejb_execution_timeout: Option<u64>,
     /// Allows JVM being remotely debugged.
     ///
     /// Takes a socket address as a parameter in form of `HOSTNAME:PORT`.
@@ -87,6 +105,9 @@ impl EjbCommand for Run {
                 log_config_path,
                 port: self.ejb_port,
                 override_system_lib_path,
+                deploy_timeout: self.ejb_deploy_timeout.map(Duration::from_secs),
+                metrics_address: self.ejb_metrics_address,
+                execution_timeout: self.ejb_execution_timeout.map(Duration::from_millis),
             };
 
             let config = Config {
@@ -127,6 +127,9 @@ impl EjbCommand for RunDev {
             artifacts_path: self.artifacts_path,
             ejb_log_config_path: self.ejb_log_config_path,
             ejb_override_java_library_path: None,
+            ejb_deploy_timeout: None,
+            ejb_metrics_address: None,
+            ejb_execution_timeout: None,
             jvm_debug: None,
             jvm_args_prepend: vec![],
             jvm_args_append: vec![],
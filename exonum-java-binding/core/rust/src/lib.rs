@@ -53,7 +53,8 @@ pub use exonum::exonum_merkledb;
 
 mod cmd;
 pub mod handle;
-mod proto;
+#[doc(hidden)]
+pub mod proto;
 mod proxy;
 mod runtime;
 mod storage;
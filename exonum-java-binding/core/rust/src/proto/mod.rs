@@ -15,8 +15,10 @@
 //! Module of the rust-protobuf generated files.
 
 #![allow(bare_trait_objects)]
+#![allow(missing_docs)]
 #![allow(renamed_and_removed_lints)]
 
+pub use self::node::*;
 pub use self::service_runtime::*;
 
 include!(concat!(env!("OUT_DIR"), "/protobuf_mod.rs"));
@@ -0,0 +1,228 @@
+/*
+ * Copyright 2019 The Exonum Team
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use exonum::{crypto::Hash, helpers::Height};
+use jni::{
+    objects::{GlobalRef, JObject, JValue},
+    signature::{JavaType, Primitive},
+    Executor, JNIEnv,
+};
+use parking_lot::Mutex;
+
+use std::{
+    collections::BTreeMap,
+    sync::{
+        mpsc::{self, Receiver, SyncSender, TrySendError},
+        Arc,
+    },
+    thread,
+};
+
+use utils::{
+    describe_java_exception, get_and_clear_java_exception, jni_cache::block_commit_listener,
+};
+use {JniError, JniErrorKind, JniResult};
+
+/// Identifier of a registered block commit listener.
+pub type ListenerId = u64;
+
+/// The maximum number of the events pending delivery to the listeners.
+const MAX_PENDING_EVENTS: usize = 256;
+
+type Listeners = Arc<Mutex<BTreeMap<ListenerId, GlobalRef>>>;
+type Event = (Height, Hash);
+
+/// Java listeners of the block commit events.
+///
+/// The listeners are notified on a dedicated thread attached to the JVM, which is started
+/// on the registration of the first listener and stopped on the removal of the last one.
+/// Therefore, a slow listener delays the delivery of the subsequent events, but not the commit
+/// of the blocks. At most `MAX_PENDING_EVENTS` events are kept pending; if the listeners fall
+/// behind, the oldest events are dropped. The listeners are shared between the clones of this
+/// structure.
+#[derive(Clone, Default)]
+pub struct BlockCommitListeners {
+    inner: Arc<Mutex<Inner>>,
+}
+
+#[derive(Default)]
+struct Inner {
+    next_id: ListenerId,
+    listeners: Listeners,
+    events: Option<Events>,
+}
+
+/// The bounded queue of the events pending delivery.
+struct Events {
+    sender: SyncSender<Event>,
+    // The receiver is shared with the delivery thread to drop the oldest events
+    // when the queue is full.
+    receiver: Arc<Mutex<Receiver<Event>>>,
+}
+
+impl Events {
+    fn new() -> Self {
+        let (sender, receiver) = mpsc::sync_channel(MAX_PENDING_EVENTS);
+        Events {
+            sender,
+            receiver: Arc::new(Mutex::new(receiver)),
+        }
+    }
+
+    /// Enqueues the event, dropping the oldest pending event if the queue is full.
+    fn send(&self, mut event: Event) {
+        loop {
+            match self.sender.try_send(event) {
+                Ok(()) => return,
+                Err(TrySendError::Full(rejected)) => {
+                    if let Ok((height, _)) = self.receiver.lock().try_recv() {
+                        warn!(
+                            "Block commit listeners are too slow, dropped the event \
+                             of the block at height {}",
+                            height
+                        );
+                    }
+                    event = rejected;
+                }
+                Err(TrySendError::Disconnected(_)) => {
+                    warn!("Block commit listeners thread is stopped");
+                    return;
+                }
+            }
+        }
+    }
+}
+
+impl BlockCommitListeners {
+    /// Registers the Java listener, which must be an instance of `NativeBlockCommitListener`.
+    /// Returns the identifier of the listener, which is used to remove it.
+    pub fn add(&self, env: &JNIEnv, listener: JObject) -> JniResult<ListenerId> {
+        let listener = env.new_global_ref(listener)?;
+        let mut inner = self.inner.lock();
+        if inner.events.is_none() {
+            let executor = Executor::new(Arc::new(env.get_java_vm()?));
+            let events = Events::new();
+            spawn_delivery_thread(executor, inner.listeners.clone(), events.receiver.clone());
+            inner.events = Some(events);
+        }
+
+        let id = inner.next_id;
+        inner.next_id += 1;
+        inner.listeners.lock().insert(id, listener);
+        Ok(id)
+    }
+
+    /// Removes the listener with the given identifier. Returns `false` if there is no such
+    /// listener.
+    ///
+    /// Stops the delivery thread once the last listener is removed.
+    pub fn remove(&self, id: ListenerId) -> bool {
+        let mut inner = self.inner.lock();
+        let (removed, no_listeners) = {
+            let mut listeners = inner.listeners.lock();
+            (listeners.remove(&id), listeners.is_empty())
+        };
+        if no_listeners {
+            // Dropping the sender stops the delivery thread once it handles the pending events.
+            inner.events = None;
+        }
+        removed.is_some()
+    }
+
+    /// Notifies the listeners of the block committed at the given height.
+    ///
+    /// Does not wait for the listeners to process the event.
+    pub fn notify(&self, height: Height, block_hash: Hash) {
+        if let Some(events) = self.inner.lock().events.as_ref() {
+            events.send((height, block_hash));
+        }
+    }
+}
+
+fn spawn_delivery_thread(
+    executor: Executor,
+    listeners: Listeners,
+    events: Arc<Mutex<Receiver<Event>>>,
+) {
+    thread::Builder::new()
+        .name("java-block-commit-listeners".to_owned())
+        .spawn(move || {
+            loop {
+                // The receiver is locked only while waiting for the event, so that the pending
+                // events can be dropped while the listeners are notified.
+                let event = events.lock().recv();
+                let (height, block_hash) = match event {
+                    Ok(event) => event,
+                    // All the listeners are removed
+                    Err(_) => break,
+                };
+                // The listeners are copied so that they can be added and removed
+                // while the event is delivered.
+                let listeners: Vec<GlobalRef> = listeners.lock().values().cloned().collect();
+                let result = executor.with_attached(|env| {
+                    for listener in &listeners {
+                        notify_listener(env, listener, height, &block_hash)?;
+                    }
+                    Ok(())
+                });
+                if let Err(err) = result {
+                    error!("Unable to notify block commit listeners: {}", err);
+                }
+            }
+        })
+        .expect("Unable to spawn the block commit listeners thread");
+}
+
+/// Calls the listener, logging any exception it throws.
+fn notify_listener(
+    env: &JNIEnv,
+    listener: &GlobalRef,
+    height: Height,
+    block_hash: &Hash,
+) -> JniResult<()> {
+    let block_hash = env.auto_local(env.byte_array_from_slice(block_hash.as_ref())?.into());
+    let height: u64 = height.into();
+    let result = env
+        .call_method_unchecked(
+            listener.as_obj(),
+            block_commit_listener::on_block_committed_id(),
+            JavaType::Primitive(Primitive::Void),
+            &[
+                JValue::from(height as i64),
+                JValue::from(block_hash.as_obj()),
+            ],
+        )
+        .and_then(JValue::v);
+
+    match result {
+        Err(ref err) if is_java_exception(err) => {
+            let exception = get_and_clear_java_exception(env);
+            error!(
+                "Block commit listener threw an exception: {}",
+                describe_java_exception(env, exception)
+            );
+            Ok(())
+        }
+        result => result,
+    }
+}
+
+fn is_java_exception(err: &JniError) -> bool {
+    match err.0 {
+        JniErrorKind::JavaException => true,
+        _ => false,
+    }
+}
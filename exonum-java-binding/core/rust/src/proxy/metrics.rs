@@ -0,0 +1,403 @@
+/*
+ * Copyright 2019 The Exonum Team
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use exonum::runtime::{ErrorKind, ExecutionError, InstanceId, MethodId};
+use parking_lot::Mutex;
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write as FmtWrite,
+    io::{self, BufRead, BufReader, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    sync::Arc,
+    thread,
+    time::{Duration, Instant},
+};
+
+/// The maximum time reading a request or writing a response of the metrics endpoint may take.
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The number of threads handling the connections to the metrics endpoint.
+const SERVER_THREADS: usize = 2;
+
+/// Upper bounds (in seconds) of the buckets of the call latency histograms.
+const LATENCY_BUCKETS: &[f64] = &[
+    0.001, 0.005, 0.01, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// Identifies the calls of the Java runtime sharing the same metrics.
+///
+/// `deploy_artifact` is not related to any service instance, therefore, its calls are not keyed
+/// by an instance id. `after_commit` and `state_hashes` are invoked once for all the services
+/// of the runtime, but the Java runtime reports the duration of each service call,
+/// so they are keyed by the instance id.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct CallKey {
+    operation: &'static str,
+    instance_id: Option<InstanceId>,
+    method_id: Option<MethodId>,
+}
+
+impl CallKey {
+    /// Creates a key of the runtime-wide operation.
+    pub(crate) fn runtime(operation: &'static str) -> Self {
+        CallKey {
+            operation,
+            instance_id: None,
+            method_id: None,
+        }
+    }
+
+    /// Creates a key of the operation performed by the service instance.
+    pub(crate) fn service(operation: &'static str, instance_id: InstanceId) -> Self {
+        CallKey {
+            operation,
+            instance_id: Some(instance_id),
+            method_id: None,
+        }
+    }
+
+    /// Creates a key of the invocation of the service method.
+    pub(crate) fn method(
+        operation: &'static str,
+        instance_id: InstanceId,
+        method_id: MethodId,
+    ) -> Self {
+        CallKey {
+            operation,
+            instance_id: Some(instance_id),
+            method_id: Some(method_id),
+        }
+    }
+
+    /// Formats the labels of the key, followed by the given extra labels.
+    fn labels(&self, extra: &[(&str, &str)]) -> String {
+        let mut labels = vec![format!("operation=\"{}\"", self.operation)];
+        if let Some(instance_id) = self.instance_id {
+            labels.push(format!("instance_id=\"{}\"", instance_id));
+        }
+        if let Some(method_id) = self.method_id {
+            labels.push(format!("method_id=\"{}\"", method_id));
+        }
+        for (name, value) in extra {
+            labels.push(format!("{}=\"{}\"", name, value));
+        }
+        format!("{{{}}}", labels.join(","))
+    }
+}
+
+/// Metrics of a single kind of calls.
+#[derive(Debug, Default)]
+struct CallMetrics {
+    calls: u64,
+    errors: BTreeMap<String, u64>,
+    /// Non-cumulative counts of observations in each of `LATENCY_BUCKETS`;
+    /// the last element counts the observations exceeding all the bounds.
+    latency_buckets: Vec<u64>,
+    latency_sum: f64,
+}
+
+impl CallMetrics {
+    fn observe(&mut self, duration: Duration, error: Option<&ErrorKind>) {
+        if self.latency_buckets.is_empty() {
+            self.latency_buckets = vec![0; LATENCY_BUCKETS.len() + 1];
+        }
+        let seconds = duration_as_secs(duration);
+        let bucket = LATENCY_BUCKETS
+            .iter()
+            .position(|bound| seconds <= *bound)
+            .unwrap_or(LATENCY_BUCKETS.len());
+
+        self.calls += 1;
+        self.latency_buckets[bucket] += 1;
+        self.latency_sum += seconds;
+        if let Some(kind) = error {
+            *self.errors.entry(error_kind_label(kind)).or_insert(0) += 1;
+        }
+    }
+}
+
+/// Call counts, error counts and latencies of the calls of the Java runtime.
+///
+/// The metrics are shared between the clones of this structure.
+#[derive(Clone, Debug, Default)]
+pub struct RuntimeMetrics {
+    inner: Arc<Mutex<BTreeMap<CallKey, CallMetrics>>>,
+}
+
+impl RuntimeMetrics {
+    /// Creates empty metrics.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Executes `f`, recording its latency and result under the given key.
+    pub(crate) fn measure<F, R>(&self, key: CallKey, f: F) -> Result<R, ExecutionError>
+    where
+        F: FnOnce() -> Result<R, ExecutionError>,
+    {
+        let start = Instant::now();
+        let result = f();
+        self.observe(key, start.elapsed(), result.as_ref().err().map(|e| &e.kind));
+        result
+    }
+
+    /// Records a single call with the given key.
+    pub(crate) fn observe(&self, key: CallKey, duration: Duration, error: Option<&ErrorKind>) {
+        self.inner
+            .lock()
+            .entry(key)
+            .or_default()
+            .observe(duration, error);
+    }
+
+    /// Returns the metrics in the Prometheus text exposition format.
+    pub fn to_prometheus_text(&self) -> String {
+        let metrics = self.inner.lock();
+        let mut text = String::new();
+
+        writeln!(
+            text,
+            "# HELP ejb_runtime_calls_total Calls of the Java runtime."
+        )
+        .unwrap();
+        writeln!(text, "# TYPE ejb_runtime_calls_total counter").unwrap();
+        for (key, call_metrics) in metrics.iter() {
+            writeln!(
+                text,
+                "ejb_runtime_calls_total{} {}",
+                key.labels(&[]),
+                call_metrics.calls
+            )
+            .unwrap();
+        }
+
+        writeln!(
+            text,
+            "# HELP ejb_runtime_errors_total Failed calls of the Java runtime by error kind."
+        )
+        .unwrap();
+        writeln!(text, "# TYPE ejb_runtime_errors_total counter").unwrap();
+        for (key, call_metrics) in metrics.iter() {
+            for (kind, count) in &call_metrics.errors {
+                writeln!(
+                    text,
+                    "ejb_runtime_errors_total{} {}",
+                    key.labels(&[("kind", kind)]),
+                    count
+                )
+                .unwrap();
+            }
+        }
+
+        writeln!(
+            text,
+            "# HELP ejb_runtime_call_duration_seconds Latency of the calls of the Java runtime."
+        )
+        .unwrap();
+        writeln!(text, "# TYPE ejb_runtime_call_duration_seconds histogram").unwrap();
+        for (key, call_metrics) in metrics.iter() {
+            let mut cumulative_count = 0;
+            for (bound, count) in LATENCY_BUCKETS.iter().zip(&call_metrics.latency_buckets) {
+                cumulative_count += count;
+                writeln!(
+                    text,
+                    "ejb_runtime_call_duration_seconds_bucket{} {}",
+                    key.labels(&[("le", &bound.to_string())]),
+                    cumulative_count
+                )
+                .unwrap();
+            }
+            writeln!(
+                text,
+                "ejb_runtime_call_duration_seconds_bucket{} {}",
+                key.labels(&[("le", "+Inf")]),
+                call_metrics.calls
+            )
+            .unwrap();
+            writeln!(
+                text,
+                "ejb_runtime_call_duration_seconds_sum{} {}",
+                key.labels(&[]),
+                call_metrics.latency_sum
+            )
+            .unwrap();
+            writeln!(
+                text,
+                "ejb_runtime_call_duration_seconds_count{} {}",
+                key.labels(&[]),
+                call_metrics.calls
+            )
+            .unwrap();
+        }
+
+        text
+    }
+
+    /// Starts an HTTP server exporting the metrics in the Prometheus text format
+    /// on the given address.
+    ///
+    /// The server responds with the metrics to any request and runs until the process exits.
+    /// The connections are handled by a fixed number of threads, each accepting and serving
+    /// one connection at a time, so that a slow client does not block the others.
+    /// A connection is closed if the client does not complete its request in time.
+    pub fn serve(&self, address: SocketAddr) -> io::Result<()> {
+        let listener = TcpListener::bind(address)?;
+        info!(
+            "Exporting Java runtime metrics on http://{}/metrics",
+            address
+        );
+        self.serve_on(listener)
+    }
+
+    fn serve_on(&self, listener: TcpListener) -> io::Result<()> {
+        for i in 0..SERVER_THREADS {
+            let listener = listener.try_clone()?;
+            let metrics = self.clone();
+            thread::Builder::new()
+                .name(format!("java-runtime-metrics-{}", i))
+                .spawn(move || {
+                    for stream in listener.incoming() {
+                        let result = stream.and_then(|stream| metrics.respond(stream));
+                        if let Err(err) = result {
+                            warn!("Unable to respond to the metrics request: {}", err);
+                        }
+                    }
+                })?;
+        }
+        Ok(())
+    }
+
+    fn respond(&self, mut stream: TcpStream) -> io::Result<()> {
+        stream.set_read_timeout(Some(CONNECTION_TIMEOUT))?;
+        stream.set_write_timeout(Some(CONNECTION_TIMEOUT))?;
+        // The request itself is not interpreted, but must be read before responding.
+        {
+            let mut reader = BufReader::new(&stream);
+            let mut line = String::new();
+            while reader.read_line(&mut line)? > 0 && line != "\r\n" && line != "\n" {
+                line.clear();
+            }
+        }
+
+        let body = self.to_prometheus_text();
+        write!(
+            stream,
+            "HTTP/1.1 200 OK\r\n\
+             Content-Type: text/plain; version=0.0.4\r\n\
+             Content-Length: {}\r\n\
+             Connection: close\r\n\r\n{}",
+            body.len(),
+            body
+        )?;
+        stream.flush()
+    }
+}
+
+/// Returns the label value identifying the kind of the error.
+fn error_kind_label(kind: &ErrorKind) -> String {
+    match *kind {
+        ErrorKind::Panic => "panic".to_owned(),
+        ErrorKind::Dispatcher { code } => format!("dispatcher:{}", code),
+        ErrorKind::Runtime { code } => format!("runtime:{}", code),
+        ErrorKind::Service { code } => format!("service:{}", code),
+    }
+}
+
+fn duration_as_secs(duration: Duration) -> f64 {
+    duration.as_secs() as f64 + f64::from(duration.subsec_nanos()) * 1e-9
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    #[test]
+    fn measure_records_calls_and_errors() {
+        let metrics = RuntimeMetrics::new();
+        let key = CallKey::method("execute", 2, 5);
+
+        let _ = metrics.measure(key, || Ok(()));
+        let _ =
+            metrics.measure::<_, ()>(key, || Err(ExecutionError::new(ErrorKind::service(3), "")));
+
+        let text = metrics.to_prometheus_text();
+        let labels = "operation=\"execute\",instance_id=\"2\",method_id=\"5\"";
+        assert!(text.contains(&format!("ejb_runtime_calls_total{{{}}} 2", labels)));
+        assert!(text.contains(&format!(
+            "ejb_runtime_errors_total{{{},kind=\"service:3\"}} 1",
+            labels
+        )));
+        assert!(text.contains(&format!(
+            "ejb_runtime_call_duration_seconds_count{{{}}} 2",
+            labels
+        )));
+    }
+
+    #[test]
+    fn serve_responds_while_other_client_is_idle() {
+        let metrics = RuntimeMetrics::new();
+        metrics.observe(
+            CallKey::runtime("deploy_artifact"),
+            Duration::from_millis(1),
+            None,
+        );
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        metrics.serve_on(listener).unwrap();
+
+        // A client that connects, but never sends its request
+        let _idle_client = TcpStream::connect(address).unwrap();
+
+        let mut client = TcpStream::connect(address).unwrap();
+        client
+            .set_read_timeout(Some(Duration::from_secs(1)))
+            .unwrap();
+        client
+            .write_all(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .unwrap();
+        let mut response = String::new();
+        client.read_to_string(&mut response).unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains("ejb_runtime_calls_total{operation=\"deploy_artifact\"} 1"));
+    }
+
+    #[test]
+    fn latency_histogram_is_cumulative() {
+        let metrics = RuntimeMetrics::new();
+        let key = CallKey::service("after_commit", 2);
+
+        metrics.observe(key, Duration::from_millis(3), None);
+        metrics.observe(key, Duration::from_millis(30), None);
+        metrics.observe(key, Duration::from_secs(60), None);
+
+        let text = metrics.to_prometheus_text();
+        let bucket = |le: &str| {
+            format!(
+                "ejb_runtime_call_duration_seconds_bucket{{operation=\"after_commit\",\
+                 instance_id=\"2\",le=\"{}\"}}",
+                le
+            )
+        };
+        assert!(text.contains(&format!("{} 0", bucket("0.001"))));
+        assert!(text.contains(&format!("{} 1", bucket("0.005"))));
+        assert!(text.contains(&format!("{} 2", bucket("0.05"))));
+        assert!(text.contains(&format!("{} 2", bucket("10"))));
+        assert!(text.contains(&format!("{} 3", bucket("+Inf"))));
+    }
+}
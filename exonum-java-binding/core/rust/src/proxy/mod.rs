@@ -12,8 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod block_commit_listeners;
+mod metrics;
 mod node;
 mod runtime;
+mod watchdog;
+mod worker;
 
+pub use self::block_commit_listeners::{BlockCommitListeners, ListenerId};
+pub use self::metrics::RuntimeMetrics;
 pub use self::node::*;
 pub use self::runtime::*;
@@ -16,37 +16,107 @@
 
 use exonum::{
     blockchain::Blockchain,
-    crypto::{Hash, PublicKey},
+    crypto::{self, Hash, PublicKey, Signature},
+    helpers::{Height, ValidatorId},
     messages::Verified,
-    runtime::{AnyTx, CallInfo},
+    runtime::{AnyTx, CallInfo, InstanceId, InstanceStatus, SnapshotExt},
 };
-use exonum_merkledb::{ObjectHash, Snapshot};
+use exonum_merkledb::{BinaryValue, ObjectHash, Snapshot};
 use failure;
-use jni::objects::JClass;
-use jni::sys::{jbyteArray, jshort};
+use jni::objects::{JClass, JObject, JThrowable, JValue};
+use jni::sys::{jboolean, jbyteArray, jint, jintArray, jlong, jobjectArray, jshort};
 use jni::JNIEnv;
+use protobuf::Message;
 
-use std::{panic, ptr};
+use std::{fmt, panic, ptr};
 
 use handle::{cast_handle, drop_handle, to_handle, Handle};
+use proto;
+use proxy::{BlockCommitListeners, ListenerId};
 use storage::View;
 use utils::{unwrap_exc_or, unwrap_exc_or_default, unwrap_jni_verbose};
 use JniResult;
 
 const TX_SUBMISSION_EXCEPTION: &str =
     "com/exonum/binding/core/service/TransactionSubmissionException";
+const INVALID_TX_MESSAGE_EXCEPTION: &str =
+    "com/exonum/binding/core/service/InvalidTransactionMessageException";
+const ILLEGAL_ARGUMENT_EXCEPTION: &str = "java/lang/IllegalArgumentException";
+
+/// An error preventing the submission of a transaction.
+#[derive(Debug)]
+pub enum SubmissionError {
+    /// No service instance with the given ID exists.
+    UnknownService(InstanceId),
+    /// The service instance with the given ID exists, but is not active.
+    InactiveService(InstanceId),
+    /// The transaction arguments exceed the maximum message length.
+    ArgumentsTooLarge { length: usize, max_length: usize },
+    /// The transaction could not be broadcast to the network.
+    Broadcast(failure::Error),
+}
+
+impl SubmissionError {
+    /// Returns the code of this error, matching the `TransactionSubmissionException.ErrorCode`
+    /// in Java.
+    pub fn code(&self) -> i32 {
+        match self {
+            SubmissionError::UnknownService(_) => 1,
+            SubmissionError::InactiveService(_) => 2,
+            SubmissionError::ArgumentsTooLarge { .. } => 3,
+            SubmissionError::Broadcast(_) => 4,
+        }
+    }
+}
+
+impl fmt::Display for SubmissionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SubmissionError::UnknownService(id) => {
+                write!(f, "No service instance with id {} exists", id)
+            }
+            SubmissionError::InactiveService(id) => {
+                write!(f, "Service instance with id {} is not active", id)
+            }
+            SubmissionError::ArgumentsTooLarge { length, max_length } => write!(
+                f,
+                "Transaction arguments are too large: {} bytes, while at most {} are allowed",
+                length, max_length
+            ),
+            SubmissionError::Broadcast(err) => {
+                write!(f, "Failed to broadcast the transaction: {}", err)
+            }
+        }
+    }
+}
+
+impl From<failure::Error> for SubmissionError {
+    fn from(err: failure::Error) -> Self {
+        SubmissionError::Broadcast(err)
+    }
+}
 
 /// An Exonum node context. Allows to add transactions to Exonum network
 /// and get a snapshot of the database state.
 #[derive(Clone)]
 pub struct Node {
     blockchain: Blockchain,
+    block_commit_listeners: BlockCommitListeners,
 }
 
 impl Node {
     /// Creates a node context for a service.
     pub fn new(blockchain: Blockchain) -> Self {
-        Node { blockchain }
+        Node {
+            blockchain,
+            block_commit_listeners: BlockCommitListeners::default(),
+        }
+    }
+
+    /// Returns the Java listeners of the block commit events. They are shared between
+    /// the clones of this node.
+    pub fn block_commit_listeners(&self) -> &BlockCommitListeners {
+        &self.block_commit_listeners
     }
 
     #[doc(hidden)]
@@ -59,19 +129,122 @@ impl Node {
         self.blockchain.service_keypair().0
     }
 
+    /// If this node is a validator according to the consensus configuration
+    /// in the given snapshot, returns its ID.
+    pub fn validator_id(&self, snapshot: &dyn Snapshot) -> Option<ValidatorId> {
+        let public_key = self.public_key();
+        snapshot
+            .for_core()
+            .consensus_config()
+            .find_validator(|validator_keys| public_key == validator_keys.service_key)
+    }
+
+    /// Signs the given data with the service secret key of this node.
+    #[doc(hidden)]
+    pub fn sign(&self, data: &[u8]) -> Signature {
+        let (_, secret_key) = self.blockchain.service_keypair();
+        crypto::sign(data, secret_key)
+    }
+
+    /// Verifies the signature of the given data with the service public key of this node.
+    #[doc(hidden)]
+    pub fn verify(&self, data: &[u8], signature: &Signature) -> bool {
+        crypto::verify(signature, data, &self.public_key())
+    }
+
+    /// Checks that the transaction can be submitted: its service instance must exist
+    /// and be active, and its arguments must fit into the maximum message length
+    /// of the current consensus configuration.
+    #[doc(hidden)]
+    pub fn check_transaction(&self, tx: &AnyTx) -> Result<(), SubmissionError> {
+        check_transaction(&*self.create_snapshot(), tx)
+    }
+
     #[doc(hidden)]
     pub fn submit(&self, tx: AnyTx) -> Result<Hash, failure::Error> {
         let (pub_key, secret_key) = self.blockchain.service_keypair();
 
         let verified = Verified::from_value(tx, pub_key.to_owned(), secret_key);
+        self.submit_verified(verified)
+    }
+
+    /// Submits the transaction message, which is already signed and verified, into the network.
+    #[doc(hidden)]
+    pub fn submit_verified(&self, verified: Verified<AnyTx>) -> Result<Hash, failure::Error> {
         let tx_hash = verified.object_hash();
-        // TODO(ECR-3679): check Core behaviour/any errors on service inactivity
         self.blockchain.sender().broadcast_transaction(verified)?;
         Ok(tx_hash)
     }
+
+    /// Checks the transactions and submits the ones passing the checks into the network,
+    /// signing them with the service keypair. All the transactions are checked against
+    /// the same snapshot.
+    ///
+    /// A failure to submit some transaction does not prevent the submission of the others:
+    /// the results correspond to the transactions in the same order.
+    #[doc(hidden)]
+    pub fn submit_batch(&self, txs: Vec<AnyTx>) -> Vec<Result<Hash, SubmissionError>> {
+        let snapshot = self.create_snapshot();
+        let (pub_key, secret_key) = self.blockchain.service_keypair();
+
+        txs.into_iter()
+            .map(|tx| {
+                check_transaction(&*snapshot, &tx)?;
+                let verified = Verified::from_value(tx, pub_key.to_owned(), secret_key);
+                self.submit_verified(verified)
+                    .map_err(SubmissionError::from)
+            })
+            .collect()
+    }
+}
+
+/// Checks that the transaction can be submitted according to the given snapshot.
+fn check_transaction(snapshot: &dyn Snapshot, tx: &AnyTx) -> Result<(), SubmissionError> {
+    let instance_id = tx.call_info.instance_id;
+    let instance = snapshot
+        .for_dispatcher()
+        .get_instance(instance_id)
+        .ok_or(SubmissionError::UnknownService(instance_id))?;
+    check_instance_status(instance_id, instance.status)?;
+
+    let max_length = snapshot.for_core().consensus_config().max_message_len as usize;
+    check_arguments_length(tx.arguments.len(), max_length)
+}
+
+/// Checks that the service instance with the given status accepts transactions.
+fn check_instance_status(
+    instance_id: InstanceId,
+    status: Option<InstanceStatus>,
+) -> Result<(), SubmissionError> {
+    match status {
+        Some(InstanceStatus::Active) => Ok(()),
+        _ => Err(SubmissionError::InactiveService(instance_id)),
+    }
+}
+
+/// Checks that the transaction arguments fit into the maximum message length.
+fn check_arguments_length(length: usize, max_length: usize) -> Result<(), SubmissionError> {
+    if length > max_length {
+        return Err(SubmissionError::ArgumentsTooLarge { length, max_length });
+    }
+    Ok(())
+}
+
+/// Throws a `TransactionSubmissionException` with the code and the description
+/// of the given error.
+fn throw_submission_error(env: &JNIEnv, err: &SubmissionError) -> JniResult<()> {
+    let description = env.new_string(err.to_string())?;
+    let exception = env.new_object(
+        TX_SUBMISSION_EXCEPTION,
+        "(ILjava/lang/String;)V",
+        &[JValue::Int(err.code()), JValue::Object(description.into())],
+    )?;
+    env.throw(JThrowable::from(exception))
 }
 
 /// Submits a transaction into the network. Returns transaction hash as byte array.
+/// If the transaction does not pass the checks or cannot be broadcast,
+/// a `TransactionSubmissionException` with the corresponding error code is thrown.
 ///
 /// Parameters:
 /// - `node_handle` - a native handle to the native node object
@@ -102,13 +275,13 @@ pub extern "system" fn Java_com_exonum_binding_core_service_NodeProxy_nativeSubm
                     arguments: args,
                 };
 
-                match node.submit(tx) {
+                let result = node
+                    .check_transaction(&tx)
+                    .and_then(|_| node.submit(tx).map_err(SubmissionError::from));
+                match result {
                     Ok(tx_hash) => convert_hash(&env, &tx_hash),
                     Err(err) => {
-                        // node#submit can fail on an error in ApiSender#send
-                        let error_class = TX_SUBMISSION_EXCEPTION;
-                        let error_description = err.to_string();
-                        env.throw_new(error_class, error_description)?;
+                        throw_submission_error(&env, &err)?;
                         Ok(ptr::null_mut())
                     }
                 }
@@ -119,6 +292,456 @@ pub extern "system" fn Java_com_exonum_binding_core_service_NodeProxy_nativeSubm
     unwrap_exc_or(&env, res, ptr::null_mut())
 }
 
+/// Submits a transaction message signed by an external key pair into the network.
+/// Returns the hash of the message as a byte array.
+///
+/// The message is submitted unchanged, after verification of its signature and its
+/// `AnyTx` payload. If the message is malformed or badly signed,
+/// an `InvalidTransactionMessageException` is thrown.
+///
+/// Parameters:
+/// - `node_handle` - a native handle to the native node object
+/// - `message` - a serialized `SignedMessage` containing the transaction
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_service_NodeProxy_nativeSubmitSigned(
+    env: JNIEnv,
+    _: JClass,
+    node_handle: Handle,
+    message: jbyteArray,
+) -> jbyteArray {
+    use utils::convert_hash;
+    let res = panic::catch_unwind(|| {
+        let node = cast_handle::<Node>(node_handle);
+        let hash = unwrap_jni_verbose(
+            &env,
+            || -> JniResult<jbyteArray> {
+                let message = env.convert_byte_array(message)?;
+                let verified = match Verified::<AnyTx>::from_bytes(message.into()) {
+                    Ok(verified) => verified,
+                    Err(err) => {
+                        let error_description = format!("Invalid transaction message: {}", err);
+                        env.throw_new(INVALID_TX_MESSAGE_EXCEPTION, error_description)?;
+                        return Ok(ptr::null_mut());
+                    }
+                };
+
+                let result = node.check_transaction(verified.payload()).and_then(|_| {
+                    node.submit_verified(verified)
+                        .map_err(SubmissionError::from)
+                });
+                match result {
+                    Ok(tx_hash) => convert_hash(&env, &tx_hash),
+                    Err(err) => {
+                        throw_submission_error(&env, &err)?;
+                        Ok(ptr::null_mut())
+                    }
+                }
+            }(),
+        );
+        Ok(hash)
+    });
+    unwrap_exc_or(&env, res, ptr::null_mut())
+}
+
+/// Submits a batch of transactions into the network. Returns an array of transaction hashes
+/// as byte arrays, with `null` at the positions of the transactions that could not be submitted.
+///
+/// All the arrays must have the same length, otherwise an `IllegalArgumentException` is thrown
+/// and no transaction is submitted.
+///
+/// Parameters:
+/// - `node_handle` - a native handle to the native node object
+/// - `instance_ids` - an array of identifiers of the services of each transaction
+/// - `method_ids` - an array of identifiers of the methods of each transaction
+/// - `arguments` - an array of the arguments of each transaction
+/// - `errors` - an array of strings to write the descriptions of submission errors into,
+///   at the positions of the failed transactions
+/// - `error_codes` - an array to write the codes of submission errors into,
+///   at the positions of the failed transactions
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_service_NodeProxy_nativeSubmitBatch(
+    env: JNIEnv,
+    _: JClass,
+    node_handle: Handle,
+    instance_ids: jintArray,
+    method_ids: jintArray,
+    arguments: jobjectArray,
+    errors: jobjectArray,
+    error_codes: jintArray,
+) -> jobjectArray {
+    use utils::convert_hash;
+    let res = panic::catch_unwind(|| {
+        let node = cast_handle::<Node>(node_handle);
+        let hashes = unwrap_jni_verbose(
+            &env,
+            || -> JniResult<jobjectArray> {
+                let count = env.get_array_length(instance_ids)?;
+                let lengths = [
+                    env.get_array_length(method_ids)?,
+                    env.get_array_length(arguments)?,
+                    env.get_array_length(errors)?,
+                    env.get_array_length(error_codes)?,
+                ];
+                if lengths.iter().any(|&length| length != count) {
+                    let message = format!(
+                        "All the arrays must have the same length, but got {} service ids, \
+                         {} method ids, {} arguments, {} errors and {} error codes",
+                        count, lengths[0], lengths[1], lengths[2], lengths[3]
+                    );
+                    env.throw_new(ILLEGAL_ARGUMENT_EXCEPTION, message)?;
+                    return Ok(ptr::null_mut());
+                }
+                let mut instance_id_values = vec![0 as jint; count as usize];
+                env.get_int_array_region(instance_ids, 0, &mut instance_id_values)?;
+                let mut method_id_values = vec![0 as jint; count as usize];
+                env.get_int_array_region(method_ids, 0, &mut method_id_values)?;
+
+                let mut txs = Vec::with_capacity(count as usize);
+                for i in 0..count {
+                    let args_array = env.get_object_array_element(arguments, i)?;
+                    let args = env.convert_byte_array(args_array.into_inner())?;
+                    env.delete_local_ref(args_array)?;
+                    txs.push(AnyTx {
+                        call_info: CallInfo {
+                            instance_id: instance_id_values[i as usize] as u32,
+                            method_id: method_id_values[i as usize] as u32,
+                        },
+                        arguments: args,
+                    });
+                }
+
+                let results = node.submit_batch(txs);
+
+                let hashes = env.new_object_array(count, "[B", JObject::null())?;
+                let mut error_code_values = vec![0 as jint; count as usize];
+                for (i, result) in results.into_iter().enumerate() {
+                    match result {
+                        Ok(tx_hash) => {
+                            let hash = JObject::from(convert_hash(&env, &tx_hash)?);
+                            env.set_object_array_element(hashes, i as jint, hash)?;
+                            env.delete_local_ref(hash)?;
+                        }
+                        Err(err) => {
+                            let error_description = env.new_string(err.to_string())?;
+                            env.set_object_array_element(errors, i as jint, *error_description)?;
+                            env.delete_local_ref(*error_description)?;
+                            error_code_values[i] = err.code();
+                        }
+                    }
+                }
+                env.set_int_array_region(error_codes, 0, &error_code_values)?;
+                Ok(hashes)
+            }(),
+        );
+        Ok(hashes)
+    });
+    unwrap_exc_or(&env, res, ptr::null_mut())
+}
+
+/// Returns the height of the latest committed block.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_service_NodeProxy_nativeGetHeight(
+    env: JNIEnv,
+    _: JClass,
+    node_handle: Handle,
+) -> jlong {
+    let res = panic::catch_unwind(|| {
+        let node = cast_handle::<Node>(node_handle);
+        let snapshot = node.create_snapshot();
+        let height: u64 = snapshot.for_core().height().into();
+        Ok(height as jlong)
+    });
+    unwrap_exc_or_default(&env, res)
+}
+
+/// Returns the block at the given height as a serialized `exonum.Block` protobuf message,
+/// or `null` if there is no such block.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_service_NodeProxy_nativeGetBlock(
+    env: JNIEnv,
+    _: JClass,
+    node_handle: Handle,
+    height: jlong,
+) -> jbyteArray {
+    use utils::proto_to_java_bytes;
+    let res = panic::catch_unwind(|| {
+        let node = cast_handle::<Node>(node_handle);
+        let snapshot = node.create_snapshot();
+        let schema = snapshot.for_core();
+        let block = schema
+            .block_hash_by_height(Height(height as u64))
+            .and_then(|block_hash| schema.blocks().get(&block_hash));
+        Ok(match block {
+            Some(block) => unwrap_jni_verbose(&env, proto_to_java_bytes(&env, block)),
+            None => ptr::null_mut(),
+        })
+    });
+    unwrap_exc_or(&env, res, ptr::null_mut())
+}
+
+/// Returns the block at the given height together with the precommits authenticating it,
+/// as a serialized `exonum.BlockProof` protobuf message, or `null` if there is no such block.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_service_NodeProxy_nativeGetBlockProof(
+    env: JNIEnv,
+    _: JClass,
+    node_handle: Handle,
+    height: jlong,
+) -> jbyteArray {
+    use utils::proto_to_java_bytes;
+    let res = panic::catch_unwind(|| {
+        let node = cast_handle::<Node>(node_handle);
+        let snapshot = node.create_snapshot();
+        let block_proof = snapshot
+            .for_core()
+            .block_and_precommits(Height(height as u64));
+        Ok(match block_proof {
+            Some(block_proof) => unwrap_jni_verbose(&env, proto_to_java_bytes(&env, block_proof)),
+            None => ptr::null_mut(),
+        })
+    });
+    unwrap_exc_or(&env, res, ptr::null_mut())
+}
+
+/// Returns the hashes of the transactions committed in the block at the given height
+/// as an array of byte arrays, or `null` if there is no such block.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_service_NodeProxy_nativeGetBlockTransactions(
+    env: JNIEnv,
+    _: JClass,
+    node_handle: Handle,
+    height: jlong,
+) -> jobjectArray {
+    use utils::convert_hash;
+    let res = panic::catch_unwind(|| {
+        let node = cast_handle::<Node>(node_handle);
+        let snapshot = node.create_snapshot();
+        let schema = snapshot.for_core();
+        let blockchain_height: u64 = schema.height().into();
+        if height as u64 > blockchain_height {
+            return Ok(ptr::null_mut());
+        }
+
+        let tx_hashes = schema.block_transactions(Height(height as u64));
+        let hashes = unwrap_jni_verbose(
+            &env,
+            || -> JniResult<jobjectArray> {
+                let hashes =
+                    env.new_object_array(tx_hashes.len() as jint, "[B", JObject::null())?;
+                for (i, tx_hash) in tx_hashes.iter().enumerate() {
+                    let hash = JObject::from(convert_hash(&env, &tx_hash)?);
+                    env.set_object_array_element(hashes, i as jint, hash)?;
+                    env.delete_local_ref(hash)?;
+                }
+                Ok(hashes)
+            }(),
+        );
+        Ok(hashes)
+    });
+    unwrap_exc_or(&env, res, ptr::null_mut())
+}
+
+/// Returns the location of the committed transaction with the given message hash
+/// as a serialized `exonum.TxLocation` protobuf message, or `null` if the transaction
+/// is unknown or not yet committed.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_service_NodeProxy_nativeGetTxLocation(
+    env: JNIEnv,
+    _: JClass,
+    node_handle: Handle,
+    message_hash: jbyteArray,
+) -> jbyteArray {
+    use utils::{convert_to_hash, proto_to_java_bytes};
+    let res = panic::catch_unwind(|| {
+        let node = cast_handle::<Node>(node_handle);
+        let message_hash = unwrap_jni_verbose(&env, convert_to_hash(&env, message_hash));
+        let snapshot = node.create_snapshot();
+        let location = snapshot
+            .for_core()
+            .transactions_locations()
+            .get(&message_hash);
+        Ok(match location {
+            Some(location) => unwrap_jni_verbose(&env, proto_to_java_bytes(&env, location)),
+            None => ptr::null_mut(),
+        })
+    });
+    unwrap_exc_or(&env, res, ptr::null_mut())
+}
+
+/// Returns the execution result of the committed transaction with the given message hash
+/// as a serialized `exonum.runtime.ExecutionStatus` protobuf message, or `null`
+/// if the transaction is unknown or not yet committed.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_service_NodeProxy_nativeGetTxResult(
+    env: JNIEnv,
+    _: JClass,
+    node_handle: Handle,
+    message_hash: jbyteArray,
+) -> jbyteArray {
+    use utils::{convert_to_hash, proto_to_java_bytes};
+    let res = panic::catch_unwind(|| {
+        let node = cast_handle::<Node>(node_handle);
+        let message_hash = unwrap_jni_verbose(&env, convert_to_hash(&env, message_hash));
+        let snapshot = node.create_snapshot();
+        let status = snapshot.for_core().transaction_results().get(&message_hash);
+        Ok(match status {
+            Some(status) => unwrap_jni_verbose(&env, proto_to_java_bytes(&env, status)),
+            None => ptr::null_mut(),
+        })
+    });
+    unwrap_exc_or(&env, res, ptr::null_mut())
+}
+
+/// Returns the current consensus configuration as a serialized `exonum.Config`
+/// protobuf message.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_service_NodeProxy_nativeGetConsensusConfiguration(
+    env: JNIEnv,
+    _: JClass,
+    node_handle: Handle,
+) -> jbyteArray {
+    use utils::proto_to_java_bytes;
+    let res = panic::catch_unwind(|| {
+        let node = cast_handle::<Node>(node_handle);
+        let snapshot = node.create_snapshot();
+        let config = snapshot.for_core().consensus_config();
+        Ok(unwrap_jni_verbose(&env, proto_to_java_bytes(&env, config)))
+    });
+    unwrap_exc_or(&env, res, ptr::null_mut())
+}
+
+/// Returns the ID of this node if it is a validator, or `-1` otherwise.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_service_NodeProxy_nativeGetValidatorId(
+    env: JNIEnv,
+    _: JClass,
+    node_handle: Handle,
+) -> jint {
+    let res = panic::catch_unwind(|| {
+        let node = cast_handle::<Node>(node_handle);
+        let snapshot = node.create_snapshot();
+        let validator_id = node
+            .validator_id(&*snapshot)
+            .map_or(-1, |id| jint::from(id.0));
+        Ok(validator_id)
+    });
+    unwrap_exc_or(&env, res, -1)
+}
+
+/// Returns the number of transactions in the pool of unconfirmed transactions.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_service_NodeProxy_nativeGetTransactionPoolSize(
+    env: JNIEnv,
+    _: JClass,
+    node_handle: Handle,
+) -> jlong {
+    let res = panic::catch_unwind(|| {
+        let node = cast_handle::<Node>(node_handle);
+        let snapshot = node.create_snapshot();
+        let pool_size = snapshot.for_core().transactions_pool_len();
+        Ok(pool_size as jlong)
+    });
+    unwrap_exc_or_default(&env, res)
+}
+
+/// Returns `true` if the transaction with the given message hash is in the pool
+/// of unconfirmed transactions.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_service_NodeProxy_nativeIsInTransactionPool(
+    env: JNIEnv,
+    _: JClass,
+    node_handle: Handle,
+    message_hash: jbyteArray,
+) -> jboolean {
+    use utils::convert_to_hash;
+    let res = panic::catch_unwind(|| {
+        let node = cast_handle::<Node>(node_handle);
+        let message_hash = unwrap_jni_verbose(&env, convert_to_hash(&env, message_hash));
+        let snapshot = node.create_snapshot();
+        let in_pool = snapshot
+            .for_core()
+            .transactions_pool()
+            .contains(&message_hash);
+        Ok(in_pool as jboolean)
+    });
+    unwrap_exc_or_default(&env, res)
+}
+
+/// Returns the transaction with the given message hash from the pool of unconfirmed
+/// transactions as a serialized `exonum.SignedMessage` protobuf message, or `null`
+/// if there is no such transaction in the pool.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_service_NodeProxy_nativeGetPendingTransaction(
+    env: JNIEnv,
+    _: JClass,
+    node_handle: Handle,
+    message_hash: jbyteArray,
+) -> jbyteArray {
+    use utils::convert_to_hash;
+    let res = panic::catch_unwind(|| {
+        let node = cast_handle::<Node>(node_handle);
+        let message_hash = unwrap_jni_verbose(&env, convert_to_hash(&env, message_hash));
+        let snapshot = node.create_snapshot();
+        let schema = snapshot.for_core();
+        if !schema.transactions_pool().contains(&message_hash) {
+            return Ok(ptr::null_mut());
+        }
+        let transaction = schema
+            .transactions()
+            .get(&message_hash)
+            .expect("No message of the transaction in the pool");
+        Ok(unwrap_jni_verbose(
+            &env,
+            env.byte_array_from_slice(&transaction.to_bytes()),
+        ))
+    });
+    unwrap_exc_or(&env, res, ptr::null_mut())
+}
+
+/// Returns the status of the transaction with the given message hash as a serialized
+/// `TransactionStatus` protobuf message.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_service_NodeProxy_nativeGetTransactionStatus(
+    env: JNIEnv,
+    _: JClass,
+    node_handle: Handle,
+    message_hash: jbyteArray,
+) -> jbyteArray {
+    use utils::convert_to_hash;
+    let res = panic::catch_unwind(|| {
+        let node = cast_handle::<Node>(node_handle);
+        let message_hash = unwrap_jni_verbose(&env, convert_to_hash(&env, message_hash));
+        let snapshot = node.create_snapshot();
+        let status = transaction_status(&*snapshot, &message_hash);
+        let status = status
+            .write_to_bytes()
+            .expect("Unable to serialize the transaction status");
+        Ok(unwrap_jni_verbose(&env, env.byte_array_from_slice(&status)))
+    });
+    unwrap_exc_or(&env, res, ptr::null_mut())
+}
+
+/// Looks up the status of the transaction in the transaction locations and the transaction pool
+/// of the core schema.
+fn transaction_status(snapshot: &dyn Snapshot, message_hash: &Hash) -> proto::TransactionStatus {
+    let schema = snapshot.for_core();
+    let mut status = proto::TransactionStatus::new();
+    if let Some(location) = schema.transactions_locations().get(message_hash) {
+        let execution_status = schema
+            .transaction_results()
+            .get(message_hash)
+            .expect("No execution status of the committed transaction");
+        let mut committed = proto::CommittedTransaction::new();
+        committed.set_height(location.block_height().into());
+        committed.set_position_in_block(location.position_in_block());
+        committed.set_execution_status(execution_status.to_bytes());
+        status.set_committed(committed);
+    } else if schema.transactions_pool().contains(message_hash) {
+        status.set_in_pool(proto::TransactionInPool::new());
+    }
+    status
+}
+
 /// Creates a new snapshot of the current database state.
 ///
 /// The snapshot must be explicitly destroyed by the caller from Java.
@@ -160,6 +783,95 @@ pub extern "system" fn Java_com_exonum_binding_core_service_NodeProxy_nativeGetP
     unwrap_exc_or(&env, res, ptr::null_mut())
 }
 
+/// Signs the given data with the service secret key of this node. Returns an Ed25519
+/// signature as a byte array.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_service_NodeProxy_nativeSign(
+    env: JNIEnv,
+    _: JClass,
+    node_handle: Handle,
+    data: jbyteArray,
+) -> jbyteArray {
+    let res = panic::catch_unwind(|| {
+        let node = cast_handle::<Node>(node_handle);
+        let signature = unwrap_jni_verbose(
+            &env,
+            || -> JniResult<jbyteArray> {
+                let data = env.convert_byte_array(data)?;
+                let signature = node.sign(&data);
+                env.byte_array_from_slice(signature.as_ref())
+            }(),
+        );
+        Ok(signature)
+    });
+    unwrap_exc_or(&env, res, ptr::null_mut())
+}
+
+/// Verifies the Ed25519 signature of the given data with the service public key of this node.
+/// Returns `false` if the signature is invalid or has an incorrect length.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_service_NodeProxy_nativeVerify(
+    env: JNIEnv,
+    _: JClass,
+    node_handle: Handle,
+    data: jbyteArray,
+    signature: jbyteArray,
+) -> jboolean {
+    let res = panic::catch_unwind(|| {
+        let node = cast_handle::<Node>(node_handle);
+        let valid = unwrap_jni_verbose(
+            &env,
+            || -> JniResult<bool> {
+                let data = env.convert_byte_array(data)?;
+                let signature = env.convert_byte_array(signature)?;
+                Ok(Signature::from_slice(&signature)
+                    .map_or(false, |signature| node.verify(&data, &signature)))
+            }(),
+        );
+        Ok(valid as jboolean)
+    });
+    unwrap_exc_or_default(&env, res)
+}
+
+/// Registers the block commit listener. Returns the identifier of the listener.
+///
+/// Parameters:
+/// - `node_handle` - a native handle to the native node object
+/// - `listener` - an instance of `NativeBlockCommitListener`
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_service_NodeProxy_nativeAddBlockCommitListener(
+    env: JNIEnv,
+    _: JClass,
+    node_handle: Handle,
+    listener: JObject,
+) -> jlong {
+    let res = panic::catch_unwind(|| {
+        let node = cast_handle::<Node>(node_handle);
+        let id = unwrap_jni_verbose(&env, node.block_commit_listeners().add(&env, listener));
+        Ok(id as jlong)
+    });
+    unwrap_exc_or_default(&env, res)
+}
+
+/// Removes the block commit listener with the given identifier. Returns `false`
+/// if there is no such listener.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_service_NodeProxy_nativeRemoveBlockCommitListener(
+    env: JNIEnv,
+    _: JClass,
+    node_handle: Handle,
+    listener_id: jlong,
+) -> jboolean {
+    let res = panic::catch_unwind(|| {
+        let node = cast_handle::<Node>(node_handle);
+        let removed = node
+            .block_commit_listeners()
+            .remove(listener_id as ListenerId);
+        Ok(removed as jboolean)
+    });
+    unwrap_exc_or_default(&env, res)
+}
+
 /// Destroys node context.
 #[no_mangle]
 pub extern "system" fn Java_com_exonum_binding_core_service_NodeProxy_nativeFree(
@@ -169,3 +881,41 @@ pub extern "system" fn Java_com_exonum_binding_core_service_NodeProxy_nativeFree
 ) {
     drop_handle::<Node>(&env, node_handle);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_active_instance() {
+        assert!(check_instance_status(1, Some(InstanceStatus::Active)).is_ok());
+    }
+
+    #[test]
+    fn check_inactive_instance() {
+        let err = check_instance_status(1, None).unwrap_err();
+        match err {
+            SubmissionError::InactiveService(id) => assert_eq!(id, 1),
+            _ => panic!("Unexpected error: {}", err),
+        }
+        assert_eq!(err.code(), 2);
+    }
+
+    #[test]
+    fn check_arguments_of_max_length() {
+        assert!(check_arguments_length(10, 10).is_ok());
+    }
+
+    #[test]
+    fn check_too_large_arguments() {
+        let err = check_arguments_length(11, 10).unwrap_err();
+        match err {
+            SubmissionError::ArgumentsTooLarge { length, max_length } => {
+                assert_eq!(length, 11);
+                assert_eq!(max_length, 10);
+            }
+            _ => panic!("Unexpected error: {}", err),
+        }
+        assert_eq!(err.code(), 3);
+    }
+}
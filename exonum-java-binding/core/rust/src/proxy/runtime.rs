@@ -16,33 +16,40 @@
 
 use exonum::{
     blockchain::Blockchain,
-    crypto::{Hash, PublicKey},
+    crypto::Hash,
     exonum_merkledb::{self, Snapshot},
     messages::BinaryValue,
     runtime::{
-        ArtifactId, CallInfo, Caller, ErrorKind, ExecutionContext, ExecutionError, InstanceId,
-        InstanceSpec, Mailbox, Runtime, RuntimeIdentifier, SnapshotExt, StateHashAggregator,
-        WellKnownRuntime,
+        Action, ArtifactId, CallInfo, Caller, ErrorKind, ExecutionContext, ExecutionError,
+        InstanceId, InstanceSpec, Mailbox, Runtime, RuntimeIdentifier, SnapshotExt,
+        StateHashAggregator, WellKnownRuntime,
     },
 };
 use exonum_proto::ProtobufConvert;
-use futures::{Future, IntoFuture};
+use futures::{sync::oneshot, Future, IntoFuture};
 use jni::{
     objects::{GlobalRef, JObject, JValue},
     signature::{JavaType, Primitive},
-    sys::jint,
     Executor, JNIEnv,
 };
+use parking_lot::{Mutex, RwLock};
+use protobuf::{self, Message};
 
-use std::fmt;
+use std::{collections::HashSet, fmt, process, sync::Arc, time::Duration};
 
 use {
     proto,
+    proxy::{
+        metrics::{CallKey, RuntimeMetrics},
+        watchdog::Watchdog,
+        worker::Worker,
+    },
     runtime::Error,
     storage::View,
     to_handle,
     utils::{
-        describe_java_exception, get_and_clear_java_exception, get_exception_message,
+        describe_java_exception, describe_java_exception_verbose, get_and_clear_java_exception,
+        get_current_thread_name, get_exception_message,
         jni_cache::{classes_refs, runtime_adapter, tx_execution_exception},
         panic_on_exception, unwrap_jni,
     },
@@ -53,13 +60,24 @@ use {
 const DEFAULT_VALIDATOR_ID: i32 = -1;
 /// Java Runtime ID.
 pub const JAVA_RUNTIME_ID: u32 = RuntimeIdentifier::Java as u32;
+/// Default maximum time the deployment of a single artifact may take.
+pub const DEFAULT_DEPLOY_TIMEOUT: Duration = Duration::from_secs(60);
 
 /// A proxy for `ServiceRuntimeAdapter`s.
 #[derive(Clone)]
 pub struct JavaRuntimeProxy {
     exec: Executor,
     runtime_adapter: GlobalRef,
-    blockchain: Option<Blockchain>,
+    node: Option<Node>,
+    deploy_timeout: Duration,
+    execution_timeout: Option<Duration>,
+    metrics: RuntimeMetrics,
+    deployed_artifacts: Arc<RwLock<HashSet<JavaArtifactId>>>,
+    /// The artifacts which deployment has not yet completed in the Java runtime,
+    /// including the ones already reported as failed on the timeout.
+    pending_deploys: Arc<RwLock<HashSet<JavaArtifactId>>>,
+    deploy_worker: Worker,
+    watchdog: Watchdog,
 }
 
 impl JavaRuntimeProxy {
@@ -68,10 +86,27 @@ impl JavaRuntimeProxy {
         JavaRuntimeProxy {
             exec: executor,
             runtime_adapter: adapter,
-            blockchain: None,
+            node: None,
+            deploy_timeout: DEFAULT_DEPLOY_TIMEOUT,
+            execution_timeout: None,
+            metrics: RuntimeMetrics::new(),
+            deployed_artifacts: Arc::default(),
+            pending_deploys: Arc::default(),
+            deploy_worker: Worker::new("java-deploy-worker"),
+            watchdog: Watchdog::default(),
         }
     }
 
+    /// Sets the maximum time the deployment of a single artifact may take.
+    ///
+    /// If the deployment does not complete in time, it is reported as failed. If the Java
+    /// runtime completes such deployment later, the artifact is unloaded, so that it does not
+    /// become deployed after the failure has been reported.
+    pub fn with_deploy_timeout(mut self, deploy_timeout: Duration) -> Self {
+        self.deploy_timeout = deploy_timeout;
+        self
+    }
+
     fn parse_artifact(&self, artifact: &ArtifactId) -> Result<JavaArtifactId, ExecutionError> {
         if artifact.runtime_id != JAVA_RUNTIME_ID {
             Err(Error::IncorrectArtifactId.into())
@@ -80,41 +115,106 @@ impl JavaRuntimeProxy {
         }
     }
 
-    /// If the current node is a validator, returns its ID, otherwise returns `-1`.
-    fn validator_id(snapshot: &dyn Snapshot, pub_key: &PublicKey) -> i32 {
-        snapshot
-            .for_core()
-            .consensus_config()
-            .find_validator(|validator_keys| *pub_key == validator_keys.service_key)
-            .map_or(DEFAULT_VALIDATOR_ID, |id| i32::from(id.0))
+    /// Deploys the artifact in the Java runtime, blocking the current thread.
+    fn deploy_artifact_sync(
+        &self,
+        artifact_id: String,
+        deploy_spec: &[u8],
+    ) -> Result<(), ExecutionError> {
+        self.jni_call(|env| {
+            let artifact_id = JObject::from(env.new_string(artifact_id)?);
+            let spec = JObject::from(env.byte_array_from_slice(deploy_spec)?);
+
+            env.call_method_unchecked(
+                self.runtime_adapter.as_obj(),
+                runtime_adapter::deploy_artifact_id(),
+                JavaType::Primitive(Primitive::Void),
+                &[JValue::from(artifact_id), JValue::from(spec)],
+            )
+            .and_then(JValue::v)
+        })
+    }
+
+    /// Unloads the artifact from the Java runtime, logging any errors.
+    fn unload_artifact(&self, artifact: &JavaArtifactId) {
+        let result = self.jni_call(|env| {
+            let artifact_id = JObject::from(env.new_string(artifact.to_string())?);
+
+            env.call_method_unchecked(
+                self.runtime_adapter.as_obj(),
+                runtime_adapter::unload_artifact_id(),
+                JavaType::Primitive(Primitive::Void),
+                &[JValue::from(artifact_id)],
+            )
+            .and_then(JValue::v)
+        });
+        if let Err(err) = result {
+            error!("Unable to unload the artifact {}: {}", artifact, err);
+        }
+    }
+
+    /// Checks whether the artifact is deployed by querying the Java runtime.
+    #[cfg(debug_assertions)]
+    fn is_artifact_deployed_in_java(&self, artifact: &JavaArtifactId) -> bool {
+        unwrap_jni(self.exec.with_attached(|env| {
+            let artifact_id = JObject::from(env.new_string(artifact.to_string())?);
+
+            panic_on_exception(
+                env,
+                env.call_method_unchecked(
+                    self.runtime_adapter.as_obj(),
+                    runtime_adapter::is_artifact_deployed_id(),
+                    JavaType::Primitive(Primitive::Boolean),
+                    &[JValue::from(artifact_id)],
+                ),
+            )
+            .z()
+        }))
+    }
+
+    /// Sets the maximum time the execution of a single service method may take.
+    ///
+    /// If the execution does not complete in time, the node is stopped. The execution result
+    /// is not affected by the timeout, as it depends on the wall-clock time of the node and
+    /// would make the nodes disagree on the result (see `RuntimeConfig::execution_timeout`).
+    pub fn with_execution_timeout(mut self, execution_timeout: Duration) -> Self {
+        self.execution_timeout = Some(execution_timeout);
+        self
+    }
+
+    /// Returns the metrics of the calls of the Java runtime made through this proxy.
+    pub fn metrics(&self) -> &RuntimeMetrics {
+        &self.metrics
+    }
+
+    /// Records the metrics of the calls of the commit hooks of each service, which the Java
+    /// runtime measures itself. An exception thrown by a service is counted as
+    /// `Error::JavaException`.
+    fn observe_service_calls(&self, operation: &'static str, calls: &[ServiceCallMetrics]) {
+        for call in calls {
+            let error = if call.failed {
+                Some(ErrorKind::runtime(Error::JavaException as u8))
+            } else {
+                None
+            };
+            self.metrics.observe(
+                CallKey::service(operation, call.instance_id),
+                Duration::from_nanos(call.duration_nanos),
+                error.as_ref(),
+            );
+        }
     }
 
     /// Handles and clears any Java exceptions or other JNI errors.
     ///
     /// Any JNI errors are converted into `ExecutionError` with their descriptions, for JNI errors
     /// like `JniErrorKind::JavaException` it gets (and clears) any exception that is currently
-    /// being thrown, then exception is passed to corresponding `ExceptionHandler` according their
-    /// type and `exception_handlers` mapping.
-    /// `ExceptionHandlers::DEFAULT` is called in case of there is no any handlers or handlers are
-    /// not matched to exception type.
-    fn handle_error_or_exception<H, R>(
-        env: &JNIEnv,
-        err: JniError,
-        exception_handlers: &[(&GlobalRef, H)],
-    ) -> ExecutionError
-    where
-        H: Fn(&JNIEnv, JObject) -> ExecutionError,
-    {
+    /// being thrown, then the exception is converted by `ExceptionHandlers::convert`.
+    fn handle_error_or_exception(env: &JNIEnv, err: JniError) -> ExecutionError {
         match err.kind() {
             JniErrorKind::JavaException => {
                 let exception = get_and_clear_java_exception(env);
-                for (class, handler) in exception_handlers {
-                    if unwrap_jni(env.is_instance_of(exception, *class)) {
-                        return handler(env, exception);
-                    }
-                }
-
-                ExceptionHandlers::DEFAULT(env, exception)
+                ExceptionHandlers::convert(env, exception)
             }
             _ => (Error::OtherJniError, err).into(),
         }
@@ -124,30 +224,10 @@ impl JavaRuntimeProxy {
     ///
     /// Any JNI errors are converted into `ExecutionError` with their descriptions, for JNI errors
     /// like `JniErrorKind::JavaException` it gets (and clears) any exception that is currently
-    /// being thrown, `ExceptionHandlers::DEFAULT` is applied for such exceptions.
-    fn jni_call_default<F, R>(&self, f: F) -> Result<R, ExecutionError>
+    /// being thrown, `ExceptionHandlers::convert` is applied for such exceptions.
+    fn jni_call<F, R>(&self, f: F) -> Result<R, ExecutionError>
     where
         F: FnOnce(&JNIEnv) -> JniResult<R>,
-    {
-        self.jni_call::<F, &ExceptionHandler, R>(&[], f)
-    }
-
-    /// Executes closure `f` and handles any type of JNI errors from it.
-    ///
-    /// Any JNI errors are converted into `ExecutionError` with their descriptions, for JNI errors
-    /// like `JniErrorKind::JavaException` it gets (and clears) any exception that is currently
-    /// being thrown, then exception is passed to corresponding `ExceptionHandler` according their
-    /// type and `exception_handlers` mapping.
-    /// `ExceptionHandlers::DEFAULT` is called in case of there is no any handlers or handlers are
-    /// not matched to exception type.
-    fn jni_call<F, H, R>(
-        &self,
-        exception_handlers: &[(&GlobalRef, H)],
-        f: F,
-    ) -> Result<R, ExecutionError>
-    where
-        F: FnOnce(&JNIEnv) -> JniResult<R>,
-        H: Fn(&JNIEnv, JObject) -> ExecutionError,
     {
         let mut execution_error: Option<ExecutionError> = None;
 
@@ -159,11 +239,7 @@ impl JavaRuntimeProxy {
         let result = self.exec.with_attached(|env| match f(env) {
             Ok(value) => Ok(Some(value)),
             Err(err) => {
-                execution_error = Some(Self::handle_error_or_exception::<H, R>(
-                    env,
-                    err,
-                    exception_handlers,
-                ));
+                execution_error = Some(Self::handle_error_or_exception(env, err));
                 Ok(None)
             }
         });
@@ -187,10 +263,11 @@ impl JavaRuntimeProxy {
 
 impl Runtime for JavaRuntimeProxy {
     fn initialize(&mut self, blockchain: &Blockchain) {
-        self.blockchain = Some(blockchain.clone());
+        let node = Node::new(blockchain.clone());
+        self.node = Some(node.clone());
 
         unwrap_jni(self.exec.with_attached(|env| {
-            let node_handle = to_handle(Node::new(blockchain.clone()));
+            let node_handle = to_handle(node);
 
             env.call_method_unchecked(
                 self.runtime_adapter.as_obj(),
@@ -208,48 +285,76 @@ impl Runtime for JavaRuntimeProxy {
         deploy_spec: Vec<u8>,
     ) -> Box<dyn Future<Item = (), Error = ExecutionError>> {
         let id = match self.parse_artifact(&artifact) {
-            Ok(id) => id.to_string(),
+            Ok(id) => id,
             Err(err) => return Box::new(Err(err).into_future()),
         };
 
-        let result = self.jni_call_default(|env| {
-            let artifact_id = JObject::from(env.new_string(id)?);
-            let spec = JObject::from(env.byte_array_from_slice(&deploy_spec)?);
-
-            env.call_method_unchecked(
-                self.runtime_adapter.as_obj(),
-                runtime_adapter::deploy_artifact_id(),
-                JavaType::Primitive(Primitive::Void),
-                &[JValue::from(artifact_id), JValue::from(spec)],
-            )
-            .and_then(JValue::v)
-        });
+        self.pending_deploys.write().insert(id.clone());
+        let runtime = self.clone();
+        let deploy_id = id.clone();
+        let deploy = move || {
+            let result = runtime
+                .metrics
+                .measure(CallKey::runtime("deploy_artifact"), || {
+                    runtime.deploy_artifact_sync(deploy_id.to_string(), &deploy_spec)
+                });
+            if result.is_err() {
+                runtime.pending_deploys.write().remove(&deploy_id);
+            }
+            result
+        };
+        let runtime = self.clone();
+        let deployed_id = id.clone();
+        let on_deployed = move |in_time| {
+            if in_time {
+                runtime
+                    .deployed_artifacts
+                    .write()
+                    .insert(deployed_id.clone());
+            } else {
+                warn!(
+                    "Unloading the artifact {} deployed after the timeout",
+                    deployed_id
+                );
+                runtime.unload_artifact(&deployed_id);
+            }
+            runtime.pending_deploys.write().remove(&deployed_id);
+        };
 
-        Box::new(result.map(|_| ()).into_future())
+        deploy_with_timeout(
+            &self.deploy_worker,
+            &self.watchdog,
+            self.deploy_timeout,
+            &id,
+            deploy,
+            on_deployed,
+        )
     }
 
     fn is_artifact_deployed(&self, id: &ArtifactId) -> bool {
         let artifact = match self.parse_artifact(id) {
-            Ok(id) => id.to_string(),
+            Ok(id) => id,
             Err(_) => {
                 return false;
             }
         };
 
-        unwrap_jni(self.exec.with_attached(|env| {
-            let artifact_id = JObject::from(env.new_string(artifact)?);
-
-            panic_on_exception(
-                env,
-                env.call_method_unchecked(
-                    self.runtime_adapter.as_obj(),
-                    runtime_adapter::is_artifact_deployed_id(),
-                    JavaType::Primitive(Primitive::Boolean),
-                    &[JValue::from(artifact_id)],
-                ),
-            )
-            .z()
-        }))
+        // The artifacts deployed after the timeout are unloaded from the Java runtime,
+        // therefore, the cache is consistent with it once their deployment completes.
+        #[cfg(debug_assertions)]
+        {
+            // Hold the lock so that no deployment of the artifact completes during the check
+            let pending_deploys = self.pending_deploys.read();
+            if !pending_deploys.contains(&artifact) {
+                debug_assert_eq!(
+                    self.deployed_artifacts.read().contains(&artifact),
+                    self.is_artifact_deployed_in_java(&artifact),
+                    "The deployment status of artifact {} differs from the one in the Java runtime",
+                    artifact
+                );
+            }
+        }
+        self.deployed_artifacts.read().contains(&artifact)
     }
 
     fn start_adding_service(
@@ -260,7 +365,7 @@ impl Runtime for JavaRuntimeProxy {
     ) -> Result<(), ExecutionError> {
         let serialized_instance_spec: Vec<u8> = spec.to_bytes();
 
-        self.jni_call_default(|env| {
+        self.jni_call(|env| {
             let fork_handle = to_handle(View::from_ref_mut_fork(context.fork));
             let instance_spec =
                 JObject::from(env.byte_array_from_slice(&serialized_instance_spec)?);
@@ -280,13 +385,18 @@ impl Runtime for JavaRuntimeProxy {
         })
     }
 
+    // TODO: Forward the instance status changes (active -> stopped -> active) to the Java
+    //  runtime, so that stopped services unregister their API and do not receive the commit
+    //  notifications. The `Runtime` trait of Exonum 0.13 has no hook for that: the instances
+    //  cannot be stopped yet, and the only notifications are `start_adding_service` and
+    //  `commit_service`.
     fn commit_service(
         &mut self,
         _snapshot: &dyn Snapshot,
         instance_spec: &InstanceSpec,
     ) -> Result<(), ExecutionError> {
         let serialized_instance_spec: Vec<u8> = instance_spec.to_bytes();
-        self.jni_call_default(|env| {
+        self.jni_call(|env| {
             let instance_spec =
                 JObject::from(env.byte_array_from_slice(&serialized_instance_spec)?);
 
@@ -306,56 +416,51 @@ impl Runtime for JavaRuntimeProxy {
         call_info: &CallInfo,
         arguments: &[u8],
     ) -> Result<(), ExecutionError> {
-        // todo: Replace this abomination (8-parameter method, arguments that make sense only
-        //   in some cases) with a single protobuf message or other alternative [ECR-3872]
-        let tx_info: (InstanceId, Hash, PublicKey) = match context.caller {
-            Caller::Transaction {
-                hash: message_hash,
-                author: author_pk,
-            } => (0, message_hash, author_pk),
-            Caller::Service {
-                instance_id: caller_id,
-            } => (caller_id, Hash::default(), PublicKey::default()),
-            Caller::Blockchain => {
-                return Err(Error::NotSupportedOperation.into());
-            }
-        };
+        let execution_context = execution_context_to_proto(
+            &context.caller,
+            context.interface_name,
+            call_info,
+            arguments,
+        )
+        .write_to_bytes()
+        .expect("Failed to serialize the execution context");
 
-        self.jni_call(
-            &[(
-                &classes_refs::transaction_execution_exception(),
-                ExceptionHandlers::TX_EXECUTION,
-            )],
-            |env| {
-                let service_id = call_info.instance_id as i32;
-                let interface_name = JObject::from(env.new_string(context.interface_name)?);
-                let tx_id = call_info.method_id as i32;
-                let args = JObject::from(env.byte_array_from_slice(arguments)?);
+        let key = CallKey::method("execute", call_info.instance_id, call_info.method_id);
+        self.metrics.measure(key, || {
+            let deadline = self.execution_timeout.map(|timeout| {
+                let method_id = call_info.method_id;
+                let instance_id = call_info.instance_id;
+                self.watchdog.start(timeout, move || {
+                    error!(
+                        "Execution of method {} of service {} did not complete within {} ms, \
+                         stopping the node",
+                        method_id,
+                        instance_id,
+                        timeout.as_millis()
+                    );
+                    process::abort();
+                })
+            });
+
+            let result = self.jni_call(|env| {
+                let execution_context =
+                    JObject::from(env.byte_array_from_slice(&execution_context)?);
                 let view_handle = to_handle(View::from_ref_fork(context.fork));
-                let caller_id = tx_info.0;
-                let message_hash = tx_info.1.to_bytes();
-                let message_hash = JObject::from(env.byte_array_from_slice(&message_hash)?);
-                let author_pk = tx_info.2.to_bytes();
-                let author_pk = JObject::from(env.byte_array_from_slice(&author_pk)?);
 
                 env.call_method_unchecked(
                     self.runtime_adapter.as_obj(),
                     runtime_adapter::execute_tx_id(),
                     JavaType::Primitive(Primitive::Void),
-                    &[
-                        JValue::from(service_id),
-                        JValue::from(interface_name),
-                        JValue::from(tx_id),
-                        JValue::from(args),
-                        JValue::from(view_handle),
-                        JValue::from(caller_id as jint),
-                        JValue::from(message_hash),
-                        JValue::from(author_pk),
-                    ],
+                    &[JValue::from(execution_context), JValue::from(view_handle)],
                 )
                 .and_then(JValue::v)
-            },
-        )
+            });
+
+            if let Some(deadline) = deadline {
+                deadline.cancel();
+            }
+            result
+        })
     }
 
     fn state_hashes(&self, snapshot: &dyn Snapshot) -> StateHashAggregator {
@@ -375,10 +480,9 @@ impl Runtime for JavaRuntimeProxy {
 
             Ok(data)
         }));
-
-        ServiceRuntimeStateHashes::from_bytes(bytes.into())
-            .unwrap()
-            .into()
+        let state_hashes = ServiceRuntimeStateHashes::from_bytes(bytes.into()).unwrap();
+        self.observe_service_calls("state_hashes", &state_hashes.service_calls);
+        state_hashes.into()
     }
 
     fn before_commit(
@@ -386,49 +490,87 @@ impl Runtime for JavaRuntimeProxy {
         context: ExecutionContext,
         instance_id: InstanceId,
     ) -> Result<(), ExecutionError> {
-        self.jni_call_default(|env| {
-            let view_handle = to_handle(View::from_ref_mut_fork(context.fork));
+        let key = CallKey::service("before_commit", instance_id);
+        self.metrics.measure(key, || {
+            self.jni_call(|env| {
+                let view_handle = to_handle(View::from_ref_mut_fork(context.fork));
 
-            panic_on_exception(
-                env,
                 env.call_method_unchecked(
                     self.runtime_adapter.as_obj(),
                     runtime_adapter::before_commit_id(),
                     JavaType::Primitive(Primitive::Void),
                     &[JValue::from(instance_id as i32), JValue::from(view_handle)],
-                ),
-            );
-            Ok(())
+                )
+                .and_then(JValue::v)
+            })
         })
     }
 
-    fn after_commit(&mut self, snapshot: &dyn Snapshot, _mailbox: &mut Mailbox) {
-        unwrap_jni(self.exec.with_attached(|env| {
+    fn after_commit(&mut self, snapshot: &dyn Snapshot, mailbox: &mut Mailbox) {
+        let node = self
+            .node
+            .as_ref()
+            .expect("afterCommit called before initialize");
+        // If the current node is a validator, passes its ID, otherwise passes `-1`.
+        let validator_id = node
+            .validator_id(snapshot)
+            .map_or(DEFAULT_VALIDATOR_ID, |id| i32::from(id.0));
+        let core_schema = snapshot.for_core();
+        let block_height = core_schema.height();
+        let height: u64 = block_height.into();
+
+        let result = self.jni_call(|env| {
             let view_handle = to_handle(View::from_ref_snapshot(snapshot));
-            let public_key = self
-                .blockchain
-                .as_ref()
-                .expect("afterCommit called before initialize")
-                .service_keypair()
-                .0;
-            let validator_id = Self::validator_id(snapshot, &public_key);
-            let height: u64 = snapshot.for_core().height().into();
+            let result = env.call_method_unchecked(
+                self.runtime_adapter.as_obj(),
+                runtime_adapter::after_commit_id(),
+                JavaType::Array(Box::new(JavaType::Primitive(Primitive::Byte))),
+                &[
+                    JValue::from(view_handle),
+                    JValue::from(validator_id),
+                    JValue::from(height as i64),
+                ],
+            )?;
+            let byte_array = result.l()?.into_inner();
+            env.convert_byte_array(byte_array)
+        });
 
-            panic_on_exception(
-                env,
-                env.call_method_unchecked(
-                    self.runtime_adapter.as_obj(),
-                    runtime_adapter::after_commit_id(),
-                    JavaType::Primitive(Primitive::Void),
-                    &[
-                        JValue::from(view_handle),
-                        JValue::from(validator_id),
-                        JValue::from(height as i64),
-                    ],
-                ),
-            );
-            Ok(())
-        }));
+        // The listeners are notified once the services have handled the block. They are
+        // notified asynchronously, on their own thread.
+        let block_hash = core_schema
+            .block_hash_by_height(block_height)
+            .expect("No hash of the latest committed block");
+        node.block_commit_listeners()
+            .notify(block_height, block_hash);
+
+        // Exceptions of individual services are handled by the Java runtime, therefore
+        // the error can only come from the runtime itself. It must not stop the node.
+        let result = match result {
+            Ok(result) => result,
+            Err(err) => {
+                error!(
+                    "Java runtime failed to handle the block commit at height {}: {}",
+                    height, err
+                );
+                return;
+            }
+        };
+
+        match protobuf::parse_from_bytes::<proto::AfterCommitResult>(&result) {
+            Ok(mut result) => {
+                let service_calls: Vec<_> = result
+                    .take_service_calls()
+                    .into_iter()
+                    .map(|call| ServiceCallMetrics::from_pb(call).unwrap())
+                    .collect();
+                self.observe_service_calls("after_commit", &service_calls);
+                push_mailbox_actions(mailbox, result.take_actions());
+            }
+            Err(err) => error!(
+                "Java runtime returned an invalid result of the block commit at height {}: {}",
+                height, err
+            ),
+        }
     }
 
     fn shutdown(&mut self) {
@@ -485,11 +627,21 @@ impl From<&ServiceStateHashes> for (InstanceId, Vec<Hash>) {
     }
 }
 
+/// The metrics of a call of a service, measured by the Java runtime.
+#[derive(Serialize, Deserialize, Clone, ProtobufConvert, PartialEq)]
+#[protobuf_convert(source = "proto::ServiceCallMetrics")]
+struct ServiceCallMetrics {
+    instance_id: u32,
+    duration_nanos: u64,
+    failed: bool,
+}
+
 #[derive(Serialize, Deserialize, Clone, ProtobufConvert, BinaryValue, PartialEq)]
 #[protobuf_convert(source = "proto::ServiceRuntimeStateHashes")]
 struct ServiceRuntimeStateHashes {
     runtime_state_hashes: Vec<Vec<u8>>,
     service_state_hashes: Vec<ServiceStateHashes>,
+    service_calls: Vec<ServiceCallMetrics>,
 }
 
 impl ServiceRuntimeStateHashes {
@@ -517,26 +669,208 @@ impl From<ServiceRuntimeStateHashes> for StateHashAggregator {
     }
 }
 
+/// Runs the deployment on the deploy worker, reporting it as failed if it does not complete
+/// within the `timeout`. The timeout is counted from the start of the deployment
+/// on the worker, excluding the time it waits for the previous deployments.
+///
+/// `on_deployed` is invoked on the successful deployment with `true` if it has completed
+/// in time; with `false` if it has already been reported as failed.
+fn deploy_with_timeout<D, F>(
+    worker: &Worker,
+    watchdog: &Watchdog,
+    timeout: Duration,
+    artifact: &JavaArtifactId,
+    deploy: D,
+    on_deployed: F,
+) -> Box<dyn Future<Item = (), Error = ExecutionError>>
+where
+    D: FnOnce() -> Result<(), ExecutionError> + Send + 'static,
+    F: FnOnce(bool) + Send + 'static,
+{
+    let (result_sender, result_receiver) = oneshot::channel();
+    // The result is sent either by the worker or on the timeout, whichever comes first.
+    let result_sender = Arc::new(Mutex::new(Some(result_sender)));
+
+    let watchdog = watchdog.clone();
+    let timeout_message = format!(
+        "Deployment of artifact {} did not complete within {} seconds",
+        artifact,
+        timeout.as_secs()
+    );
+    worker.execute(move || {
+        let timeout_sender = result_sender.clone();
+        let deadline = watchdog.start(timeout, move || {
+            warn!("{}", timeout_message);
+            send_deploy_result(
+                &timeout_sender,
+                Err((Error::DeployTimeout, timeout_message).into()),
+            );
+        });
+
+        let result = deploy();
+        let timed_out = deadline.cancel();
+        if result.is_ok() {
+            on_deployed(!timed_out);
+        }
+        send_deploy_result(&result_sender, result);
+    });
+
+    Box::new(result_receiver.then(|result| {
+        match result {
+            Ok(result) => result,
+            Err(_) => Err((
+                Error::OtherJniError,
+                "Artifact deployment was interrupted unexpectedly",
+            )
+                .into()),
+        }
+    }))
+}
+
+/// Sends the result of the deployment unless it has already been sent.
+fn send_deploy_result(
+    sender: &Mutex<Option<oneshot::Sender<Result<(), ExecutionError>>>>,
+    result: Result<(), ExecutionError>,
+) {
+    if let Some(sender) = sender.lock().take() {
+        // The receiver is gone if the dispatcher no longer waits for the result.
+        let _ = sender.send(result);
+    }
+}
+
+/// Pushes the actions requested by Java services into the dispatcher mailbox.
+fn push_mailbox_actions(mailbox: &mut Mailbox, mut actions: proto::MailboxActions) {
+    for mut action in actions.take_actions().into_iter() {
+        if action.has_start_deploy() {
+            let mut start_deploy = action.take_start_deploy();
+            let artifact = ArtifactId {
+                runtime_id: start_deploy.get_runtime_id(),
+                name: start_deploy.take_artifact_name(),
+            };
+            let artifact_name = artifact.to_string();
+            mailbox.push(Action::StartDeploy {
+                artifact,
+                spec: start_deploy.take_deploy_spec(),
+                then: Box::new(move |result| {
+                    match result {
+                        Ok(()) => info!(
+                            "Deployed the artifact {} requested by a Java service",
+                            artifact_name
+                        ),
+                        Err(ref e) => error!(
+                            "Failed to deploy the artifact {} requested by a Java service: {}",
+                            artifact_name, e
+                        ),
+                    }
+                    result
+                }),
+            });
+        } else {
+            warn!("Ignoring an unknown mailbox action requested by a Java service");
+        }
+    }
+}
+
+/// Converts the information about a service method invocation into the protobuf message
+/// accepted by `ServiceRuntimeAdapter.executeTransaction`.
+fn execution_context_to_proto(
+    caller: &Caller,
+    interface_name: &str,
+    call_info: &CallInfo,
+    arguments: &[u8],
+) -> proto::ExecutionContext {
+    let mut message = proto::ExecutionContext::new();
+    message.set_service_id(call_info.instance_id);
+    message.set_interface_name(interface_name.to_owned());
+    message.set_method_id(call_info.method_id);
+    message.set_arguments(arguments.to_vec());
+    match *caller {
+        Caller::Transaction { hash, author } => {
+            let mut transaction = proto::TransactionCaller::new();
+            transaction.set_message_hash(hash.as_ref().to_vec());
+            transaction.set_author_pk(author.as_ref().to_vec());
+            message.set_transaction(transaction);
+        }
+        Caller::Service { instance_id } => {
+            let mut service = proto::ServiceCaller::new();
+            service.set_instance_id(instance_id);
+            message.set_service(service);
+        }
+        Caller::Blockchain => message.set_blockchain(proto::BlockchainCaller::new()),
+    }
+    message
+}
+
 fn to_hash(bytes: &[u8]) -> Hash {
     Hash::from_bytes(bytes.into()).unwrap()
 }
 
-type ExceptionHandler = Fn(&JNIEnv, JObject) -> ExecutionError;
+/// Converts a Java exception of a particular class into an execution error.
+type ExceptionConverter = fn(&JNIEnv, JObject) -> JniResult<ExecutionError>;
+
+/// The Java exception classes with the converters of their instances, in the order
+/// of precedence. The exceptions of other classes are converted into `Error::JavaException`.
+const EXCEPTION_CONVERTERS: &[(fn() -> GlobalRef, ExceptionConverter)] = &[
+    (
+        classes_refs::transaction_execution_exception,
+        ExceptionHandlers::service_error,
+    ),
+    (
+        classes_refs::java_lang_error,
+        ExceptionHandlers::panic_error,
+    ),
+];
+
 struct ExceptionHandlers;
 
 impl ExceptionHandlers {
-    const DEFAULT: &'static ExceptionHandler = &|env, exception| {
+    /// Converts the exception with the converter registered for its class
+    /// in `EXCEPTION_CONVERTERS`, or into `Error::JavaException` if there is no such converter.
+    ///
+    /// The error descriptions are recorded in the blockchain, therefore, they include only
+    /// the class name and the message of the exception, which are the same on any node.
+    /// The cause chain, the stack trace and the thread name are logged instead.
+    fn convert(env: &JNIEnv, exception: JObject) -> ExecutionError {
         assert!(!exception.is_null(), "No exception thrown.");
-        let message = describe_java_exception(env, exception);
-        (Error::JavaException, message).into()
-    };
+        let converter = EXCEPTION_CONVERTERS
+            .iter()
+            .find(|(class, _)| unwrap_jni(env.is_instance_of(exception, &class())))
+            .map(|(_, converter)| *converter);
+        match converter {
+            Some(converter) => unwrap_jni(converter(env, exception)),
+            None => {
+                Self::log_exception(env, exception);
+                let description = describe_java_exception(env, exception);
+                (Error::JavaException, description).into()
+            }
+        }
+    }
 
-    const TX_EXECUTION: &'static ExceptionHandler = &|env, exception| {
-        assert!(!exception.is_null(), "No exception thrown.");
-        let code = unwrap_jni(Self::get_tx_error_code(env, exception)) as u8;
-        let msg = unwrap_jni(get_exception_message(env, exception)).unwrap_or_default();
-        ExecutionError::new(ErrorKind::service(code), msg)
-    };
+    /// Converts `TransactionExecutionException` into a service error. The exception message
+    /// is used as the description, being a part of the service API.
+    fn service_error(env: &JNIEnv, exception: JObject) -> JniResult<ExecutionError> {
+        let code = Self::get_tx_error_code(env, exception)? as u8;
+        let message = get_exception_message(env, exception)?.unwrap_or_default();
+        Ok(ExecutionError::new(ErrorKind::service(code), message))
+    }
+
+    /// Converts `java.lang.Error` into a panic as it indicates a serious problem
+    /// which services are not expected to handle.
+    fn panic_error(env: &JNIEnv, exception: JObject) -> JniResult<ExecutionError> {
+        Self::log_exception(env, exception);
+        let description = describe_java_exception(env, exception);
+        Ok(ExecutionError::new(ErrorKind::Panic, description))
+    }
+
+    /// Logs the exception with its cause chain, truncated stack traces and the name
+    /// of the thread in which it occurred.
+    fn log_exception(env: &JNIEnv, exception: JObject) {
+        warn!(
+            "Unexpected Java exception in thread {:?}: {}",
+            get_current_thread_name(env),
+            describe_java_exception_verbose(env, exception)
+        );
+    }
 
     fn get_tx_error_code(env: &JNIEnv, exception: JObject) -> JniResult<i8> {
         let err_code = env.call_method_unchecked(
@@ -548,3 +882,155 @@ impl ExceptionHandlers {
         err_code.b()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use exonum::crypto::{gen_keypair, hash};
+    use std::{sync::mpsc, thread};
+
+    const ARGUMENTS: &[u8] = &[1, 2, 3];
+
+    #[test]
+    fn deploy_completed_in_time() {
+        let deployed = Arc::new(Mutex::new(Vec::new()));
+        let on_deployed = {
+            let deployed = deployed.clone();
+            move |in_time| deployed.lock().push(in_time)
+        };
+
+        let result = deploy_with_timeout(
+            &Worker::new("test-deploy-worker"),
+            &Watchdog::default(),
+            Duration::from_secs(60),
+            &artifact_id(),
+            || Ok(()),
+            on_deployed,
+        )
+        .wait();
+
+        assert!(result.is_ok());
+        assert_eq!(*deployed.lock(), vec![true]);
+    }
+
+    #[test]
+    fn deploy_failed_in_time() {
+        let deployed = Arc::new(Mutex::new(Vec::new()));
+        let on_deployed = {
+            let deployed = deployed.clone();
+            move |in_time| deployed.lock().push(in_time)
+        };
+
+        let result = deploy_with_timeout(
+            &Worker::new("test-deploy-worker"),
+            &Watchdog::default(),
+            Duration::from_secs(60),
+            &artifact_id(),
+            || Err(Error::JavaException.into()),
+            on_deployed,
+        )
+        .wait();
+
+        assert_eq!(result.unwrap_err().kind, Error::JavaException.into());
+        assert!(deployed.lock().is_empty());
+    }
+
+    #[test]
+    fn deploy_timed_out() {
+        let worker = Worker::new("test-deploy-worker");
+        let (deployed_tx, deployed_rx) = mpsc::channel();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        // Emulate a slow deployment that completes once the timeout is reported
+        let deploy = move || {
+            release_rx.recv().unwrap();
+            Ok(())
+        };
+
+        let result = deploy_with_timeout(
+            &worker,
+            &Watchdog::default(),
+            Duration::from_millis(1),
+            &artifact_id(),
+            deploy,
+            move |in_time| deployed_tx.send(in_time).unwrap(),
+        )
+        .wait();
+
+        assert_eq!(result.unwrap_err().kind, Error::DeployTimeout.into());
+        release_tx.send(()).unwrap();
+        // The deployment completed after the timeout must be reported as late
+        assert_eq!(deployed_rx.recv().unwrap(), false);
+    }
+
+    #[test]
+    fn deploy_timeout_excludes_queueing() {
+        let worker = Worker::new("test-deploy-worker");
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        // Occupy the worker with a previous job that outlasts the timeout
+        worker.execute(move || release_rx.recv().unwrap());
+
+        let result = deploy_with_timeout(
+            &worker,
+            &Watchdog::default(),
+            Duration::from_millis(100),
+            &artifact_id(),
+            || Ok(()),
+            |_| {},
+        );
+        thread::sleep(Duration::from_millis(300));
+        release_tx.send(()).unwrap();
+
+        assert!(result.wait().is_ok());
+    }
+
+    #[test]
+    fn execution_context_transaction_caller() {
+        let hash = hash(&[0, 1]);
+        let author = gen_keypair().0;
+        let caller = Caller::Transaction { hash, author };
+
+        let message = execution_context_to_proto(&caller, "", &call_info(), ARGUMENTS);
+
+        check_call_info(&message);
+        assert!(message.has_transaction());
+        assert_eq!(message.get_transaction().get_message_hash(), hash.as_ref());
+        assert_eq!(message.get_transaction().get_author_pk(), author.as_ref());
+    }
+
+    #[test]
+    fn execution_context_service_caller() {
+        let caller = Caller::Service { instance_id: 5 };
+
+        let message = execution_context_to_proto(&caller, "", &call_info(), ARGUMENTS);
+
+        check_call_info(&message);
+        assert!(message.has_service());
+        assert_eq!(message.get_service().get_instance_id(), 5);
+    }
+
+    #[test]
+    fn execution_context_blockchain_caller() {
+        let message = execution_context_to_proto(&Caller::Blockchain, "", &call_info(), ARGUMENTS);
+
+        check_call_info(&message);
+        assert!(message.has_blockchain());
+    }
+
+    fn artifact_id() -> JavaArtifactId {
+        JavaArtifactId("com.acme:foo:1.0.0".to_owned())
+    }
+
+    fn call_info() -> CallInfo {
+        CallInfo {
+            instance_id: 1,
+            method_id: 2,
+        }
+    }
+
+    fn check_call_info(message: &proto::ExecutionContext) {
+        assert_eq!(message.get_service_id(), 1);
+        assert_eq!(message.get_interface_name(), "");
+        assert_eq!(message.get_method_id(), 2);
+        assert_eq!(message.get_arguments(), ARGUMENTS);
+    }
+}
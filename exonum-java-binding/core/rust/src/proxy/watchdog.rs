@@ -0,0 +1,224 @@
+/*
+ * Copyright 2019 The Exonum Team
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use parking_lot::Mutex;
+
+use std::{
+    collections::BTreeMap,
+    sync::{
+        mpsc::{self, Receiver, RecvTimeoutError, Sender},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+type Callback = Box<dyn FnOnce() + Send>;
+type DeadlineId = u64;
+
+/// Invokes callbacks on expiration of their deadlines.
+///
+/// All the deadlines are tracked by a single thread, which is started on the registration
+/// of the first deadline and stopped once the watchdog and all its clones are dropped.
+/// The callbacks are invoked on this thread one at a time, therefore, they must be quick.
+#[derive(Clone, Default)]
+pub(crate) struct Watchdog {
+    inner: Arc<Mutex<Inner>>,
+}
+
+#[derive(Default)]
+struct Inner {
+    next_id: DeadlineId,
+    commands: Option<Sender<Command>>,
+}
+
+enum Command {
+    Start {
+        id: DeadlineId,
+        expires_at: Instant,
+        on_expiry: Callback,
+    },
+    Cancel {
+        id: DeadlineId,
+        expired: Sender<bool>,
+    },
+}
+
+/// A deadline registered in the `Watchdog`.
+///
+/// The callback of the deadline is invoked on its expiration unless the deadline is cancelled.
+pub(crate) struct Deadline {
+    id: DeadlineId,
+    commands: Sender<Command>,
+}
+
+impl Watchdog {
+    /// Registers a deadline expiring after the `timeout`, invoking `on_expiry` unless cancelled
+    /// before that.
+    pub fn start<F>(&self, timeout: Duration, on_expiry: F) -> Deadline
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let expires_at = Instant::now() + timeout;
+        let mut inner = self.inner.lock();
+        let commands = inner
+            .commands
+            .get_or_insert_with(spawn_watchdog_thread)
+            .clone();
+
+        let id = inner.next_id;
+        inner.next_id += 1;
+        let command = Command::Start {
+            id,
+            expires_at,
+            on_expiry: Box::new(on_expiry),
+        };
+        commands.send(command).expect("Watchdog thread is stopped");
+        Deadline { id, commands }
+    }
+}
+
+impl Deadline {
+    /// Cancels the deadline, returning `true` if it has already expired.
+    ///
+    /// Once this method returns, the callback of the deadline is either completed
+    /// or will never be invoked.
+    pub fn cancel(self) -> bool {
+        let (expired_tx, expired_rx) = mpsc::channel();
+        let command = Command::Cancel {
+            id: self.id,
+            expired: expired_tx,
+        };
+        self.commands
+            .send(command)
+            .expect("Watchdog thread is stopped");
+        expired_rx.recv().expect("Watchdog thread is stopped")
+    }
+}
+
+fn spawn_watchdog_thread() -> Sender<Command> {
+    let (commands_tx, commands_rx) = mpsc::channel();
+    thread::Builder::new()
+        .name("java-runtime-watchdog".to_owned())
+        .spawn(move || run_watchdog(commands_rx))
+        .expect("Unable to spawn the watchdog thread");
+    commands_tx
+}
+
+fn run_watchdog(commands: Receiver<Command>) {
+    // The pending deadlines ordered by the expiration time.
+    let mut pending: BTreeMap<(Instant, DeadlineId), Callback> = BTreeMap::new();
+    loop {
+        let command = match pending.keys().next() {
+            Some(&(expires_at, _)) => {
+                let now = Instant::now();
+                let timeout = if expires_at > now {
+                    expires_at - now
+                } else {
+                    Duration::from_secs(0)
+                };
+                commands.recv_timeout(timeout)
+            }
+            None => commands.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+
+        match command {
+            Ok(Command::Start {
+                id,
+                expires_at,
+                on_expiry,
+            }) => {
+                pending.insert((expires_at, id), on_expiry);
+            }
+            Ok(Command::Cancel { id, expired }) => {
+                let key = pending
+                    .keys()
+                    .find(|&&(_, pending_id)| pending_id == id)
+                    .cloned();
+                // The deadline that is no longer pending has expired.
+                let removed = key.and_then(|key| pending.remove(&key));
+                let _ = expired.send(removed.is_none());
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                let now = Instant::now();
+                while let Some(&key) = pending.keys().next() {
+                    if key.0 > now {
+                        break;
+                    }
+                    let on_expiry = pending.remove(&key).unwrap();
+                    on_expiry();
+                }
+            }
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn deadline_cancelled_in_time() {
+        let watchdog = Watchdog::default();
+        let expired = Arc::new(AtomicUsize::new(0));
+        let callback_expired = expired.clone();
+        let deadline = watchdog.start(Duration::from_secs(60), move || {
+            callback_expired.fetch_add(1, Ordering::SeqCst);
+        });
+
+        assert!(!deadline.cancel());
+        assert_eq!(expired.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn deadline_expired() {
+        let watchdog = Watchdog::default();
+        let expired = Arc::new(AtomicUsize::new(0));
+        let callback_expired = expired.clone();
+        let deadline = watchdog.start(Duration::from_millis(1), move || {
+            callback_expired.fetch_add(1, Ordering::SeqCst);
+        });
+
+        // Emulate a slow operation
+        thread::sleep(Duration::from_millis(100));
+
+        assert!(deadline.cancel());
+        assert_eq!(expired.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn several_deadlines() {
+        let watchdog = Watchdog::default();
+        let expired = Arc::new(AtomicUsize::new(0));
+        let deadlines: Vec<_> = [1, 60_000, 2]
+            .iter()
+            .map(|&timeout| {
+                let callback_expired = expired.clone();
+                watchdog.start(Duration::from_millis(timeout), move || {
+                    callback_expired.fetch_add(1, Ordering::SeqCst);
+                })
+            })
+            .collect();
+
+        thread::sleep(Duration::from_millis(100));
+
+        let cancelled: Vec<bool> = deadlines.into_iter().map(Deadline::cancel).collect();
+        assert_eq!(cancelled, vec![true, false, true]);
+        assert_eq!(expired.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,104 @@
+/*
+ * Copyright 2019 The Exonum Team
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use parking_lot::Mutex;
+
+use std::{
+    sync::{
+        mpsc::{self, Sender},
+        Arc,
+    },
+    thread,
+};
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Executes the jobs one at a time on a single long-lived thread.
+///
+/// The thread is started on the submission of the first job and stopped once the worker
+/// and all its clones are dropped. A thread performing JNI calls stays attached to the JVM
+/// after the first call, therefore, the subsequent jobs do not pay the cost of attachment.
+#[derive(Clone)]
+pub(crate) struct Worker {
+    name: &'static str,
+    jobs: Arc<Mutex<Option<Sender<Job>>>>,
+}
+
+impl Worker {
+    /// Creates a worker with the given thread name.
+    pub fn new(name: &'static str) -> Self {
+        Worker {
+            name,
+            jobs: Arc::default(),
+        }
+    }
+
+    /// Submits the job for execution after the previously submitted ones.
+    pub fn execute<F>(&self, job: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let mut jobs = self.jobs.lock();
+        let name = self.name;
+        let jobs = jobs.get_or_insert_with(|| spawn_worker_thread(name));
+        if jobs.send(Box::new(job)).is_err() {
+            // The thread may only stop if one of the jobs has panicked.
+            error!("Worker thread {} is stopped", name);
+        }
+    }
+}
+
+fn spawn_worker_thread(name: &str) -> Sender<Job> {
+    let (jobs_tx, jobs_rx) = mpsc::channel::<Job>();
+    thread::Builder::new()
+        .name(name.to_owned())
+        .spawn(move || {
+            for job in jobs_rx {
+                job();
+            }
+        })
+        .expect("Unable to spawn a worker thread");
+    jobs_tx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn executes_jobs_in_order_on_single_thread() {
+        let worker = Worker::new("test-worker");
+        let (results_tx, results_rx) = mpsc::channel();
+        for i in 0..3 {
+            let results_tx = results_tx.clone();
+            worker.execute(move || {
+                let thread_name = thread::current().name().map(str::to_owned);
+                results_tx.send((i, thread_name)).unwrap();
+            });
+        }
+
+        let results: Vec<_> = results_rx.iter().take(3).collect();
+        let expected_name = Some("test-worker".to_owned());
+        assert_eq!(
+            results,
+            vec![
+                (0, expected_name.clone()),
+                (1, expected_name.clone()),
+                (2, expected_name),
+            ]
+        );
+    }
+}
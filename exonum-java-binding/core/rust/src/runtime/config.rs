@@ -16,7 +16,7 @@
 
 use exonum_cli::command::run::NodeRunConfig;
 
-use std::{fmt, path::PathBuf};
+use std::{fmt, net::SocketAddr, path::PathBuf, time::Duration};
 
 use {absolute_library_path, system_classpath};
 
@@ -64,6 +64,24 @@ pub struct RuntimeConfig {
     pub port: i32,
     /// Overridden path to native library if specified.
     pub override_system_lib_path: Option<String>,
+    /// The maximum time the deployment of a single artifact may take.
+    ///
+    /// A deployment exceeding this time is reported as failed. If not specified,
+    /// `DEFAULT_DEPLOY_TIMEOUT` (60 seconds) is used.
+    pub deploy_timeout: Option<Duration>,
+    /// Socket address of the HTTP endpoint exporting the metrics of the Java runtime
+    /// in the Prometheus text format.
+    ///
+    /// The metrics are not exported if not specified.
+    pub metrics_address: Option<SocketAddr>,
+    /// The maximum time the execution of a single service method may take.
+    ///
+    /// A method exceeding this time stops the node. The limit is measured in wall-clock time,
+    /// which differs across the nodes, therefore, it does not affect the execution results
+    /// and only protects the node from a service that never completes. The limit must be
+    /// well above the time any transaction takes on this node. There is no limit
+    /// if not specified, which is the default.
+    pub execution_timeout: Option<Duration>,
 }
 
 /// Internal EJB configuration.
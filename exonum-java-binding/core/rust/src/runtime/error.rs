@@ -24,4 +24,6 @@ pub enum Error {
     OtherJniError = 2,
     /// Not supported operation
     NotSupportedOperation = 3,
+    /// Artifact deployment did not complete within the configured timeout
+    DeployTimeout = 4,
 }
@@ -35,7 +35,20 @@ pub fn create_service_runtime(
     runtime_config: &RuntimeConfig,
 ) -> JavaRuntimeProxy {
     let runtime_adapter = create_service_runtime_adapter(&executor, &runtime_config);
-    JavaRuntimeProxy::new(executor, runtime_adapter)
+    let mut runtime = JavaRuntimeProxy::new(executor, runtime_adapter);
+    if let Some(deploy_timeout) = runtime_config.deploy_timeout {
+        runtime = runtime.with_deploy_timeout(deploy_timeout);
+    }
+    if let Some(execution_timeout) = runtime_config.execution_timeout {
+        runtime = runtime.with_execution_timeout(execution_timeout);
+    }
+    if let Some(metrics_address) = runtime_config.metrics_address {
+        runtime
+            .metrics()
+            .serve(metrics_address)
+            .expect("Unable to start the metrics endpoint of the Java runtime");
+    }
+    runtime
 }
 
 /// Creates service runtime adapter for JavaRuntimeProxy.
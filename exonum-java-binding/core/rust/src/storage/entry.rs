@@ -22,7 +22,10 @@ use jni::{
 use std::{panic, ptr};
 
 use handle::{self, Handle};
-use storage::db::{Value, View, ViewRef};
+use storage::{
+    db::{Value, View, ViewRef},
+    index_access,
+};
 use utils;
 
 type Index<T> = Entry<T, Value>;
@@ -44,12 +47,13 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_EntryIndexPr
         let name = utils::convert_to_string(&env, name)?;
         Ok(handle::to_handle(
             match handle::cast_handle::<View>(view_handle).get() {
-                ViewRef::Snapshot(snapshot) => {
-                    IndexType::SnapshotIndex(Index::from_access(snapshot, name.into()).unwrap())
-                }
-                ViewRef::Fork(fork) => {
-                    IndexType::ForkIndex(Index::from_access(fork, name.into()).unwrap())
-                }
+                ViewRef::Snapshot(snapshot) => IndexType::SnapshotIndex(
+                    index_access::unwrap_or_throw(&env, Index::from_access(snapshot, name.into()))?,
+                ),
+                ViewRef::Fork(fork) => IndexType::ForkIndex(index_access::unwrap_or_throw(
+                    &env,
+                    Index::from_access(fork, name.into()),
+                )?),
             },
         ))
     });
@@ -0,0 +1,75 @@
+// Copyright 2019 The Exonum Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use exonum_merkledb::access::{AccessError, AccessErrorKind};
+use jni::{
+    objects::{JObject, JThrowable, JValue},
+    JNIEnv,
+};
+
+use {JniErrorKind, JniResult};
+
+const INDEX_ACCESS_EXCEPTION: &str = "com/exonum/binding/core/storage/indices/IndexAccessException";
+
+/// Returns the accessed index, or throws `IndexAccessException` describing the access error.
+///
+/// In the latter case, `JavaException` error is returned, so that the exception propagates
+/// to the Java side when the native method returns.
+pub(crate) fn unwrap_or_throw<T>(env: &JNIEnv, result: Result<T, AccessError>) -> JniResult<T> {
+    result.or_else(|err| {
+        throw_access_error(env, &err)?;
+        Err(JniErrorKind::JavaException.into())
+    })
+}
+
+fn throw_access_error(env: &JNIEnv, err: &AccessError) -> JniResult<()> {
+    let (expected_type, actual_type) = match err.kind {
+        AccessErrorKind::WrongIndexType {
+            ref expected,
+            ref actual,
+        } => (
+            Some(format!("{:?}", expected)),
+            Some(format!("{:?}", actual)),
+        ),
+        _ => (None, None),
+    };
+    let message: JObject = env.new_string(err.to_string())?.into();
+    let name: JObject = env.new_string(err.addr.name())?.into();
+    let id_in_group: JObject = match err.addr.bytes() {
+        Some(bytes) => env.byte_array_from_slice(bytes)?.into(),
+        None => JObject::null(),
+    };
+    let expected_type = optional_string(env, expected_type)?;
+    let actual_type = optional_string(env, actual_type)?;
+    let exception = env.new_object(
+        INDEX_ACCESS_EXCEPTION,
+        "(Ljava/lang/String;Ljava/lang/String;[BLjava/lang/String;Ljava/lang/String;)V",
+        &[
+            JValue::Object(message),
+            JValue::Object(name),
+            JValue::Object(id_in_group),
+            JValue::Object(expected_type),
+            JValue::Object(actual_type),
+        ],
+    )?;
+    env.throw(JThrowable::from(exception))
+}
+
+// Converts an optional string to a Java string, or null if it is absent.
+fn optional_string<'a>(env: &JNIEnv<'a>, value: Option<String>) -> JniResult<JObject<'a>> {
+    match value {
+        Some(value) => Ok(env.new_string(value)?.into()),
+        None => Ok(JObject::null()),
+    }
+}
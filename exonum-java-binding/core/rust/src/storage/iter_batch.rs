@@ -0,0 +1,63 @@
+// Copyright 2019 The Exonum Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use jni::{objects::JObject, sys::jobjectArray, JNIEnv};
+
+use JniResult;
+
+/// Takes the next elements from the iterator and returns them packed into a Java `byte[][]`,
+/// so that Java can read them in a single native call.
+///
+/// Each element is packed by `pack` as one or more byte arrays (e.g., a key and a value),
+/// which are put into the resulting array one after another.
+///
+/// At most `max_count` elements are taken. No more elements are taken once their total size
+/// reaches `max_bytes`, but at least one element is taken if the iterator is not exhausted.
+/// An empty array is returned when the iteration is finished.
+pub(crate) fn next_batch<I, F>(
+    env: &JNIEnv,
+    iter: &mut I,
+    max_count: i32,
+    max_bytes: i32,
+    mut pack: F,
+) -> JniResult<jobjectArray>
+where
+    I: Iterator,
+    F: FnMut(I::Item, &mut Vec<Vec<u8>>),
+{
+    assert!(max_count > 0, "max_count must be positive: {}", max_count);
+    assert!(max_bytes > 0, "max_bytes must be positive: {}", max_bytes);
+
+    let mut parts = Vec::new();
+    let mut count = 0;
+    let mut size = 0;
+    while count < max_count as usize && size < max_bytes as usize {
+        let element = match iter.next() {
+            Some(element) => element,
+            None => break,
+        };
+        let first_part = parts.len();
+        pack(element, &mut parts);
+        size += parts[first_part..].iter().map(Vec::len).sum::<usize>();
+        count += 1;
+    }
+
+    let array = env.new_object_array(parts.len() as i32, "[B", JObject::null())?;
+    for (i, part) in parts.iter().enumerate() {
+        let part: JObject = env.byte_array_from_slice(part)?.into();
+        env.set_object_array_element(array, i as i32, part)?;
+        env.delete_local_ref(part)?;
+    }
+    Ok(array)
+}
@@ -17,14 +17,17 @@ use exonum_merkledb::{
 };
 use jni::{
     objects::{JClass, JObject, JString},
-    sys::{jboolean, jbyteArray},
+    sys::{jboolean, jbyteArray, jint, jobjectArray},
     JNIEnv,
 };
 
 use std::{panic, ptr};
 
 use handle::{self, Handle};
-use storage::db::{Key, View, ViewRef};
+use storage::{
+    db::{Key, View, ViewRef},
+    index_access, iter_batch,
+};
 use utils;
 
 type Index<T> = KeySetIndex<T, Key>;
@@ -46,12 +49,13 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_KeySetIndexP
         let name = utils::convert_to_string(&env, name)?;
         Ok(handle::to_handle(
             match handle::cast_handle::<View>(view_handle).get() {
-                ViewRef::Snapshot(snapshot) => {
-                    IndexType::SnapshotIndex(Index::from_access(snapshot, name.into()).unwrap())
-                }
-                ViewRef::Fork(fork) => {
-                    IndexType::ForkIndex(Index::from_access(fork, name.into()).unwrap())
-                }
+                ViewRef::Snapshot(snapshot) => IndexType::SnapshotIndex(
+                    index_access::unwrap_or_throw(&env, Index::from_access(snapshot, name.into()))?,
+                ),
+                ViewRef::Fork(fork) => IndexType::ForkIndex(index_access::unwrap_or_throw(
+                    &env,
+                    Index::from_access(fork, name.into()),
+                )?),
             },
         ))
     });
@@ -73,10 +77,14 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_KeySetIndexP
         let address = IndexAddress::with_root(group_name).append_bytes(&set_id);
         let view_ref = handle::cast_handle::<View>(view_handle).get();
         Ok(handle::to_handle(match view_ref {
-            ViewRef::Snapshot(snapshot) => {
-                IndexType::SnapshotIndex(Index::from_access(snapshot, address).unwrap())
-            }
-            ViewRef::Fork(fork) => IndexType::ForkIndex(Index::from_access(fork, address).unwrap()),
+            ViewRef::Snapshot(snapshot) => IndexType::SnapshotIndex(index_access::unwrap_or_throw(
+                &env,
+                Index::from_access(snapshot, address),
+            )?),
+            ViewRef::Fork(fork) => IndexType::ForkIndex(index_access::unwrap_or_throw(
+                &env,
+                Index::from_access(fork, address),
+            )?),
         }))
     });
     utils::unwrap_exc_or_default(&env, res)
@@ -209,19 +217,22 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_KeySetIndexP
     utils::unwrap_exc_or_default(&env, res)
 }
 
-/// Return next value from the iterator. Returns null pointer when iteration is finished.
+/// Returns the next keys from the iterator, packed into an array of byte arrays.
+/// Returns at most `max_count` keys, stopping early once their total size reaches
+/// `max_bytes`. Returns an empty array when iteration is finished.
 #[no_mangle]
-pub extern "system" fn Java_com_exonum_binding_core_storage_indices_KeySetIndexProxy_nativeIteratorNext(
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_KeySetIndexProxy_nativeIteratorNextBatch(
     env: JNIEnv,
     _: JObject,
     iter_handle: Handle,
-) -> jbyteArray {
+    max_count: jint,
+    max_bytes: jint,
+) -> jobjectArray {
     let res = panic::catch_unwind(|| {
         let iter = handle::cast_handle::<KeySetIndexIter<Key>>(iter_handle);
-        match iter.next() {
-            Some(val) => env.byte_array_from_slice(&val),
-            None => Ok(ptr::null_mut()),
-        }
+        iter_batch::next_batch(&env, iter, max_count, max_bytes, |key, parts| {
+            parts.push(key)
+        })
     });
     utils::unwrap_exc_or(&env, res, ptr::null_mut())
 }
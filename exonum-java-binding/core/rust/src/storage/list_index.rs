@@ -17,14 +17,17 @@ use exonum_merkledb::{
 };
 use jni::{
     objects::{JClass, JObject, JString},
-    sys::{jboolean, jbyteArray, jlong},
+    sys::{jboolean, jbyteArray, jint, jlong, jobjectArray},
     JNIEnv,
 };
 
 use std::{panic, ptr};
 
 use handle::{self, Handle};
-use storage::db::{Value, View, ViewRef};
+use storage::{
+    db::{Value, View, ViewRef},
+    index_access, iter_batch,
+};
 use utils;
 
 type Index<T> = ListIndex<T, Value>;
@@ -46,12 +49,13 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ListIndexPro
         let name = utils::convert_to_string(&env, name)?;
         Ok(handle::to_handle(
             match handle::cast_handle::<View>(view_handle).get() {
-                ViewRef::Snapshot(snapshot) => {
-                    IndexType::SnapshotIndex(Index::from_access(snapshot, name.into()).unwrap())
-                }
-                ViewRef::Fork(fork) => {
-                    IndexType::ForkIndex(Index::from_access(fork, name.into()).unwrap())
-                }
+                ViewRef::Snapshot(snapshot) => IndexType::SnapshotIndex(
+                    index_access::unwrap_or_throw(&env, Index::from_access(snapshot, name.into()))?,
+                ),
+                ViewRef::Fork(fork) => IndexType::ForkIndex(index_access::unwrap_or_throw(
+                    &env,
+                    Index::from_access(fork, name.into()),
+                )?),
             },
         ))
     });
@@ -73,10 +77,14 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ListIndexPro
         let address = IndexAddress::with_root(group_name).append_bytes(&list_id);
         let view_ref = handle::cast_handle::<View>(view_handle).get();
         Ok(handle::to_handle(match view_ref {
-            ViewRef::Snapshot(snapshot) => {
-                IndexType::SnapshotIndex(Index::from_access(snapshot, address).unwrap())
-            }
-            ViewRef::Fork(fork) => IndexType::ForkIndex(Index::from_access(fork, address).unwrap()),
+            ViewRef::Snapshot(snapshot) => IndexType::SnapshotIndex(index_access::unwrap_or_throw(
+                &env,
+                Index::from_access(snapshot, address),
+            )?),
+            ViewRef::Fork(fork) => IndexType::ForkIndex(index_access::unwrap_or_throw(
+                &env,
+                Index::from_access(fork, address),
+            )?),
         }))
     });
     utils::unwrap_exc_or_default(&env, res)
@@ -304,19 +312,22 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ListIndexPro
     utils::unwrap_exc_or_default(&env, res)
 }
 
-/// Returns next value from the iterator. Returns null pointer when iteration is finished.
+/// Returns the next values from the iterator, packed into an array of byte arrays.
+/// Returns at most `max_count` values, stopping early once their total size reaches
+/// `max_bytes`. Returns an empty array when iteration is finished.
 #[no_mangle]
-pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ListIndexProxy_nativeIterNext(
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ListIndexProxy_nativeIterNextBatch(
     env: JNIEnv,
     _: JObject,
     iter_handle: Handle,
-) -> jbyteArray {
+    max_count: jint,
+    max_bytes: jint,
+) -> jobjectArray {
     let res = panic::catch_unwind(|| {
         let iter = handle::cast_handle::<ListIndexIter<Value>>(iter_handle);
-        match iter.next() {
-            Some(val) => env.byte_array_from_slice(&val),
-            None => Ok(ptr::null_mut()),
-        }
+        iter_batch::next_batch(&env, iter, max_count, max_bytes, |value, parts| {
+            parts.push(value)
+        })
     });
     utils::unwrap_exc_or(&env, res, ptr::null_mut())
 }
@@ -19,7 +19,7 @@ use exonum_merkledb::{
 };
 use jni::{
     objects::{JClass, JObject, JString},
-    sys::{jboolean, jbyteArray, jobject},
+    sys::{jboolean, jbyteArray, jint, jobjectArray},
     JNIEnv,
 };
 
@@ -28,7 +28,7 @@ use std::{panic, ptr};
 use handle::{self, Handle};
 use storage::{
     db::{Key, Value, View, ViewRef},
-    PairIter,
+    index_access, iter_batch, PairIter,
 };
 use utils;
 
@@ -55,12 +55,13 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_MapIndexProx
         let name = utils::convert_to_string(&env, name)?;
         Ok(handle::to_handle(
             match handle::cast_handle::<View>(view_handle).get() {
-                ViewRef::Snapshot(snapshot) => {
-                    IndexType::SnapshotIndex(Index::from_access(snapshot, name.into()).unwrap())
-                }
-                ViewRef::Fork(fork) => {
-                    IndexType::ForkIndex(Index::from_access(fork, name.into()).unwrap())
-                }
+                ViewRef::Snapshot(snapshot) => IndexType::SnapshotIndex(
+                    index_access::unwrap_or_throw(&env, Index::from_access(snapshot, name.into()))?,
+                ),
+                ViewRef::Fork(fork) => IndexType::ForkIndex(index_access::unwrap_or_throw(
+                    &env,
+                    Index::from_access(fork, name.into()),
+                )?),
             },
         ))
     });
@@ -82,10 +83,14 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_MapIndexProx
         let address = IndexAddress::with_root(group_name).append_bytes(&map_id);
         let view_ref = handle::cast_handle::<View>(view_handle).get();
         Ok(handle::to_handle(match view_ref {
-            ViewRef::Snapshot(snapshot) => {
-                IndexType::SnapshotIndex(Index::from_access(snapshot, address).unwrap())
-            }
-            ViewRef::Fork(fork) => IndexType::ForkIndex(Index::from_access(fork, address).unwrap()),
+            ViewRef::Snapshot(snapshot) => IndexType::SnapshotIndex(index_access::unwrap_or_throw(
+                &env,
+                Index::from_access(snapshot, address),
+            )?),
+            ViewRef::Fork(fork) => IndexType::ForkIndex(index_access::unwrap_or_throw(
+                &env,
+                Index::from_access(fork, address),
+            )?),
         }))
     });
     utils::unwrap_exc_or_default(&env, res)
@@ -123,6 +128,25 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_MapIndexProx
     utils::unwrap_exc_or(&env, res, ptr::null_mut())
 }
 
+/// Returns the values identified by the `keys`, with null pointers in place of absent values.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeGetAll(
+    env: JNIEnv,
+    _: JObject,
+    map_handle: Handle,
+    keys: jobjectArray,
+) -> jobjectArray {
+    let res = panic::catch_unwind(|| {
+        let keys = utils::java_arrays_to_rust(&env, keys, |env, key| env.convert_byte_array(key))?;
+        let values: Vec<_> = match *handle::cast_handle::<IndexType>(map_handle) {
+            IndexType::SnapshotIndex(ref map) => keys.iter().map(|key| map.get(key)).collect(),
+            IndexType::ForkIndex(ref map) => keys.iter().map(|key| map.get(key)).collect(),
+        };
+        utils::optional_arrays_to_java(&env, &values)
+    });
+    utils::unwrap_exc_or(&env, res, ptr::null_mut())
+}
+
 /// Returns `true` if the map contains a value for the specified key.
 #[no_mangle]
 pub extern "system" fn Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeContainsKey(
@@ -278,6 +302,36 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_MapIndexProx
     utils::unwrap_exc_or_default(&env, res)
 }
 
+/// Sets the `values` identified by the corresponding `keys` into the index.
+///
+/// Throws `IllegalArgumentException` if the number of keys and values differ.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativePutAll(
+    env: JNIEnv,
+    _: JObject,
+    map_handle: Handle,
+    keys: jobjectArray,
+    values: jobjectArray,
+) {
+    let res = panic::catch_unwind(|| match *handle::cast_handle::<IndexType>(map_handle) {
+        IndexType::SnapshotIndex(_) => {
+            panic!("Unable to modify snapshot.");
+        }
+        IndexType::ForkIndex(ref mut map) => {
+            let entries = utils::java_pairs_to_rust(&env, keys, values, |env, key| {
+                env.convert_byte_array(key)
+            })?;
+            if let Some(entries) = entries {
+                for (key, value) in entries {
+                    map.put(&key, value);
+                }
+            }
+            Ok(())
+        }
+    });
+    utils::unwrap_exc_or_default(&env, res)
+}
+
 /// Removes value identified by the `key` from the index.
 #[no_mangle]
 pub extern "system" fn Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeRemove(
@@ -299,6 +353,30 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_MapIndexProx
     utils::unwrap_exc_or_default(&env, res)
 }
 
+/// Removes the values identified by the `keys` from the index.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeRemoveAll(
+    env: JNIEnv,
+    _: JObject,
+    map_handle: Handle,
+    keys: jobjectArray,
+) {
+    let res = panic::catch_unwind(|| match *handle::cast_handle::<IndexType>(map_handle) {
+        IndexType::SnapshotIndex(_) => {
+            panic!("Unable to modify snapshot.");
+        }
+        IndexType::ForkIndex(ref mut map) => {
+            let keys =
+                utils::java_arrays_to_rust(&env, keys, |env, key| env.convert_byte_array(key))?;
+            for key in &keys {
+                map.remove(key);
+            }
+            Ok(())
+        }
+    });
+    utils::unwrap_exc_or_default(&env, res)
+}
+
 /// Clears the index, removing all values.
 #[no_mangle]
 pub extern "system" fn Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeClear(
@@ -318,29 +396,30 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_MapIndexProx
     utils::unwrap_exc_or_default(&env, res)
 }
 
-/// Returns the next value from the iterator. Returns null pointer when iteration is finished.
+/// Returns the next entries from the iterator, packed into an array of byte arrays,
+/// where the key of each entry is followed by its value.
+/// Returns at most `max_count` entries, stopping early once their total size reaches
+/// `max_bytes`. Returns an empty array when iteration is finished.
 #[no_mangle]
-pub extern "system" fn Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeEntriesIterNext(
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeEntriesIterNextBatch(
     env: JNIEnv,
     _: JObject,
     iter_handle: Handle,
-) -> jobject {
+    max_count: jint,
+    max_bytes: jint,
+) -> jobjectArray {
     let res = panic::catch_unwind(|| {
-        let iterWrapper = handle::cast_handle::<Iter>(iter_handle);
-        match iterWrapper.iter.next() {
-            Some(val) => {
-                let key: JObject = env.byte_array_from_slice(&val.0)?.into();
-                let value: JObject = env.byte_array_from_slice(&val.1)?.into();
-                Ok(env
-                    .new_object_unchecked(
-                        &iterWrapper.element_class,
-                        iterWrapper.constructor_id,
-                        &[key.into(), value.into()],
-                    )?
-                    .into_inner())
-            }
-            None => Ok(ptr::null_mut()),
-        }
+        let iter = handle::cast_handle::<Iter>(iter_handle);
+        iter_batch::next_batch(
+            &env,
+            &mut iter.iter,
+            max_count,
+            max_bytes,
+            |(key, value), parts| {
+                parts.push(key);
+                parts.push(value);
+            },
+        )
     });
     utils::unwrap_exc_or(&env, res, ptr::null_mut())
 }
@@ -355,19 +434,22 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_MapIndexProx
     handle::drop_handle::<Iter>(&env, iter_handle);
 }
 
-/// Returns the next value from the keys-iterator. Returns null pointer when iteration is finished.
+/// Returns the next keys from the keys-iterator, packed into an array of byte arrays.
+/// Returns at most `max_count` keys, stopping early once their total size reaches
+/// `max_bytes`. Returns an empty array when iteration is finished.
 #[no_mangle]
-pub extern "system" fn Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeKeysIterNext(
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeKeysIterNextBatch(
     env: JNIEnv,
     _: JObject,
     iter_handle: Handle,
-) -> jbyteArray {
+    max_count: jint,
+    max_bytes: jint,
+) -> jobjectArray {
     let res = panic::catch_unwind(|| {
         let iter = handle::cast_handle::<MapIndexKeys<Key>>(iter_handle);
-        match iter.next() {
-            Some(val) => env.byte_array_from_slice(&val),
-            None => Ok(ptr::null_mut()),
-        }
+        iter_batch::next_batch(&env, iter, max_count, max_bytes, |key, parts| {
+            parts.push(key)
+        })
     });
     utils::unwrap_exc_or(&env, res, ptr::null_mut())
 }
@@ -382,19 +464,22 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_MapIndexProx
     handle::drop_handle::<MapIndexKeys<Key>>(&env, iter_handle);
 }
 
-/// Return next value from the values-iterator. Returns null pointer when iteration is finished.
+/// Returns the next values from the values-iterator, packed into an array of byte arrays.
+/// Returns at most `max_count` values, stopping early once their total size reaches
+/// `max_bytes`. Returns an empty array when iteration is finished.
 #[no_mangle]
-pub extern "system" fn Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeValuesIterNext(
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_MapIndexProxy_nativeValuesIterNextBatch(
     env: JNIEnv,
     _: JObject,
     iter_handle: Handle,
-) -> jbyteArray {
+    max_count: jint,
+    max_bytes: jint,
+) -> jobjectArray {
     let res = panic::catch_unwind(|| {
         let iter = handle::cast_handle::<MapIndexValues<Value>>(iter_handle);
-        match iter.next() {
-            Some(val) => env.byte_array_from_slice(&val),
-            None => Ok(ptr::null_mut()),
-        }
+        iter_batch::next_batch(&env, iter, max_count, max_bytes, |value, parts| {
+            parts.push(value)
+        })
     });
     utils::unwrap_exc_or(&env, res, ptr::null_mut())
 }
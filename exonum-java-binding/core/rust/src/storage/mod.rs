@@ -15,14 +15,18 @@
 mod db;
 mod entry;
 mod fork;
+mod index_access;
+mod iter_batch;
 mod key_set_index;
 mod list_index;
 mod map_index;
 mod pair_iter;
+mod proof_entry;
 mod proof_list_index;
 mod proof_map_index;
 mod proof_map_index_next;
 mod raw_proof_map_index;
+mod sparse_list_index;
 mod temporarydb;
 mod value_set_index;
 
@@ -33,9 +37,11 @@ pub use self::key_set_index::*;
 pub use self::list_index::*;
 pub use self::map_index::*;
 pub use self::pair_iter::PairIter;
+pub use self::proof_entry::*;
 pub use self::proof_list_index::*;
 pub use self::proof_map_index::*;
 pub use self::proof_map_index_next::*;
 pub use self::raw_proof_map_index::*;
+pub use self::sparse_list_index::*;
 pub use self::temporarydb::*;
 pub use self::value_set_index::*;
@@ -21,8 +21,8 @@ use exonum_merkledb::{
 };
 use exonum_proto::ProtobufConvert;
 use jni::{
-    objects::{GlobalRef, JClass, JMethodID, JObject, JString},
-    sys::{jboolean, jbyteArray, jobject, jobjectArray, JNI_TRUE},
+    objects::{JClass, JObject, JString},
+    sys::{jboolean, jbyteArray, jint, jobjectArray, JNI_TRUE},
     JNIEnv,
 };
 use protobuf::Message;
@@ -32,7 +32,7 @@ use std::{panic, ptr};
 use handle::{self, Handle};
 use storage::{
     db::{Key, Value, View, ViewRef},
-    PairIter,
+    index_access, iter_batch, PairIter,
 };
 use utils;
 use JniResult;
@@ -113,25 +113,33 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ProofMapInde
             match handle::cast_handle::<View>(view_handle).get() {
                 ViewRef::Snapshot(snapshot) => {
                     let index = if key_is_hashed {
-                        ProofMapIndex::<_, _, _, Hashed>::from_access(snapshot, name.into())
-                            .unwrap()
-                            .into()
+                        index_access::unwrap_or_throw(
+                            &env,
+                            ProofMapIndex::<_, _, _, Hashed>::from_access(snapshot, name.into()),
+                        )?
+                        .into()
                     } else {
-                        RawProofMapIndex::from_access(snapshot, name.into())
-                            .unwrap()
-                            .into()
+                        index_access::unwrap_or_throw(
+                            &env,
+                            RawProofMapIndex::from_access(snapshot, name.into()),
+                        )?
+                        .into()
                     };
                     IndexType::SnapshotIndex(index)
                 }
                 ViewRef::Fork(fork) => {
                     let index = if key_is_hashed {
-                        ProofMapIndex::<_, _, _, Hashed>::from_access(fork, name.into())
-                            .unwrap()
-                            .into()
+                        index_access::unwrap_or_throw(
+                            &env,
+                            ProofMapIndex::<_, _, _, Hashed>::from_access(fork, name.into()),
+                        )?
+                        .into()
                     } else {
-                        RawProofMapIndex::from_access(fork, name.into())
-                            .unwrap()
-                            .into()
+                        index_access::unwrap_or_throw(
+                            &env,
+                            RawProofMapIndex::from_access(fork, name.into()),
+                        )?
+                        .into()
                     };
                     IndexType::ForkIndex(index)
                 }
@@ -160,23 +168,33 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ProofMapInde
             match handle::cast_handle::<View>(view_handle).get() {
                 ViewRef::Snapshot(snapshot) => {
                     let index = if key_is_hashed {
-                        ProofMapIndex::<_, _, _, Hashed>::from_access(snapshot, address)
-                            .unwrap()
-                            .into()
+                        index_access::unwrap_or_throw(
+                            &env,
+                            ProofMapIndex::<_, _, _, Hashed>::from_access(snapshot, address),
+                        )?
+                        .into()
                     } else {
-                        RawProofMapIndex::from_access(snapshot, address)
-                            .unwrap()
-                            .into()
+                        index_access::unwrap_or_throw(
+                            &env,
+                            RawProofMapIndex::from_access(snapshot, address),
+                        )?
+                        .into()
                     };
                     IndexType::SnapshotIndex(index)
                 }
                 ViewRef::Fork(fork) => {
                     let index = if key_is_hashed {
-                        ProofMapIndex::<_, _, _, Hashed>::from_access(fork, address)
-                            .unwrap()
-                            .into()
+                        index_access::unwrap_or_throw(
+                            &env,
+                            ProofMapIndex::<_, _, _, Hashed>::from_access(fork, address),
+                        )?
+                        .into()
                     } else {
-                        RawProofMapIndex::from_access(fork, address).unwrap().into()
+                        index_access::unwrap_or_throw(
+                            &env,
+                            RawProofMapIndex::from_access(fork, address),
+                        )?
+                        .into()
                     };
                     IndexType::ForkIndex(index)
                 }
@@ -247,6 +265,31 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ProofMapInde
     utils::unwrap_exc_or(&env, res, ptr::null_mut())
 }
 
+/// Returns the values identified by the `keys`, with null pointers in place of absent values.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ProofMapIndexProxy_nativeGetAll(
+    env: JNIEnv,
+    _: JObject,
+    map_handle: Handle,
+    keys: jobjectArray,
+) -> jobjectArray {
+    let res = panic::catch_unwind(|| {
+        let keys = convert_to_keys(&env, keys)?;
+        let values: Vec<_> = match *handle::cast_handle::<IndexType>(map_handle) {
+            IndexType::SnapshotIndex(ref index) => match index {
+                Index::Raw(map) => keys.iter().map(|key| map.get(&key.to_raw())).collect(),
+                Index::Hashed(map) => keys.iter().map(|key| map.get(key)).collect(),
+            },
+            IndexType::ForkIndex(ref index) => match index {
+                Index::Raw(map) => keys.iter().map(|key| map.get(&key.to_raw())).collect(),
+                Index::Hashed(map) => keys.iter().map(|key| map.get(key)).collect(),
+            },
+        };
+        utils::optional_arrays_to_java(&env, &values)
+    });
+    utils::unwrap_exc_or(&env, res, ptr::null_mut())
+}
+
 /// Returns `true` if the map contains a value for the specified key.
 #[no_mangle]
 pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ProofMapIndexProxy_nativeContainsKey(
@@ -493,6 +536,39 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ProofMapInde
     utils::unwrap_exc_or_default(&env, res)
 }
 
+/// Sets the `values` identified by the corresponding `keys` into the index.
+///
+/// Throws `IllegalArgumentException` if the number of keys and values differ.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ProofMapIndexProxy_nativePutAll(
+    env: JNIEnv,
+    _: JObject,
+    map_handle: Handle,
+    keys: jobjectArray,
+    values: jobjectArray,
+) {
+    let res = panic::catch_unwind(|| match *handle::cast_handle::<IndexType>(map_handle) {
+        IndexType::SnapshotIndex(_) => {
+            panic!("Unable to modify snapshot.");
+        }
+        IndexType::ForkIndex(ref mut index) => {
+            let entries = utils::java_pairs_to_rust(&env, keys, values, |env, key| {
+                env.convert_byte_array(key)
+            })?;
+            if let Some(entries) = entries {
+                for (key, value) in entries {
+                    match index {
+                        Index::Raw(map) => map.put(&key.to_raw(), value),
+                        Index::Hashed(map) => map.put(&key, value),
+                    }
+                }
+            }
+            Ok(())
+        }
+    });
+    utils::unwrap_exc_or_default(&env, res)
+}
+
 /// Removes value identified by the `key` from the index.
 #[no_mangle]
 pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ProofMapIndexProxy_nativeRemove(
@@ -517,6 +593,32 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ProofMapInde
     utils::unwrap_exc_or_default(&env, res)
 }
 
+/// Removes the values identified by the `keys` from the index.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ProofMapIndexProxy_nativeRemoveAll(
+    env: JNIEnv,
+    _: JObject,
+    map_handle: Handle,
+    keys: jobjectArray,
+) {
+    let res = panic::catch_unwind(|| match *handle::cast_handle::<IndexType>(map_handle) {
+        IndexType::SnapshotIndex(_) => {
+            panic!("Unable to modify snapshot.");
+        }
+        IndexType::ForkIndex(ref mut index) => {
+            let keys = convert_to_keys(&env, keys)?;
+            for key in &keys {
+                match index {
+                    Index::Raw(map) => map.remove(&key.to_raw()),
+                    Index::Hashed(map) => map.remove(key),
+                }
+            }
+            Ok(())
+        }
+    });
+    utils::unwrap_exc_or_default(&env, res)
+}
+
 /// Removes all entries of the map.
 #[no_mangle]
 pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ProofMapIndexProxy_nativeClear(
@@ -539,38 +641,39 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ProofMapInde
     utils::unwrap_exc_or_default(&env, res)
 }
 
-/// Returns the next value from the iterator. Returns null pointer when iteration is finished.
+/// Returns the next entries from the iterator, packed into an array of byte arrays,
+/// where the key of each entry is followed by its value.
+/// Returns at most `max_count` entries, stopping early once their total size reaches
+/// `max_bytes`. Returns an empty array when iteration is finished.
 #[no_mangle]
-pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ProofMapIndexProxy_nativeEntriesIterNext(
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ProofMapIndexProxy_nativeEntriesIterNextBatch(
     env: JNIEnv,
     _: JObject,
     iter_handle: Handle,
-) -> jobject {
-    let res = panic::catch_unwind(|| {
-        let iterWrapper = handle::cast_handle::<Iter>(iter_handle);
-
-        let result = match iterWrapper {
-            Iter::Raw(ref mut wrapper) => wrapper.iter.next().map(|(arr, val)| {
-                create_element(
-                    &env,
-                    &arr[..],
-                    &val,
-                    &wrapper.element_class,
-                    wrapper.constructor_id,
-                )
-            }),
-            Iter::Hashed(ref mut wrapper) => wrapper.iter.next().map(|(key, val)| {
-                create_element(
-                    &env,
-                    key.as_slice(),
-                    &val,
-                    &wrapper.element_class,
-                    wrapper.constructor_id,
-                )
-            }),
-        };
-
-        result.or(Some(Ok(ptr::null_mut()))).unwrap()
+    max_count: jint,
+    max_bytes: jint,
+) -> jobjectArray {
+    let res = panic::catch_unwind(|| match *handle::cast_handle::<Iter>(iter_handle) {
+        Iter::Raw(ref mut wrapper) => iter_batch::next_batch(
+            &env,
+            &mut wrapper.iter,
+            max_count,
+            max_bytes,
+            |(key, value), parts| {
+                parts.push(key.to_vec());
+                parts.push(value);
+            },
+        ),
+        Iter::Hashed(ref mut wrapper) => iter_batch::next_batch(
+            &env,
+            &mut wrapper.iter,
+            max_count,
+            max_bytes,
+            |(key, value), parts| {
+                parts.push(key);
+                parts.push(value);
+            },
+        ),
     });
     utils::unwrap_exc_or(&env, res, ptr::null_mut())
 }
@@ -585,21 +688,28 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ProofMapInde
     handle::drop_handle::<Iter>(&env, iter_handle);
 }
 
-/// Returns the next value from the keys-iterator. Returns null pointer when iteration is finished.
+/// Returns the next keys from the keys-iterator, packed into an array of byte arrays.
+/// Returns at most `max_count` keys, stopping early once their total size reaches
+/// `max_bytes`. Returns an empty array when iteration is finished.
 #[no_mangle]
-pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ProofMapIndexProxy_nativeKeysIterNext(
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ProofMapIndexProxy_nativeKeysIterNextBatch(
     env: JNIEnv,
     _: JObject,
     iter_handle: Handle,
-) -> jbyteArray {
-    let res = panic::catch_unwind(|| {
-        let array = match *handle::cast_handle::<KeysIter>(iter_handle) {
-            KeysIter::Raw(ref mut iter) => iter.next().map(|val| env.byte_array_from_slice(&val)),
-            KeysIter::Hashed(ref mut iter) => {
-                iter.next().map(|val| env.byte_array_from_slice(&val))
-            }
-        };
-        array.or(Some(Ok(ptr::null_mut()))).unwrap()
+    max_count: jint,
+    max_bytes: jint,
+) -> jobjectArray {
+    let res = panic::catch_unwind(|| match *handle::cast_handle::<KeysIter>(iter_handle) {
+        KeysIter::Raw(ref mut iter) => {
+            iter_batch::next_batch(&env, iter, max_count, max_bytes, |key, parts| {
+                parts.push(key.to_vec())
+            })
+        }
+        KeysIter::Hashed(ref mut iter) => {
+            iter_batch::next_batch(&env, iter, max_count, max_bytes, |key, parts| {
+                parts.push(key)
+            })
+        }
     });
     utils::unwrap_exc_or(&env, res, ptr::null_mut())
 }
@@ -614,19 +724,22 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ProofMapInde
     handle::drop_handle::<KeysIter>(&env, iter_handle);
 }
 
-/// Return next value from the values-iterator. Returns null pointer when iteration is finished.
+/// Returns the next values from the values-iterator, packed into an array of byte arrays.
+/// Returns at most `max_count` values, stopping early once their total size reaches
+/// `max_bytes`. Returns an empty array when iteration is finished.
 #[no_mangle]
-pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ProofMapIndexProxy_nativeValuesIterNext(
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ProofMapIndexProxy_nativeValuesIterNextBatch(
     env: JNIEnv,
     _: JObject,
     iter_handle: Handle,
-) -> jbyteArray {
+    max_count: jint,
+    max_bytes: jint,
+) -> jobjectArray {
     let res = panic::catch_unwind(|| {
         let iter = handle::cast_handle::<ProofMapIndexValues<Value>>(iter_handle);
-        match iter.next() {
-            Some(val) => env.byte_array_from_slice(&val),
-            None => Ok(ptr::null_mut()),
-        }
+        iter_batch::next_batch(&env, iter, max_count, max_bytes, |value, parts| {
+            parts.push(value)
+        })
     });
     utils::unwrap_exc_or(&env, res, ptr::null_mut())
 }
@@ -658,21 +771,6 @@ fn convert_keys(keys: Vec<Key>) -> Vec<RawKey> {
     keys.into_iter().map(|key| key.to_raw()).collect()
 }
 
-// Creates element for PairIter.
-fn create_element(
-    env: &JNIEnv,
-    key: &[u8],
-    value: &[u8],
-    class: &GlobalRef,
-    constructor: JMethodID,
-) -> JniResult<jobject> {
-    let key: JObject = env.byte_array_from_slice(key)?.into();
-    let value: JObject = env.byte_array_from_slice(value)?.into();
-    Ok(env
-        .new_object_unchecked(class, constructor, &[key.into(), value.into()])?
-        .into_inner())
-}
-
 // Creates PairIter for corresponding iterator and map entry.
 fn create_pair_iter<I: Iterator>(env: &JNIEnv, iter: I) -> JniResult<PairIter<I>> {
     PairIter::new(&env, iter, MAP_ENTRY_INTERNAL_FQN)
@@ -28,7 +28,7 @@ use std::{panic, ptr};
 use handle::{self, Handle};
 use storage::{
     db::{Value, View, ViewRef},
-    PairIter,
+    index_access, PairIter,
 };
 use utils;
 use JniResult;
@@ -57,12 +57,13 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ProofMapInde
         let name = utils::convert_to_string(&env, name)?;
         Ok(handle::to_handle(
             match handle::cast_handle::<View>(view_handle).get() {
-                ViewRef::Snapshot(snapshot) => {
-                    IndexType::SnapshotIndex(Index::from_access(snapshot, name.into()).unwrap())
-                }
-                ViewRef::Fork(fork) => {
-                    IndexType::ForkIndex(Index::from_access(fork, name.into()).unwrap())
-                }
+                ViewRef::Snapshot(snapshot) => IndexType::SnapshotIndex(
+                    index_access::unwrap_or_throw(&env, Index::from_access(snapshot, name.into()))?,
+                ),
+                ViewRef::Fork(fork) => IndexType::ForkIndex(index_access::unwrap_or_throw(
+                    &env,
+                    Index::from_access(fork, name.into()),
+                )?),
             },
         ))
     });
@@ -84,10 +85,14 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ProofMapInde
         let address = IndexAddress::with_root(group_name).append_bytes(&map_id);
         let view_ref = handle::cast_handle::<View>(view_handle).get();
         Ok(handle::to_handle(match view_ref {
-            ViewRef::Snapshot(snapshot) => {
-                IndexType::SnapshotIndex(Index::from_access(snapshot, address).unwrap())
-            }
-            ViewRef::Fork(fork) => IndexType::ForkIndex(Index::from_access(fork, address).unwrap()),
+            ViewRef::Snapshot(snapshot) => IndexType::SnapshotIndex(index_access::unwrap_or_throw(
+                &env,
+                Index::from_access(snapshot, address),
+            )?),
+            ViewRef::Fork(fork) => IndexType::ForkIndex(index_access::unwrap_or_throw(
+                &env,
+                Index::from_access(fork, address),
+            )?),
         }))
     });
     utils::unwrap_exc_or_default(&env, res)
@@ -30,7 +30,7 @@ use std::{panic, ptr};
 use handle::{self, Handle};
 use storage::{
     db::{Value, View, ViewRef},
-    PairIter,
+    index_access, PairIter,
 };
 use utils;
 use JniResult;
@@ -59,12 +59,13 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_RawProofMapI
         let name = utils::convert_to_string(&env, name)?;
         Ok(handle::to_handle(
             match handle::cast_handle::<View>(view_handle).get() {
-                ViewRef::Snapshot(snapshot) => {
-                    IndexType::SnapshotIndex(Index::from_access(snapshot, name.into()).unwrap())
-                }
-                ViewRef::Fork(fork) => {
-                    IndexType::ForkIndex(Index::from_access(fork, name.into()).unwrap())
-                }
+                ViewRef::Snapshot(snapshot) => IndexType::SnapshotIndex(
+                    index_access::unwrap_or_throw(&env, Index::from_access(snapshot, name.into()))?,
+                ),
+                ViewRef::Fork(fork) => IndexType::ForkIndex(index_access::unwrap_or_throw(
+                    &env,
+                    Index::from_access(fork, name.into()),
+                )?),
             },
         ))
     });
@@ -86,10 +87,14 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_RawProofMapI
         let address = IndexAddress::with_root(group_name).append_bytes(&map_id);
         let view_ref = handle::cast_handle::<View>(view_handle).get();
         Ok(handle::to_handle(match view_ref {
-            ViewRef::Snapshot(snapshot) => {
-                IndexType::SnapshotIndex(Index::from_access(snapshot, address).unwrap())
-            }
-            ViewRef::Fork(fork) => IndexType::ForkIndex(Index::from_access(fork, address).unwrap()),
+            ViewRef::Snapshot(snapshot) => IndexType::SnapshotIndex(index_access::unwrap_or_throw(
+                &env,
+                Index::from_access(snapshot, address),
+            )?),
+            ViewRef::Fork(fork) => IndexType::ForkIndex(index_access::unwrap_or_throw(
+                &env,
+                Index::from_access(fork, address),
+            )?),
         }))
     });
     utils::unwrap_exc_or_default(&env, res)
@@ -377,33 +382,6 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_RawProofMapI
     utils::unwrap_exc_or_default(&env, res)
 }
 
-/// Returns the next value from the iterator. Returns null pointer when iteration is finished.
-#[no_mangle]
-pub extern "system" fn Java_com_exonum_binding_core_storage_indices_RawProofMapIndexProxy_nativeEntriesIterNext(
-    env: JNIEnv,
-    _: JObject,
-    iter_handle: Handle,
-) -> jobject {
-    let res = panic::catch_unwind(|| {
-        let iterWrapper = handle::cast_handle::<Iter>(iter_handle);
-        match iterWrapper.iter.next() {
-            Some(val) => {
-                let key: JObject = env.byte_array_from_slice(&val.0)?.into();
-                let value: JObject = env.byte_array_from_slice(&val.1)?.into();
-                Ok(env
-                    .new_object_unchecked(
-                        &iterWrapper.element_class,
-                        iterWrapper.constructor_id,
-                        &[key.into(), value.into()],
-                    )?
-                    .into_inner())
-            }
-            None => Ok(ptr::null_mut()),
-        }
-    });
-    utils::unwrap_exc_or(&env, res, ptr::null_mut())
-}
-
 /// Destroys the underlying `RawProofMapIndex` iterator object and frees memory.
 #[no_mangle]
 pub extern "system" fn Java_com_exonum_binding_core_storage_indices_RawProofMapIndexProxy_nativeEntriesIterFree(
@@ -414,23 +392,6 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_RawProofMapI
     handle::drop_handle::<Iter>(&env, iter_handle);
 }
 
-/// Returns the next value from the keys-iterator. Returns null pointer when iteration is finished.
-#[no_mangle]
-pub extern "system" fn Java_com_exonum_binding_core_storage_indices_RawProofMapIndexProxy_nativeKeysIterNext(
-    env: JNIEnv,
-    _: JObject,
-    iter_handle: Handle,
-) -> jbyteArray {
-    let res = panic::catch_unwind(|| {
-        let iter = handle::cast_handle::<ProofMapIndexKeys<Key>>(iter_handle);
-        match iter.next() {
-            Some(val) => env.byte_array_from_slice(&val),
-            None => Ok(ptr::null_mut()),
-        }
-    });
-    utils::unwrap_exc_or(&env, res, ptr::null_mut())
-}
-
 /// Destroys the underlying `RawProofMapIndex` keys-iterator object and frees memory.
 #[no_mangle]
 pub extern "system" fn Java_com_exonum_binding_core_storage_indices_RawProofMapIndexProxy_nativeKeysIterFree(
@@ -441,23 +402,6 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_RawProofMapI
     handle::drop_handle::<ProofMapIndexKeys<Key>>(&env, iter_handle);
 }
 
-/// Return next value from the values-iterator. Returns null pointer when iteration is finished.
-#[no_mangle]
-pub extern "system" fn Java_com_exonum_binding_core_storage_indices_RawProofMapIndexProxy_nativeValuesIterNext(
-    env: JNIEnv,
-    _: JObject,
-    iter_handle: Handle,
-) -> jbyteArray {
-    let res = panic::catch_unwind(|| {
-        let iter = handle::cast_handle::<ProofMapIndexValues<Value>>(iter_handle);
-        match iter.next() {
-            Some(val) => env.byte_array_from_slice(&val),
-            None => Ok(ptr::null_mut()),
-        }
-    });
-    utils::unwrap_exc_or(&env, res, ptr::null_mut())
-}
-
 /// Destroys the underlying `RawProofMapIndex` values-iterator object and frees memory.
 #[no_mangle]
 pub extern "system" fn Java_com_exonum_binding_core_storage_indices_RawProofMapIndexProxy_nativeValuesIterFree(
@@ -0,0 +1,352 @@
+// Copyright 2019 The Exonum Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use exonum_merkledb::{
+    access::FromAccess,
+    sparse_list_index::{SparseListIndexKeys, SparseListIndexValues},
+    BinaryValue, Fork, IndexAddress, Snapshot, SparseListIndex,
+};
+use jni::{
+    objects::{JClass, JObject, JString},
+    sys::{jboolean, jbyteArray, jint, jlong, jobjectArray},
+    JNIEnv,
+};
+
+use std::{panic, ptr};
+
+use handle::{self, Handle};
+use storage::{
+    db::{Value, View, ViewRef},
+    index_access, iter_batch,
+};
+use utils;
+
+type Index<T> = SparseListIndex<T, Value>;
+
+enum IndexType {
+    SnapshotIndex(Index<&'static dyn Snapshot>),
+    ForkIndex(Index<&'static Fork>),
+}
+
+/// Returns pointer to the created `SparseListIndex` object.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_SparseListIndexProxy_nativeCreate(
+    env: JNIEnv,
+    _: JClass,
+    name: JString,
+    view_handle: Handle,
+) -> Handle {
+    let res = panic::catch_unwind(|| {
+        let name = utils::convert_to_string(&env, name)?;
+        Ok(handle::to_handle(
+            match handle::cast_handle::<View>(view_handle).get() {
+                ViewRef::Snapshot(snapshot) => IndexType::SnapshotIndex(
+                    index_access::unwrap_or_throw(&env, Index::from_access(snapshot, name.into()))?,
+                ),
+                ViewRef::Fork(fork) => IndexType::ForkIndex(index_access::unwrap_or_throw(
+                    &env,
+                    Index::from_access(fork, name.into()),
+                )?),
+            },
+        ))
+    });
+    utils::unwrap_exc_or_default(&env, res)
+}
+
+/// Returns a pointer to the created `SparseListIndex` instance in an index family (= group).
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_SparseListIndexProxy_nativeCreateInGroup(
+    env: JNIEnv,
+    _: JClass,
+    group_name: JString,
+    list_id: jbyteArray,
+    view_handle: Handle,
+) -> Handle {
+    let res = panic::catch_unwind(|| {
+        let group_name = utils::convert_to_string(&env, group_name)?;
+        let list_id = env.convert_byte_array(list_id)?;
+        let address = IndexAddress::with_root(group_name).append_bytes(&list_id);
+        let view_ref = handle::cast_handle::<View>(view_handle).get();
+        Ok(handle::to_handle(match view_ref {
+            ViewRef::Snapshot(snapshot) => IndexType::SnapshotIndex(index_access::unwrap_or_throw(
+                &env,
+                Index::from_access(snapshot, address),
+            )?),
+            ViewRef::Fork(fork) => IndexType::ForkIndex(index_access::unwrap_or_throw(
+                &env,
+                Index::from_access(fork, address),
+            )?),
+        }))
+    });
+    utils::unwrap_exc_or_default(&env, res)
+}
+
+/// Destroys the underlying `SparseListIndex` object and frees memory.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_SparseListIndexProxy_nativeFree(
+    env: JNIEnv,
+    _: JClass,
+    list_handle: Handle,
+) {
+    handle::drop_handle::<IndexType>(&env, list_handle);
+}
+
+/// Returns the value by index. Null pointer is returned if value is not found.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_SparseListIndexProxy_nativeGet(
+    env: JNIEnv,
+    _: JObject,
+    list_handle: Handle,
+    index: jlong,
+) -> jbyteArray {
+    let res = panic::catch_unwind(|| {
+        let val = match *handle::cast_handle::<IndexType>(list_handle) {
+            IndexType::SnapshotIndex(ref list) => list.get(index as u64),
+            IndexType::ForkIndex(ref list) => list.get(index as u64),
+        };
+        match val {
+            Some(val) => env.byte_array_from_slice(&val),
+            None => Ok(ptr::null_mut()),
+        }
+    });
+    utils::unwrap_exc_or(&env, res, ptr::null_mut())
+}
+
+/// Returns `true` if the list has no values.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_SparseListIndexProxy_nativeIsEmpty(
+    env: JNIEnv,
+    _: JObject,
+    list_handle: Handle,
+) -> jboolean {
+    let res = panic::catch_unwind(|| {
+        Ok(match *handle::cast_handle::<IndexType>(list_handle) {
+            IndexType::SnapshotIndex(ref list) => list.is_empty(),
+            IndexType::ForkIndex(ref list) => list.is_empty(),
+        } as jboolean)
+    });
+    utils::unwrap_exc_or_default(&env, res)
+}
+
+/// Returns the capacity of the list, i.e., the index following the last pushed value.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_SparseListIndexProxy_nativeCapacity(
+    env: JNIEnv,
+    _: JObject,
+    list_handle: Handle,
+) -> jlong {
+    let res = panic::catch_unwind(|| {
+        Ok(match *handle::cast_handle::<IndexType>(list_handle) {
+            IndexType::SnapshotIndex(ref list) => list.capacity(),
+            IndexType::ForkIndex(ref list) => list.capacity(),
+        } as jlong)
+    });
+    utils::unwrap_exc_or_default(&env, res)
+}
+
+/// Returns the number of values in the list.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_SparseListIndexProxy_nativeSize(
+    env: JNIEnv,
+    _: JObject,
+    list_handle: Handle,
+) -> jlong {
+    let res = panic::catch_unwind(|| {
+        Ok(match *handle::cast_handle::<IndexType>(list_handle) {
+            IndexType::SnapshotIndex(ref list) => list.len(),
+            IndexType::ForkIndex(ref list) => list.len(),
+        } as jlong)
+    });
+    utils::unwrap_exc_or_default(&env, res)
+}
+
+/// Appends value to the list.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_SparseListIndexProxy_nativeAdd(
+    env: JNIEnv,
+    _: JObject,
+    list_handle: Handle,
+    value: jbyteArray,
+) {
+    let res = panic::catch_unwind(|| match *handle::cast_handle::<IndexType>(list_handle) {
+        IndexType::SnapshotIndex(_) => {
+            panic!("Unable to modify snapshot.");
+        }
+        IndexType::ForkIndex(ref mut list) => {
+            let value = env.convert_byte_array(value)?;
+            list.push(value);
+            Ok(())
+        }
+    });
+    utils::unwrap_exc_or_default(&env, res)
+}
+
+/// Sets value into specified index. Panics if `index` is not less than the capacity.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_SparseListIndexProxy_nativeSet(
+    env: JNIEnv,
+    _: JObject,
+    list_handle: Handle,
+    index: jlong,
+    value: jbyteArray,
+) {
+    let res = panic::catch_unwind(|| match *handle::cast_handle::<IndexType>(list_handle) {
+        IndexType::SnapshotIndex(_) => {
+            panic!("Unable to modify snapshot.");
+        }
+        IndexType::ForkIndex(ref mut list) => {
+            let value = env.convert_byte_array(value)?;
+            list.set(index as u64, value);
+            Ok(())
+        }
+    });
+    utils::unwrap_exc_or_default(&env, res)
+}
+
+/// Removes the value by index and returns it, or null pointer if there is no such value.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_SparseListIndexProxy_nativeRemove(
+    env: JNIEnv,
+    _: JObject,
+    list_handle: Handle,
+    index: jlong,
+) -> jbyteArray {
+    let res = panic::catch_unwind(|| {
+        let val = match *handle::cast_handle::<IndexType>(list_handle) {
+            IndexType::SnapshotIndex(_) => panic!("Unable to modify snapshot."),
+            IndexType::ForkIndex(ref mut list) => list.remove(index as u64),
+        };
+        match val {
+            Some(val) => env.byte_array_from_slice(&val),
+            None => Ok(ptr::null_mut()),
+        }
+    });
+    utils::unwrap_exc_or(&env, res, ptr::null_mut())
+}
+
+/// Clears the list, removing all values and resetting its capacity.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_SparseListIndexProxy_nativeClear(
+    env: JNIEnv,
+    _: JObject,
+    list_handle: Handle,
+) {
+    let res = panic::catch_unwind(|| match *handle::cast_handle::<IndexType>(list_handle) {
+        IndexType::SnapshotIndex(_) => {
+            panic!("Unable to modify snapshot.");
+        }
+        IndexType::ForkIndex(ref mut list) => {
+            list.clear();
+            Ok(())
+        }
+    });
+    utils::unwrap_exc_or_default(&env, res)
+}
+
+/// Returns pointer to the iterator over the indices of the list values.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_SparseListIndexProxy_nativeCreateIndexIter(
+    env: JNIEnv,
+    _: JObject,
+    list_handle: Handle,
+) -> Handle {
+    let res = panic::catch_unwind(|| {
+        Ok(handle::to_handle(
+            match *handle::cast_handle::<IndexType>(list_handle) {
+                IndexType::SnapshotIndex(ref list) => list.indices(),
+                IndexType::ForkIndex(ref list) => list.indices(),
+            },
+        ))
+    });
+    utils::unwrap_exc_or_default(&env, res)
+}
+
+/// Returns the next indices from the iterator, packed into an array of byte arrays.
+/// Returns at most `max_count` indices, stopping early once their total size reaches
+/// `max_bytes`. Returns an empty array when iteration is finished.
+/// Each index is serialized as a little-endian 64-bit integer.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_SparseListIndexProxy_nativeIndexIterNextBatch(
+    env: JNIEnv,
+    _: JObject,
+    iter_handle: Handle,
+    max_count: jint,
+    max_bytes: jint,
+) -> jobjectArray {
+    let res = panic::catch_unwind(|| {
+        let iter = handle::cast_handle::<SparseListIndexKeys>(iter_handle);
+        iter_batch::next_batch(&env, iter, max_count, max_bytes, |index, parts| {
+            parts.push(index.to_bytes())
+        })
+    });
+    utils::unwrap_exc_or(&env, res, ptr::null_mut())
+}
+
+/// Destroys the underlying iterator over indices and frees memory.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_SparseListIndexProxy_nativeIndexIterFree(
+    env: JNIEnv,
+    _: JObject,
+    iter_handle: Handle,
+) {
+    handle::drop_handle::<SparseListIndexKeys>(&env, iter_handle);
+}
+
+/// Returns pointer to the iterator over the list values.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_SparseListIndexProxy_nativeCreateValueIter(
+    env: JNIEnv,
+    _: JObject,
+    list_handle: Handle,
+) -> Handle {
+    let res = panic::catch_unwind(|| {
+        Ok(handle::to_handle(
+            match *handle::cast_handle::<IndexType>(list_handle) {
+                IndexType::SnapshotIndex(ref list) => list.values(),
+                IndexType::ForkIndex(ref list) => list.values(),
+            },
+        ))
+    });
+    utils::unwrap_exc_or_default(&env, res)
+}
+
+/// Returns the next values from the iterator, packed into an array of byte arrays.
+/// Returns at most `max_count` values, stopping early once their total size reaches
+/// `max_bytes`. Returns an empty array when iteration is finished.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_SparseListIndexProxy_nativeValueIterNextBatch(
+    env: JNIEnv,
+    _: JObject,
+    iter_handle: Handle,
+    max_count: jint,
+    max_bytes: jint,
+) -> jobjectArray {
+    let res = panic::catch_unwind(|| {
+        let iter = handle::cast_handle::<SparseListIndexValues<Value>>(iter_handle);
+        iter_batch::next_batch(&env, iter, max_count, max_bytes, |value, parts| {
+            parts.push(value)
+        })
+    });
+    utils::unwrap_exc_or(&env, res, ptr::null_mut())
+}
+
+/// Destroys the underlying iterator over values and frees memory.
+#[no_mangle]
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_SparseListIndexProxy_nativeValueIterFree(
+    env: JNIEnv,
+    _: JObject,
+    iter_handle: Handle,
+) {
+    handle::drop_handle::<SparseListIndexValues<Value>>(&env, iter_handle);
+}
@@ -19,7 +19,7 @@ use exonum_merkledb::{
 };
 use jni::{
     objects::{JClass, JObject, JString},
-    sys::{jboolean, jbyteArray, jobject},
+    sys::{jboolean, jbyteArray, jint, jobjectArray},
     JNIEnv,
 };
 
@@ -28,7 +28,7 @@ use std::{panic, ptr};
 use handle::{self, Handle};
 use storage::{
     db::{Value, View, ViewRef},
-    PairIter,
+    index_access, iter_batch, PairIter,
 };
 use utils;
 
@@ -56,12 +56,13 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ValueSetInde
         let name = utils::convert_to_string(&env, name)?;
         Ok(handle::to_handle(
             match handle::cast_handle::<View>(view_handle).get() {
-                ViewRef::Snapshot(snapshot) => {
-                    IndexType::SnapshotIndex(Index::from_access(snapshot, name.into()).unwrap())
-                }
-                ViewRef::Fork(fork) => {
-                    IndexType::ForkIndex(Index::from_access(fork, name.into()).unwrap())
-                }
+                ViewRef::Snapshot(snapshot) => IndexType::SnapshotIndex(
+                    index_access::unwrap_or_throw(&env, Index::from_access(snapshot, name.into()))?,
+                ),
+                ViewRef::Fork(fork) => IndexType::ForkIndex(index_access::unwrap_or_throw(
+                    &env,
+                    Index::from_access(fork, name.into()),
+                )?),
             },
         ))
     });
@@ -83,10 +84,14 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ValueSetInde
         let address = IndexAddress::with_root(group_name).append_bytes(&set_id);
         let view_ref = handle::cast_handle::<View>(view_handle).get();
         Ok(handle::to_handle(match view_ref {
-            ViewRef::Snapshot(snapshot) => {
-                IndexType::SnapshotIndex(Index::from_access(snapshot, address).unwrap())
-            }
-            ViewRef::Fork(fork) => IndexType::ForkIndex(Index::from_access(fork, address).unwrap()),
+            ViewRef::Snapshot(snapshot) => IndexType::SnapshotIndex(index_access::unwrap_or_throw(
+                &env,
+                Index::from_access(snapshot, address),
+            )?),
+            ViewRef::Fork(fork) => IndexType::ForkIndex(index_access::unwrap_or_throw(
+                &env,
+                Index::from_access(fork, address),
+            )?),
         }))
     });
     utils::unwrap_exc_or_default(&env, res)
@@ -296,29 +301,30 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ValueSetInde
     utils::unwrap_exc_or_default(&env, res)
 }
 
-/// Returns next value from the iterator. Returns null pointer when iteration is finished.
+/// Returns the next entries from the iterator, packed into an array of byte arrays,
+/// where the hash of each entry is followed by its value.
+/// Returns at most `max_count` entries, stopping early once their total size reaches
+/// `max_bytes`. Returns an empty array when iteration is finished.
 #[no_mangle]
-pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ValueSetIndexProxy_nativeIteratorNext(
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ValueSetIndexProxy_nativeIteratorNextBatch(
     env: JNIEnv,
     _: JObject,
     iter_handle: Handle,
-) -> jobject {
+    max_count: jint,
+    max_bytes: jint,
+) -> jobjectArray {
     let res = panic::catch_unwind(|| {
-        let iterWrapper = handle::cast_handle::<Iter>(iter_handle);
-        match iterWrapper.iter.next() {
-            Some(val) => {
-                let hash: JObject = utils::convert_hash(&env, &val.0)?.into();
-                let value: JObject = env.byte_array_from_slice(&val.1)?.into();
-                Ok(env
-                    .new_object_unchecked(
-                        &iterWrapper.element_class,
-                        iterWrapper.constructor_id,
-                        &[hash.into(), value.into()],
-                    )?
-                    .into_inner())
-            }
-            None => Ok(ptr::null_mut()),
-        }
+        let iter = handle::cast_handle::<Iter>(iter_handle);
+        iter_batch::next_batch(
+            &env,
+            &mut iter.iter,
+            max_count,
+            max_bytes,
+            |(hash, value), parts| {
+                parts.push(hash.as_ref().to_vec());
+                parts.push(value);
+            },
+        )
     });
     utils::unwrap_exc_or(&env, res, ptr::null_mut())
 }
@@ -333,19 +339,22 @@ pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ValueSetInde
     handle::drop_handle::<Iter>(&env, iter_handle);
 }
 
-/// Returns next value from the hash-iterator. Returns null pointer when iteration is finished.
+/// Returns the next hashes from the iterator, packed into an array of byte arrays.
+/// Returns at most `max_count` hashes, stopping early once their total size reaches
+/// `max_bytes`. Returns an empty array when iteration is finished.
 #[no_mangle]
-pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ValueSetIndexProxy_nativeHashIteratorNext(
+pub extern "system" fn Java_com_exonum_binding_core_storage_indices_ValueSetIndexProxy_nativeHashIteratorNextBatch(
     env: JNIEnv,
     _: JObject,
     iter_handle: Handle,
-) -> jbyteArray {
+    max_count: jint,
+    max_bytes: jint,
+) -> jobjectArray {
     let res = panic::catch_unwind(|| {
         let iter = handle::cast_handle::<ValueSetIndexHashes>(iter_handle);
-        match iter.next() {
-            Some(val) => utils::convert_hash(&env, &val),
-            None => Ok(ptr::null_mut()),
-        }
+        iter_batch::next_batch(&env, iter, max_count, max_bytes, |hash, parts| {
+            parts.push(hash.as_ref().to_vec())
+        })
     });
     utils::unwrap_exc_or(&env, res, ptr::null_mut())
 }
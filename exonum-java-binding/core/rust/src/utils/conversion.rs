@@ -14,12 +14,14 @@
 
 use exonum::crypto::Hash;
 use exonum_proto::ProtobufConvert;
-use jni::objects::JString;
+use jni::objects::{JObject, JString};
 use jni::sys::{jbyteArray, jobjectArray};
 use jni::JNIEnv;
 use protobuf::Message;
 use JniResult;
 
+const ILLEGAL_ARGUMENT_EXCEPTION: &str = "java/lang/IllegalArgumentException";
+
 /// Converts Java byte array to `Hash`. Panics if array has the wrong length.
 pub fn convert_to_hash(env: &JNIEnv, array: jbyteArray) -> JniResult<Hash> {
     let bytes = env.convert_byte_array(array)?;
@@ -66,3 +68,47 @@ where
     }
     Ok(result)
 }
+
+/// Converts Java arrays of keys and values (`byte[][]`) to the vector of key-value pairs.
+///
+/// Throws `IllegalArgumentException` and returns `None` if the arrays have different lengths.
+pub fn java_pairs_to_rust<K, F>(
+    env: &JNIEnv,
+    keys: jobjectArray,
+    values: jobjectArray,
+    to_rust_key: F,
+) -> JniResult<Option<Vec<(K, Vec<u8>)>>>
+where
+    F: Fn(&JNIEnv, jbyteArray) -> JniResult<K>,
+{
+    let num_keys = env.get_array_length(keys)?;
+    let num_values = env.get_array_length(values)?;
+    if num_keys != num_values {
+        let message = format!(
+            "Keys and values must have the same length, but got {} keys and {} values",
+            num_keys, num_values
+        );
+        env.throw_new(ILLEGAL_ARGUMENT_EXCEPTION, message)?;
+        return Ok(None);
+    }
+    let keys = java_arrays_to_rust(env, keys, to_rust_key)?;
+    let values = java_arrays_to_rust(env, values, |env, value| env.convert_byte_array(value))?;
+    Ok(Some(keys.into_iter().zip(values).collect()))
+}
+
+/// Converts optional Rust byte arrays to Java array of byte arrays (`byte[][]`),
+/// with `null` in place of absent ones.
+pub fn optional_arrays_to_java<T: AsRef<[u8]>>(
+    env: &JNIEnv,
+    arrays: &[Option<T>],
+) -> JniResult<jobjectArray> {
+    let result = env.new_object_array(arrays.len() as i32, "[B", JObject::null())?;
+    for (i, array) in arrays.iter().enumerate() {
+        if let Some(array) = array {
+            let element: JObject = env.byte_array_from_slice(array.as_ref())?.into();
+            env.set_object_array_element(result, i as i32, element)?;
+            env.delete_local_ref(element)?;
+        }
+    }
+    Ok(result)
+}
@@ -24,7 +24,10 @@ use std::error::Error;
 use std::result;
 use std::thread;
 
-use utils::{get_class_name, get_exception_message, jni_cache::classes_refs};
+use utils::{
+    get_class_name, get_exception_cause, get_exception_message, get_exception_stack_trace,
+    jni_cache::classes_refs,
+};
 use {JniError, JniErrorKind, JniResult};
 
 /// Unwraps the result, returning its content.
@@ -134,6 +137,59 @@ pub fn describe_java_exception(env: &JNIEnv, exception: JObject) -> String {
     unwrap_jni_verbose(env, format())
 }
 
+/// The maximum number of exceptions in the cause chain included in the verbose description.
+const MAX_DESCRIBED_CAUSES: usize = 8;
+/// The maximum number of stack frames of each exception included in the verbose description.
+const MAX_DESCRIBED_FRAMES: usize = 10;
+
+/// Describes Java exception along with its cause chain and truncated stack traces
+/// in a form of:
+///
+/// ```text
+/// Java exception: EXCEPTION_NAME; EXCEPTION_DETAILS
+///     at FRAME
+///     ... N more
+/// Caused by: CAUSE_NAME; CAUSE_DETAILS
+///     at FRAME
+/// ```
+pub fn describe_java_exception_verbose<'e>(env: &JNIEnv<'e>, exception: JObject<'e>) -> String {
+    assert!(!exception.is_null(), "No exception thrown.");
+    let format = || {
+        let mut description = "Java exception: ".to_owned();
+        let mut current = Some(exception);
+        let mut depth = 0;
+        while let Some(exception) = current {
+            if depth == MAX_DESCRIBED_CAUSES {
+                description.push_str("\n... (further causes omitted)");
+                break;
+            }
+            if depth > 0 {
+                description.push_str("\nCaused by: ");
+            }
+            description.push_str(&format!(
+                "{}; {:?}",
+                get_class_name(env, exception)?,
+                get_exception_message(env, exception)?,
+            ));
+
+            let (frames, total_frames) =
+                get_exception_stack_trace(env, exception, MAX_DESCRIBED_FRAMES)?;
+            for frame in &frames {
+                description.push_str("\n\tat ");
+                description.push_str(frame);
+            }
+            if total_frames > frames.len() {
+                description.push_str(&format!("\n\t... {} more", total_frames - frames.len()));
+            }
+
+            current = get_exception_cause(env, exception)?;
+            depth += 1;
+        }
+        Ok(description)
+    };
+    unwrap_jni_verbose(env, format())
+}
+
 type ExceptionResult<T> = thread::Result<result::Result<T, JniError>>;
 
 /// Returns value or "throws" exception. `error_val` is returned, because exception will be thrown
@@ -14,18 +14,24 @@
  * limitations under the License.
  */
 
-use jni::objects::JObject;
+use jni::objects::{JClass, JObject};
 use jni::signature::JavaType;
+use jni::sys::jsize;
 use jni::JNIEnv;
 
+use std::cmp;
+
 use utils::{
     convert_to_string,
-    jni_cache::{class, object, throwable},
+    jni_cache::{class, classes_refs, object, thread, throwable},
 };
 use JniResult;
 
 const RETVAL_TYPE_STRING: &str = "java/lang/String";
 const RETVAL_TYPE_CLASS: &str = "java/lang/Class";
+const RETVAL_TYPE_THROWABLE: &str = "java/lang/Throwable";
+const RETVAL_TYPE_THREAD: &str = "java/lang/Thread";
+const RETVAL_TYPE_STACK_TRACE_ELEMENT: &str = "java/lang/StackTraceElement";
 
 /// Returns a class name of an obj as a `String`.
 pub fn get_class_name(env: &JNIEnv, obj: JObject) -> JniResult<String> {
@@ -66,3 +72,89 @@ pub fn get_exception_message(env: &JNIEnv, exception: JObject) -> JniResult<Opti
     }
     convert_to_string(env, message).map(Some)
 }
+
+/// Returns the cause of the exception if it is not null.
+///
+/// `exception` should extend `java.lang.Throwable` and be not null
+pub fn get_exception_cause<'e>(
+    env: &JNIEnv<'e>,
+    exception: JObject,
+) -> JniResult<Option<JObject<'e>>> {
+    assert!(!exception.is_null(), "Invalid exception argument");
+    let cause = env
+        .call_method_unchecked(
+            exception,
+            throwable::get_cause_id(),
+            JavaType::Object(RETVAL_TYPE_THROWABLE.into()),
+            &[],
+        )?
+        .l()?;
+    if cause.is_null() {
+        return Ok(None);
+    }
+    Ok(Some(cause))
+}
+
+/// Returns at most `max_frames` top frames of the stack trace of the exception
+/// and the total number of frames in it.
+///
+/// `exception` should extend `java.lang.Throwable` and be not null
+pub fn get_exception_stack_trace(
+    env: &JNIEnv,
+    exception: JObject,
+    max_frames: usize,
+) -> JniResult<(Vec<String>, usize)> {
+    assert!(!exception.is_null(), "Invalid exception argument");
+    let stack_trace = env
+        .call_method_unchecked(
+            exception,
+            throwable::get_stack_trace_id(),
+            JavaType::Array(Box::new(JavaType::Object(
+                RETVAL_TYPE_STACK_TRACE_ELEMENT.into(),
+            ))),
+            &[],
+        )?
+        .l()?;
+    let total_frames = env.get_array_length(stack_trace.into_inner())? as usize;
+
+    let mut frames = Vec::new();
+    for i in 0..cmp::min(max_frames, total_frames) {
+        let frame = env.get_object_array_element(stack_trace.into_inner(), i as jsize)?;
+        let frame_description = env
+            .call_method_unchecked(
+                frame,
+                object::to_string_id(),
+                JavaType::Object(RETVAL_TYPE_STRING.into()),
+                &[],
+            )?
+            .l()?;
+        frames.push(convert_to_string(env, frame_description)?);
+        // Release the references as the stack trace might be long
+        env.delete_local_ref(frame_description)?;
+        env.delete_local_ref(frame)?;
+    }
+    env.delete_local_ref(stack_trace)?;
+    Ok((frames, total_frames))
+}
+
+/// Returns the name of the current Java thread.
+pub fn get_current_thread_name(env: &JNIEnv) -> JniResult<String> {
+    let thread_class = classes_refs::java_lang_thread();
+    let thread = env
+        .call_static_method_unchecked(
+            JClass::from(thread_class.as_obj()),
+            thread::current_thread_id(),
+            JavaType::Object(RETVAL_TYPE_THREAD.into()),
+            &[],
+        )?
+        .l()?;
+    let name = env
+        .call_method_unchecked(
+            thread,
+            thread::get_name_id(),
+            JavaType::Object(RETVAL_TYPE_STRING.into()),
+            &[],
+        )?
+        .l()?;
+    convert_to_string(env, name)
+}
@@ -21,7 +21,7 @@
 //! See: https://docs.oracle.com/en/java/javase/12/docs/specs/jni/invocation.html#jni_onload
 
 use jni::{
-    objects::{GlobalRef, JMethodID},
+    objects::{GlobalRef, JMethodID, JStaticMethodID},
     sys::{jint, JNI_VERSION_1_8},
     JNIEnv, JavaVM,
 };
@@ -35,13 +35,19 @@ const SERVICE_RUNTIME_ADAPTER_CLASS: &str = "com/exonum/binding/core/runtime/Ser
 static INIT: Once = Once::new();
 
 static mut OBJECT_GET_CLASS: Option<JMethodID> = None;
+static mut OBJECT_TO_STRING: Option<JMethodID> = None;
 static mut CLASS_GET_NAME: Option<JMethodID> = None;
 static mut THROWABLE_GET_MESSAGE: Option<JMethodID> = None;
+static mut THROWABLE_GET_CAUSE: Option<JMethodID> = None;
+static mut THROWABLE_GET_STACK_TRACE: Option<JMethodID> = None;
+static mut THREAD_CURRENT_THREAD: Option<JStaticMethodID> = None;
+static mut THREAD_GET_NAME: Option<JMethodID> = None;
 static mut TX_EXECUTION_GET_ERROR_CODE: Option<JMethodID> = None;
 
 static mut RUNTIME_ADAPTER_INITIALIZE: Option<JMethodID> = None;
 static mut RUNTIME_ADAPTER_DEPLOY_ARTIFACT: Option<JMethodID> = None;
 static mut RUNTIME_ADAPTER_IS_ARTIFACT_DEPLOYED: Option<JMethodID> = None;
+static mut RUNTIME_ADAPTER_UNLOAD_ARTIFACT: Option<JMethodID> = None;
 static mut RUNTIME_ADAPTER_START_ADDING_SERVICE: Option<JMethodID> = None;
 static mut RUNTIME_ADAPTER_COMMIT_SERVICE: Option<JMethodID> = None;
 static mut RUNTIME_ADAPTER_EXECUTE_TX: Option<JMethodID> = None;
@@ -50,6 +56,9 @@ static mut RUNTIME_ADAPTER_BEFORE_COMMIT: Option<JMethodID> = None;
 static mut RUNTIME_ADAPTER_AFTER_COMMIT: Option<JMethodID> = None;
 static mut RUNTIME_ADAPTER_SHUTDOWN: Option<JMethodID> = None;
 
+static mut BLOCK_COMMIT_LISTENER_ON_BLOCK_COMMITTED: Option<JMethodID> = None;
+
+static mut JAVA_LANG_THREAD: Option<GlobalRef> = None;
 static mut JAVA_LANG_ERROR: Option<GlobalRef> = None;
 static mut JAVA_LANG_RUNTIME_EXCEPTION: Option<GlobalRef> = None;
 static mut TRANSACTION_EXECUTION_EXCEPTION: Option<GlobalRef> = None;
@@ -77,6 +86,7 @@ pub fn init_cache(env: &JNIEnv) {
 /// Caches all required classes and methods ids.
 unsafe fn cache_methods(env: &JNIEnv) {
     OBJECT_GET_CLASS = get_method_id(&env, "java/lang/Object", "getClass", "()Ljava/lang/Class;");
+    OBJECT_TO_STRING = get_method_id(&env, "java/lang/Object", "toString", "()Ljava/lang/String;");
     CLASS_GET_NAME = get_method_id(&env, "java/lang/Class", "getName", "()Ljava/lang/String;");
     THROWABLE_GET_MESSAGE = get_method_id(
         &env,
@@ -84,6 +94,25 @@ unsafe fn cache_methods(env: &JNIEnv) {
         "getMessage",
         "()Ljava/lang/String;",
     );
+    THROWABLE_GET_CAUSE = get_method_id(
+        &env,
+        "java/lang/Throwable",
+        "getCause",
+        "()Ljava/lang/Throwable;",
+    );
+    THROWABLE_GET_STACK_TRACE = get_method_id(
+        &env,
+        "java/lang/Throwable",
+        "getStackTrace",
+        "()[Ljava/lang/StackTraceElement;",
+    );
+    THREAD_CURRENT_THREAD = get_static_method_id(
+        &env,
+        "java/lang/Thread",
+        "currentThread",
+        "()Ljava/lang/Thread;",
+    );
+    THREAD_GET_NAME = get_method_id(&env, "java/lang/Thread", "getName", "()Ljava/lang/String;");
     TX_EXECUTION_GET_ERROR_CODE = get_method_id(
         &env,
         "com/exonum/binding/core/transaction/TransactionExecutionException",
@@ -104,6 +133,12 @@ unsafe fn cache_methods(env: &JNIEnv) {
         "isArtifactDeployed",
         "(Ljava/lang/String;)Z",
     );
+    RUNTIME_ADAPTER_UNLOAD_ARTIFACT = get_method_id(
+        &env,
+        SERVICE_RUNTIME_ADAPTER_CLASS,
+        "unloadArtifact",
+        "(Ljava/lang/String;)V",
+    );
     RUNTIME_ADAPTER_START_ADDING_SERVICE = get_method_id(
         &env,
         SERVICE_RUNTIME_ADAPTER_CLASS,
@@ -120,7 +155,7 @@ unsafe fn cache_methods(env: &JNIEnv) {
         &env,
         SERVICE_RUNTIME_ADAPTER_CLASS,
         "executeTransaction",
-        "(ILjava/lang/String;I[BJI[B[B)V",
+        "([BJ)V",
     );
     RUNTIME_ADAPTER_STATE_HASHES = get_method_id(
         &env,
@@ -130,10 +165,23 @@ unsafe fn cache_methods(env: &JNIEnv) {
     );
     RUNTIME_ADAPTER_BEFORE_COMMIT =
         get_method_id(&env, SERVICE_RUNTIME_ADAPTER_CLASS, "beforeCommit", "(IJ)V");
-    RUNTIME_ADAPTER_AFTER_COMMIT =
-        get_method_id(&env, SERVICE_RUNTIME_ADAPTER_CLASS, "afterCommit", "(JIJ)V");
+    RUNTIME_ADAPTER_AFTER_COMMIT = get_method_id(
+        &env,
+        SERVICE_RUNTIME_ADAPTER_CLASS,
+        "afterCommit",
+        "(JIJ)[B",
+    );
     RUNTIME_ADAPTER_SHUTDOWN =
         get_method_id(&env, SERVICE_RUNTIME_ADAPTER_CLASS, "shutdown", "()V");
+    BLOCK_COMMIT_LISTENER_ON_BLOCK_COMMITTED = get_method_id(
+        &env,
+        "com/exonum/binding/core/service/NativeBlockCommitListener",
+        "onBlockCommitted",
+        "(J[B)V",
+    );
+    JAVA_LANG_THREAD = env
+        .new_global_ref(env.find_class("java/lang/Thread").unwrap().into())
+        .ok();
     JAVA_LANG_ERROR = env
         .new_global_ref(env.find_class("java/lang/Error").unwrap().into())
         .ok();
@@ -157,12 +205,18 @@ unsafe fn cache_methods(env: &JNIEnv) {
 
     assert!(
         OBJECT_GET_CLASS.is_some()
+            && OBJECT_TO_STRING.is_some()
             && JAVA_LANG_ERROR.is_some()
             && THROWABLE_GET_MESSAGE.is_some()
+            && THROWABLE_GET_CAUSE.is_some()
+            && THROWABLE_GET_STACK_TRACE.is_some()
+            && THREAD_CURRENT_THREAD.is_some()
+            && THREAD_GET_NAME.is_some()
             && TX_EXECUTION_GET_ERROR_CODE.is_some()
             && RUNTIME_ADAPTER_INITIALIZE.is_some()
             && RUNTIME_ADAPTER_DEPLOY_ARTIFACT.is_some()
             && RUNTIME_ADAPTER_IS_ARTIFACT_DEPLOYED.is_some()
+            && RUNTIME_ADAPTER_UNLOAD_ARTIFACT.is_some()
             && RUNTIME_ADAPTER_START_ADDING_SERVICE.is_some()
             && RUNTIME_ADAPTER_COMMIT_SERVICE.is_some()
             && RUNTIME_ADAPTER_EXECUTE_TX.is_some()
@@ -170,6 +224,8 @@ unsafe fn cache_methods(env: &JNIEnv) {
             && RUNTIME_ADAPTER_BEFORE_COMMIT.is_some()
             && RUNTIME_ADAPTER_AFTER_COMMIT.is_some()
             && RUNTIME_ADAPTER_SHUTDOWN.is_some()
+            && BLOCK_COMMIT_LISTENER_ON_BLOCK_COMMITTED.is_some()
+            && JAVA_LANG_THREAD.is_some()
             && JAVA_LANG_ERROR.is_some()
             && JAVA_LANG_RUNTIME_EXCEPTION.is_some()
             && TRANSACTION_EXECUTION_EXCEPTION.is_some()
@@ -188,6 +244,19 @@ fn get_method_id(env: &JNIEnv, class: &str, name: &str, sig: &str) -> Option<JMe
         .ok()
 }
 
+/// Produces `JStaticMethodID` for a particular class dealing with its lifetime.
+fn get_static_method_id(
+    env: &JNIEnv,
+    class: &str,
+    name: &str,
+    sig: &str,
+) -> Option<JStaticMethodID<'static>> {
+    env.get_static_method_id(class, name, sig)
+        // we need this line to erase lifetime in order to save underlying raw pointer in static
+        .map(|mid| mid.into_inner().into())
+        .ok()
+}
+
 fn check_cache_initialized() {
     if !INIT.state().done() {
         panic!("JNI cache is not initialized")
@@ -216,6 +285,12 @@ pub mod runtime_adapter {
         unsafe { RUNTIME_ADAPTER_IS_ARTIFACT_DEPLOYED.unwrap() }
     }
 
+    /// Returns cached `JMethodID` for `ServiceRuntimeAdapter.unloadArtifact()`.
+    pub fn unload_artifact_id() -> JMethodID<'static> {
+        check_cache_initialized();
+        unsafe { RUNTIME_ADAPTER_UNLOAD_ARTIFACT.unwrap() }
+    }
+
     /// Returns cached `JMethodID` for `ServiceRuntimeAdapter.startAddingService()`.
     pub fn start_adding_service_id() -> JMethodID<'static> {
         check_cache_initialized();
@@ -259,6 +334,17 @@ pub mod runtime_adapter {
     }
 }
 
+/// Refers to the cached methods of the `NativeBlockCommitListener` class.
+pub mod block_commit_listener {
+    use super::*;
+
+    /// Returns cached `JMethodID` for `NativeBlockCommitListener.onBlockCommitted()`.
+    pub fn on_block_committed_id() -> JMethodID<'static> {
+        check_cache_initialized();
+        unsafe { BLOCK_COMMIT_LISTENER_ON_BLOCK_COMMITTED.unwrap() }
+    }
+}
+
 /// Refers to the cached methods of the `java.lang.Object` class.
 pub mod object {
     use super::*;
@@ -268,6 +354,12 @@ pub mod object {
         check_cache_initialized();
         unsafe { OBJECT_GET_CLASS.unwrap() }
     }
+
+    /// Returns cached `JMethodID` for `java.lang.Object.toString()`.
+    pub fn to_string_id() -> JMethodID<'static> {
+        check_cache_initialized();
+        unsafe { OBJECT_TO_STRING.unwrap() }
+    }
 }
 
 /// Refers to the cached methods of the `java.lang.Class` class.
@@ -290,6 +382,35 @@ pub mod throwable {
         check_cache_initialized();
         unsafe { THROWABLE_GET_MESSAGE.unwrap() }
     }
+
+    /// Returns cached `JMethodID` for `java.lang.Throwable.getCause()`.
+    pub fn get_cause_id() -> JMethodID<'static> {
+        check_cache_initialized();
+        unsafe { THROWABLE_GET_CAUSE.unwrap() }
+    }
+
+    /// Returns cached `JMethodID` for `java.lang.Throwable.getStackTrace()`.
+    pub fn get_stack_trace_id() -> JMethodID<'static> {
+        check_cache_initialized();
+        unsafe { THROWABLE_GET_STACK_TRACE.unwrap() }
+    }
+}
+
+/// Refers to the cached methods of the `java.lang.Thread` class.
+pub mod thread {
+    use super::*;
+
+    /// Returns cached `JStaticMethodID` for `java.lang.Thread.currentThread()`.
+    pub fn current_thread_id() -> JStaticMethodID<'static> {
+        check_cache_initialized();
+        unsafe { THREAD_CURRENT_THREAD.unwrap() }
+    }
+
+    /// Returns cached `JMethodID` for `java.lang.Thread.getName()`.
+    pub fn get_name_id() -> JMethodID<'static> {
+        check_cache_initialized();
+        unsafe { THREAD_GET_NAME.unwrap() }
+    }
 }
 
 /// Refers to the cached methods of the `com.exonum.binding.core.transaction.TransactionExecutionException` class.
@@ -307,6 +428,12 @@ pub mod tx_execution_exception {
 pub mod classes_refs {
     use super::*;
 
+    /// Returns cached `JClass` for `java/lang/Thread` as a `GlobalRef`.
+    pub fn java_lang_thread() -> GlobalRef {
+        check_cache_initialized();
+        unsafe { JAVA_LANG_THREAD.clone().unwrap() }
+    }
+
     /// Returns cached `JClass` for `java/lang/Error` as a `GlobalRef`.
     pub fn java_lang_error() -> GlobalRef {
         check_cache_initialized();
@@ -22,13 +22,18 @@ mod jni;
 pub mod jni_cache;
 
 pub use self::conversion::{
-    convert_hash, convert_to_hash, convert_to_string, java_arrays_to_rust, proto_to_java_bytes,
+    convert_hash, convert_to_hash, convert_to_string, java_arrays_to_rust, java_pairs_to_rust,
+    optional_arrays_to_java, proto_to_java_bytes,
 };
 pub use self::errors::{
-    any_to_string, check_error_on_exception, describe_java_exception, get_and_clear_java_exception,
-    panic_on_exception, unwrap_exc_or, unwrap_exc_or_default, unwrap_jni, unwrap_jni_verbose,
+    any_to_string, check_error_on_exception, describe_java_exception,
+    describe_java_exception_verbose, get_and_clear_java_exception, panic_on_exception,
+    unwrap_exc_or, unwrap_exc_or_default, unwrap_jni, unwrap_jni_verbose,
+};
+pub use self::jni::{
+    get_class_name, get_current_thread_name, get_exception_cause, get_exception_message,
+    get_exception_stack_trace,
 };
-pub use self::jni::{get_class_name, get_exception_message};
 
 /// Asserts that given closure panics while executed and the resulting error message contains given
 /// substring.
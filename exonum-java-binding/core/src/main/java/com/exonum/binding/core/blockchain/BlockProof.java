@@ -0,0 +1,80 @@
+/*
+ * Copyright 2019 The Exonum Team
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+package com.exonum.binding.core.blockchain;
+
+import com.exonum.binding.core.blockchain.serialization.BlockSerializer;
+import com.exonum.binding.core.service.NodeProtos;
+import com.exonum.core.messages.Consensus.SignedMessage;
+import com.google.auto.value.AutoValue;
+import com.google.common.collect.ImmutableList;
+import com.google.protobuf.ByteString;
+import com.google.protobuf.InvalidProtocolBufferException;
+import java.util.List;
+
+/**
+ * A block together with the precommit messages of the validators that authenticate it.
+ *
+ * <p>The block proof allows to check that the block was accepted by the supermajority
+ * of the validators, using the public keys of the validators from the consensus configuration.
+ * As the block includes the {@linkplain Block#getStateHash() blockchain state hash},
+ * the block proof of the latest block anchors the proofs of the index contents
+ * created from the current database state.
+ *
+ * @see com.exonum.binding.core.service.Node#getBlockProof(long)
+ * @see <a href="https://exonum.com/doc/version/0.13-rc.2/architecture/merkledb/#state-aggregation">State aggregation</a>
+ */
+@AutoValue
+public abstract class BlockProof {
+
+  /**
+   * Returns the block.
+   */
+  public abstract Block getBlock();
+
+  /**
+   * Returns the precommit messages authenticating the block. Each message contains
+   * a serialized {@code exonum.consensus.Precommit} message, signed by a validator.
+   */
+  public abstract ImmutableList<SignedMessage> getPrecommits();
+
+  /**
+   * Creates a block proof.
+   */
+  public static BlockProof valueOf(Block block, List<SignedMessage> precommits) {
+    return new AutoValue_BlockProof(block, ImmutableList.copyOf(precommits));
+  }
+
+  /**
+   * Parses the block proof from the serialized {@code exonum.BlockProof} protobuf message.
+   *
+   * @param blockProof the serialized block proof
+   * @throws IllegalArgumentException if the message is not a valid block proof
+   */
+  public static BlockProof parseFrom(byte[] blockProof) {
+    try {
+      NodeProtos.BlockProof proof = NodeProtos.BlockProof.parseFrom(blockProof);
+      Block block = BlockSerializer.INSTANCE.fromBytes(proof.getBlock().toByteArray());
+      ImmutableList.Builder<SignedMessage> precommits = ImmutableList.builder();
+      for (ByteString precommit : proof.getPrecommitsList()) {
+        precommits.add(SignedMessage.parseFrom(precommit));
+      }
+      return valueOf(block, precommits.build());
+    } catch (InvalidProtocolBufferException e) {
+      throw new IllegalArgumentException("Invalid block proof", e);
+    }
+  }
+}
@@ -257,4 +257,12 @@ public final class Blockchain {
   public KeySetIndexProxy<HashCode> getTransactionPool() {
     return schema.getTransactionPool();
   }
+
+  /**
+   * Returns the number of transactions in the {@linkplain #getTransactionPool() pool
+   * of unconfirmed transactions}.
+   */
+  public long getTransactionPoolSize() {
+    return schema.getTransactionPoolSize();
+  }
 }
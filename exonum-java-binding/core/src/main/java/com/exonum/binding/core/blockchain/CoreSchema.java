@@ -160,6 +160,17 @@ final class CoreSchema {
         StandardSerializers.hash());
   }
 
+  /**
+   * Returns the number of transactions in the pool of unconfirmed transactions.
+   * The core maintains it along with the {@linkplain #getTransactionPool() pool},
+   * so that it is not required to iterate over the pool to find its size.
+   */
+  long getTransactionPoolSize() {
+    EntryIndexProxy<Long> poolSize = EntryIndexProxy.newInstance(CoreIndex.TRANSACTIONS_POOL_LEN,
+        dbView, StandardSerializers.fixed64());
+    return poolSize.toOptional().orElse(0L);
+  }
+
   /**
    * Returns the current consensus configuration of the network.
    *
@@ -193,6 +204,7 @@ final class CoreSchema {
     private static final String TRANSACTIONS_RESULTS = PREFIX + "transaction_results";
     private static final String TRANSACTIONS_LOCATIONS = PREFIX + "transactions_locations";
     private static final String TRANSACTIONS_POOL = PREFIX + "transactions_pool";
+    private static final String TRANSACTIONS_POOL_LEN = PREFIX + "transactions_pool_len";
     private static final String CONSENSUS_CONFIG = PREFIX + "consensus_config";
   }
 }
@@ -29,7 +29,7 @@ import java.util.Comparator;
 import java.util.List;
 import java.util.Optional;
 import java.util.SortedMap;
-import java.util.TreeMap;
+import java.util.concurrent.ConcurrentSkipListMap;
 import java.util.function.Supplier;
 import org.pf4j.Extension;
 import org.pf4j.PluginManager;
@@ -40,7 +40,8 @@ import org.pf4j.PluginState;
  * a certain format ('groupId:artifactId:version'); have a single {@link ServiceModule} as
  * an extension.
  *
- * <p>This class is not thread-safe.
+ * <p>This class is thread-safe. The plugin operations are serialized, but the lookups
+ * of the loaded services do not wait for them.
  *
  * @see <a href="https://pf4j.org/doc/getting-started.html">PF4J docs</a>
  */
@@ -52,13 +53,15 @@ final class Pf4jServiceLoader implements ServiceLoader {
 
   private final PluginManager pluginManager;
   private final ClassLoadingScopeChecker classLoadingChecker;
+  /** Guards the plugin manager, which is not thread-safe. */
+  private final Object pluginLock = new Object();
   private final SortedMap<ServiceArtifactId, LoadedServiceDefinition> loadedServices;
 
   @Inject
   Pf4jServiceLoader(PluginManager pluginManager, ClassLoadingScopeChecker classLoadingChecker) {
     this.pluginManager = checkNotNull(pluginManager);
     this.classLoadingChecker = classLoadingChecker;
-    loadedServices = new TreeMap<>(SERVICE_ID_COMPARATOR);
+    loadedServices = new ConcurrentSkipListMap<>(SERVICE_ID_COMPARATOR);
   }
 
   /**
@@ -70,23 +73,26 @@ final class Pf4jServiceLoader implements ServiceLoader {
   @Override
   public LoadedServiceDefinition loadService(Path artifactPath)
       throws ServiceLoadingException {
-    // Load a plugin
-    String pluginId = loadPlugin(artifactPath);
-    try {
-      // Verify the plugin
-      verifyPostLoad(pluginId);
+    synchronized (pluginLock) {
+      // Load a plugin
+      String pluginId = loadPlugin(artifactPath);
+      try {
+        // Verify the plugin
+        verifyPostLoad(pluginId);
 
-      // Start the plugin
-      startPlugin(pluginId);
+        // Start the plugin
+        startPlugin(pluginId);
 
-      // Load the service definition
-      return loadDefinition(pluginId);
-    } catch (IllegalArgumentException e) {
-      unloadPlugin(pluginId);
-      throw new ServiceLoadingException(String.format("Failed to load plugin %s:", pluginId), e);
-    } catch (Exception e) {
-      unloadPlugin(pluginId);
-      throw e;
+        // Load the service definition
+        return loadDefinition(pluginId);
+      } catch (IllegalArgumentException e) {
+        unloadPlugin(pluginId);
+        throw new ServiceLoadingException(String.format("Failed to load plugin %s:", pluginId),
+            e);
+      } catch (Exception e) {
+        unloadPlugin(pluginId);
+        throw e;
+      }
     }
   }
 
@@ -123,8 +129,9 @@ final class Pf4jServiceLoader implements ServiceLoader {
     LoadedServiceDefinition serviceDefinition =
         LoadedServiceDefinition.newInstance(artifactId, serviceModuleSupplier);
 
-    assert !loadedServices.containsKey(artifactId);
-    loadedServices.put(artifactId, serviceDefinition);
+    // Publish the definition once the plugin is completely loaded
+    LoadedServiceDefinition previous = loadedServices.putIfAbsent(artifactId, serviceDefinition);
+    assert previous == null;
     return serviceDefinition;
   }
 
@@ -191,42 +198,46 @@ final class Pf4jServiceLoader implements ServiceLoader {
     checkArgument(loadedServices.containsKey(artifactId), "No such artifactId: %s", artifactId);
 
     String pluginId = artifactId.getName();
-    try {
-      boolean stopped = pluginManager.unloadPlugin(pluginId);
-      // The docs don't say why it may fail to stop the plugin.
-      // Follow: https://github.com/pf4j/pf4j/issues/291
-      checkState(stopped, "Unknown error whilst unloading the plugin (%s)", pluginId);
-    } finally {
-      loadedServices.remove(artifactId);
+    synchronized (pluginLock) {
+      try {
+        boolean stopped = pluginManager.unloadPlugin(pluginId);
+        // The docs don't say why it may fail to stop the plugin.
+        // Follow: https://github.com/pf4j/pf4j/issues/291
+        checkState(stopped, "Unknown error whilst unloading the plugin (%s)", pluginId);
+      } finally {
+        loadedServices.remove(artifactId);
+      }
     }
   }
 
   @Override
   public void unloadAll() {
-    // Unload the services. As it does not matter if there are strong refs to the classes
-    // loaded by the plugin classloaders (instances of a subclass of URLClassLoader) when
-    // they are closed, unload first, clear second.
-
-    // Unload the plugins
-    List<Exception> errors = new ArrayList<>();
-    for (ServiceArtifactId artifactId : loadedServices.keySet()) {
-      String pluginId = artifactId.getName();
-      try {
-        unloadPlugin(pluginId);
-      } catch (Exception e) {
-        errors.add(e);
+    synchronized (pluginLock) {
+      // Unload the services. As it does not matter if there are strong refs to the classes
+      // loaded by the plugin classloaders (instances of a subclass of URLClassLoader) when
+      // they are closed, unload first, clear second.
+
+      // Unload the plugins
+      List<Exception> errors = new ArrayList<>();
+      for (ServiceArtifactId artifactId : loadedServices.keySet()) {
+        String pluginId = artifactId.getName();
+        try {
+          unloadPlugin(pluginId);
+        } catch (Exception e) {
+          errors.add(e);
+        }
       }
-    }
 
-    // Clear the loaded services
-    loadedServices.clear();
+      // Clear the loaded services
+      loadedServices.clear();
 
-    // Communicate the errors, if any
-    if (!errors.isEmpty()) {
-      IllegalStateException e = new IllegalStateException(
-          "Failed to unload some plugins (see suppressed)");
-      errors.forEach(e::addSuppressed);
-      throw e;
+      // Communicate the errors, if any
+      if (!errors.isEmpty()) {
+        IllegalStateException e = new IllegalStateException(
+            "Failed to unload some plugins (see suppressed)");
+        errors.forEach(e::addSuppressed);
+        throw e;
+      }
     }
   }
 
@@ -0,0 +1,52 @@
+/*
+ * Copyright 2019 The Exonum Team
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+package com.exonum.binding.core.runtime;
+
+import com.exonum.binding.core.runtime.ServiceRuntimeProtos.MailboxAction;
+import com.exonum.binding.core.runtime.ServiceRuntimeProtos.MailboxActions;
+import com.exonum.binding.core.runtime.ServiceRuntimeProtos.StartDeploy;
+import com.exonum.binding.core.service.Mailbox;
+import com.google.protobuf.ByteString;
+
+/**
+ * A mailbox that accumulates the actions requested by the services and converts them into
+ * a protobuf message passed to the native code.
+ *
+ * <p>This class is not thread-safe.
+ */
+final class RuntimeMailbox implements Mailbox {
+
+  private final MailboxActions.Builder actions = MailboxActions.newBuilder();
+
+  @Override
+  public void requestArtifactDeploy(ServiceArtifactId artifactId, byte[] deploySpec) {
+    StartDeploy startDeploy = StartDeploy.newBuilder()
+        .setRuntimeId(artifactId.getRuntimeId())
+        .setArtifactName(artifactId.getName())
+        .setDeploySpec(ByteString.copyFrom(deploySpec))
+        .build();
+    actions.addActions(MailboxAction.newBuilder()
+        .setStartDeploy(startDeploy));
+  }
+
+  /**
+   * Returns the actions requested so far.
+   */
+  MailboxActions getActions() {
+    return actions.build();
+  }
+}
@@ -22,6 +22,10 @@ import java.util.Optional;
 /**
  * A dynamic loader of Exonum service artifacts. It allows to load and unload service artifacts and
  * keeps track of already loaded services.
+ *
+ * <p>Implementations must be thread-safe: the services may be looked up while another
+ * artifact is being loaded. A loaded service becomes visible to {@link #findService} only
+ * once its loading is complete.
  */
 interface ServiceLoader {
 
@@ -24,12 +24,14 @@ import static java.util.stream.Collectors.toList;
 import com.exonum.binding.common.crypto.PublicKey;
 import com.exonum.binding.common.hash.HashCode;
 import com.exonum.binding.common.message.TransactionMessage;
+import com.exonum.binding.core.runtime.ServiceRuntimeProtos.ServiceCallMetrics;
 import com.exonum.binding.core.runtime.ServiceRuntimeProtos.ServiceRuntimeStateHashes;
 import com.exonum.binding.core.runtime.ServiceRuntimeProtos.ServiceStateHashes;
 import com.exonum.binding.core.service.BlockCommittedEvent;
 import com.exonum.binding.core.service.Node;
 import com.exonum.binding.core.storage.database.Fork;
 import com.exonum.binding.core.storage.database.Snapshot;
+import com.exonum.binding.core.transaction.CallerKind;
 import com.exonum.binding.core.transaction.TransactionContext;
 import com.exonum.binding.core.transaction.TransactionExecutionException;
 import com.exonum.binding.core.transport.Server;
@@ -40,12 +42,14 @@ import com.google.inject.name.Named;
 import com.google.protobuf.ByteString;
 import java.nio.file.Files;
 import java.nio.file.Path;
+import java.util.ArrayList;
 import java.util.HashMap;
 import java.util.List;
 import java.util.Map;
 import java.util.Optional;
 import java.util.SortedMap;
 import java.util.TreeMap;
+import javax.annotation.Nullable;
 import org.apache.logging.log4j.LogManager;
 import org.apache.logging.log4j.Logger;
 
@@ -64,6 +68,12 @@ import org.apache.logging.log4j.Logger;
 @Singleton
 public final class ServiceRuntime implements AutoCloseable {
 
+  /**
+   * The caller service id used when a service method is invoked by the blockchain core
+   * itself rather than by a transaction message or another service.
+   */
+  public static final int BLOCKCHAIN_CALLER_ID = -1;
+
   @VisibleForTesting
   static final String API_ROOT_PATH = "/api/services";
   private static final Logger logger = LogManager.getLogger(ServiceRuntime.class);
@@ -83,6 +93,11 @@ public final class ServiceRuntime implements AutoCloseable {
    * @see ServiceInstanceSpec#getId()
    */
   private final Map<Integer, ServiceWrapper> servicesById = new HashMap<>();
+  /**
+   * The number of exceptions thrown by the services in their afterCommit handlers,
+   * indexed by the service numeric identifier.
+   */
+  private final Map<Integer, Long> afterCommitFailures = new HashMap<>();
   private final Object lock = new Object();
 
   // todo: [ECR-2334] Ensure the Node is properly destroyed when the runtime is stopped
@@ -133,25 +148,25 @@ public final class ServiceRuntime implements AutoCloseable {
   public void deployArtifact(ServiceArtifactId id, String filename)
       throws ServiceLoadingException {
     try {
-      synchronized (lock) {
-        // Check the artifacts dir exists
-        checkState(Files.isDirectory(artifactsDir), "Artifacts dir (%s) does not exist or is not "
-                + "a directory: check the runtime configuration", artifactsDir);
-        Path artifactLocation = artifactsDir.resolve(filename);
-
-        // Load the service artifact
-        LoadedServiceDefinition loadedServiceDefinition = serviceLoader
-            .loadService(artifactLocation);
-
-        // Check the artifact has the correct identifier in its metadata
-        ServiceArtifactId actualId = loadedServiceDefinition.getId();
-        if (!actualId.equals(id)) {
-          // Unload the artifact
-          serviceLoader.unloadService(actualId);
-          throw new ServiceLoadingException(
-              String.format("The artifact loaded from (%s) has wrong id (%s) in "
-                  + "metadata. Expected id: %s", filename, actualId, id));
-        }
+      // Check the artifacts dir exists
+      checkState(Files.isDirectory(artifactsDir), "Artifacts dir (%s) does not exist or is not "
+              + "a directory: check the runtime configuration", artifactsDir);
+      Path artifactLocation = artifactsDir.resolve(filename);
+
+      // Load the service artifact. The loading may take considerable time, therefore,
+      // it is performed without the runtime lock, not blocking the operations
+      // of the active services.
+      LoadedServiceDefinition loadedServiceDefinition = serviceLoader
+          .loadService(artifactLocation);
+
+      // Check the artifact has the correct identifier in its metadata
+      ServiceArtifactId actualId = loadedServiceDefinition.getId();
+      if (!actualId.equals(id)) {
+        // Unload the artifact
+        serviceLoader.unloadService(actualId);
+        throw new ServiceLoadingException(
+            String.format("The artifact loaded from (%s) has wrong id (%s) in "
+                + "metadata. Expected id: %s", filename, actualId, id));
       }
 
       logger.info("Loaded an artifact ({}) from {}", id, filename);
@@ -161,6 +176,20 @@ public final class ServiceRuntime implements AutoCloseable {
     }
   }
 
+  /**
+   * Unloads a previously deployed Java service artifact. The artifact must not have any
+   * service instances.
+   *
+   * @param id a service artifact identifier
+   * @throws IllegalStateException if the artifact is not currently deployed
+   */
+  public void unloadArtifact(ServiceArtifactId id) {
+    synchronized (lock) {
+      serviceLoader.unloadService(id);
+    }
+    logger.info("Unloaded an artifact ({})", id);
+  }
+
   /**
    * Returns true if an artifact with the given id is currently deployed in this runtime.
    * @param id a service artifact identifier
@@ -284,14 +313,19 @@ public final class ServiceRuntime implements AutoCloseable {
    * @param txId the transaction type identifier
    * @param arguments the serialized transaction arguments
    * @param fork a native fork object
+   * @param callerKind the kind of the caller of the method
    * @param callerServiceId the id of the caller service if transaction is invoked by other
-   *     service. Currently only applicable to invocations of Configure interface methods
-   * @param txMessageHash the hash of the transaction message
-   * @param authorPublicKey the public key of the transaction author
+   *     service. Currently only applicable to invocations of Configure interface methods.
+   *     Equal to {@link #BLOCKCHAIN_CALLER_ID} if the method is invoked by the blockchain core
+   *     itself
+   * @param txMessageHash the hash of the transaction message; or null if the method is not
+   *     invoked by a transaction
+   * @param authorPublicKey the public key of the transaction author; or null if the method
+   *     is not invoked by a transaction
    */
   public void executeTransaction(int serviceId, String interfaceName, int txId,
-      byte[] arguments, Fork fork, int callerServiceId, HashCode txMessageHash,
-      PublicKey authorPublicKey)
+      byte[] arguments, Fork fork, CallerKind callerKind, int callerServiceId,
+      @Nullable HashCode txMessageHash, @Nullable PublicKey authorPublicKey)
       throws TransactionExecutionException {
     synchronized (lock) {
       ServiceWrapper service = getServiceById(serviceId);
@@ -302,12 +336,13 @@ public final class ServiceRuntime implements AutoCloseable {
           .authorPk(authorPublicKey)
           .serviceName(serviceName)
           .serviceId(serviceId)
+          .callerKind(callerKind)
           .build();
       try {
         service.executeTransaction(interfaceName, txId, arguments, callerServiceId, context);
       } catch (Exception e) {
-        logger.info("Transaction execution failed (service={}, txId={}, txMessageHash={})",
-            service.getName(), txId, context.getTransactionMessageHash(), e);
+        logger.info("Transaction execution failed (service={}, txId={}, caller={}, "
+                + "txMessageHash={})", service.getName(), txId, callerKind, txMessageHash, e);
         throw e;
       }
     }
@@ -315,21 +350,22 @@ public final class ServiceRuntime implements AutoCloseable {
 
   /**
    * Returns the state hashes of this runtime and the services registered in it as a protobuf
-   * message.
+   * message. The message also includes the time each service has taken to compute
+   * its state hashes.
    *
    * @param snapshot the snapshot of the current database state
    */
   public ServiceRuntimeStateHashes getStateHashes(Snapshot snapshot) {
     synchronized (lock) {
+      // The runtime itself does not have any state hashes at the moment.
+      ServiceRuntimeStateHashes.Builder stateHashes = ServiceRuntimeStateHashes.newBuilder();
       // Collect the service state hashes
-      List<ServiceStateHashes> serviceStateHashes = services.values().stream()
-          .map(service -> getServiceStateHashes(service, snapshot))
-          .collect(toList());
-
-      return ServiceRuntimeStateHashes.newBuilder()
-              // The runtime itself does not have any state hashes at the moment.
-              .addAllServiceStateHashes(serviceStateHashes)
-              .build();
+      for (ServiceWrapper service : services.values()) {
+        long startNanos = System.nanoTime();
+        stateHashes.addServiceStateHashes(getServiceStateHashes(service, snapshot))
+            .addServiceCalls(serviceCallMetrics(service, startNanos, false));
+      }
+      return stateHashes.build();
     }
   }
 
@@ -365,10 +401,16 @@ public final class ServiceRuntime implements AutoCloseable {
 
   /**
    * Notifies the services in the runtime of the block commit event.
+   *
+   * @return the metrics of the after commit handler of each service, in the order
+   *     the services are notified
    */
-  public void afterCommit(BlockCommittedEvent event) {
+  public List<ServiceCallMetrics> afterCommit(BlockCommittedEvent event) {
     synchronized (lock) {
+      List<ServiceCallMetrics> serviceCalls = new ArrayList<>(services.size());
       for (ServiceWrapper service: services.values()) {
+        long startNanos = System.nanoTime();
+        boolean failed = false;
         try {
           // todo: [ECR-3436] BCE carries a Snapshot which is based on a cleaner, which gets
           //   re-used by all services. If the total number of native proxies they create is large,
@@ -384,11 +426,36 @@ public final class ServiceRuntime implements AutoCloseable {
           //   clean-up.
           service.afterCommit(event);
         } catch (Exception e) {
-          // Log, but do not re-throw either immediately or later
-          logger.error("Service {} threw an exception in its afterCommit handler of {}",
-              service.getName(), event, e);
+          // Log and count, but do not re-throw either immediately or later
+          long failures = afterCommitFailures.merge(service.getId(), 1L, Long::sum);
+          logger.error("Service {} threw an exception in its afterCommit handler of {} "
+              + "(total failures: {})", service.getName(), event, failures, e);
+          failed = true;
         }
+        serviceCalls.add(serviceCallMetrics(service, startNanos, failed));
       }
+      return serviceCalls;
+    }
+  }
+
+  private static ServiceCallMetrics serviceCallMetrics(ServiceWrapper service, long startNanos,
+      boolean failed) {
+    return ServiceCallMetrics.newBuilder()
+        .setInstanceId(service.getId())
+        .setDurationNanos(System.nanoTime() - startNanos)
+        .setFailed(failed)
+        .build();
+  }
+
+  /**
+   * Returns the number of exceptions the service with the given id has thrown in its
+   * afterCommit handler since this runtime was started.
+   *
+   * @param serviceId the id of the service
+   */
+  public long getAfterCommitFailures(int serviceId) {
+    synchronized (lock) {
+      return afterCommitFailures.getOrDefault(serviceId, 0L);
     }
   }
 
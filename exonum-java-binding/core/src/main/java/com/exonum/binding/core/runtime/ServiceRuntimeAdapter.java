@@ -20,19 +20,26 @@ import com.exonum.binding.common.crypto.PublicKey;
 import com.exonum.binding.common.hash.HashCode;
 import com.exonum.binding.core.proxy.Cleaner;
 import com.exonum.binding.core.proxy.CloseFailuresException;
+import com.exonum.binding.core.runtime.ServiceRuntimeProtos.AfterCommitResult;
+import com.exonum.binding.core.runtime.ServiceRuntimeProtos.ExecutionContext;
+import com.exonum.binding.core.runtime.ServiceRuntimeProtos.ServiceCallMetrics;
+import com.exonum.binding.core.runtime.ServiceRuntimeProtos.ServiceCaller;
 import com.exonum.binding.core.runtime.ServiceRuntimeProtos.ServiceRuntimeStateHashes;
+import com.exonum.binding.core.runtime.ServiceRuntimeProtos.TransactionCaller;
 import com.exonum.binding.core.service.BlockCommittedEvent;
 import com.exonum.binding.core.service.BlockCommittedEventImpl;
 import com.exonum.binding.core.service.Node;
 import com.exonum.binding.core.service.NodeProxy;
 import com.exonum.binding.core.storage.database.Fork;
 import com.exonum.binding.core.storage.database.Snapshot;
+import com.exonum.binding.core.transaction.CallerKind;
 import com.exonum.binding.core.transaction.TransactionContext;
 import com.exonum.binding.core.transaction.TransactionExecutionException;
 import com.exonum.core.messages.Runtime.ArtifactId;
 import com.exonum.core.messages.Runtime.InstanceSpec;
 import com.google.inject.Inject;
 import com.google.protobuf.InvalidProtocolBufferException;
+import java.util.List;
 import java.util.OptionalInt;
 import org.apache.logging.log4j.LogManager;
 import org.apache.logging.log4j.Logger;
@@ -45,6 +52,12 @@ import org.apache.logging.log4j.Logger;
  */
 public class ServiceRuntimeAdapter {
 
+  /**
+   * The caller service id passed to the runtime when a method is invoked
+   * by a transaction message.
+   */
+  static final int TRANSACTION_CALLER_ID = 0;
+
   private final ServiceRuntime serviceRuntime;
   private final ViewFactory viewFactory;
   private static final Logger logger = LogManager.getLogger(ServiceRuntimeAdapter.class);
@@ -101,6 +114,17 @@ public class ServiceRuntimeAdapter {
     return serviceRuntime.isArtifactDeployed(artifactId);
   }
 
+  /**
+   * Unloads the artifact with the given name, which has been deployed by
+   * {@link #deployArtifact(String, byte[])}.
+   * @param name the service artifact name in format "groupId:artifactId:version"
+   * @see ServiceRuntime#unloadArtifact(ServiceArtifactId)
+   */
+  void unloadArtifact(String name) {
+    ServiceArtifactId artifactId = ServiceArtifactId.newJavaId(name);
+    serviceRuntime.unloadArtifact(artifactId);
+  }
+
   private static DeployArguments parseDeployArgs(String name, byte[] deploySpec) {
     try {
       return DeployArguments.parseFrom(deploySpec);
@@ -162,36 +186,67 @@ public class ServiceRuntimeAdapter {
   /**
    * Executes the service transaction.
    *
-   * @param serviceId the service numeric identifier
-   * @param interfaceName the name of the interface in which the transaction is defined
-   * @param txId the transaction type identifier within the service
-   * @param arguments the transaction arguments
+   * @param executionContext the context of the invocation as a serialized
+   *     {@link ExecutionContext} protobuf message: the called method, its arguments and the caller
    * @param forkNativeHandle a handle to a native fork object
-   * @param callerServiceId the id of the service which invoked the transaction (in case of
-   *      inner transactions); or 0 when the caller is an external message
-   * @param txMessageHash the hash of the transaction message
-   * @param authorPublicKey the public key of the transaction author
    * @throws TransactionExecutionException if the transaction execution failed
-   * @see ServiceRuntime#executeTransaction(int, String, int, byte[], Fork, int, HashCode,
-   *      PublicKey)
+   * @throws IllegalArgumentException if the execution context is not valid or the caller
+   *     is not supported
+   * @see ServiceRuntime#executeTransaction(int, String, int, byte[], Fork, CallerKind, int,
+   *      HashCode, PublicKey)
    * @see com.exonum.binding.core.transaction.Transaction#execute(TransactionContext)
    */
-  void executeTransaction(int serviceId, String interfaceName, int txId, byte[] arguments,
-      long forkNativeHandle, int callerServiceId, byte[] txMessageHash, byte[] authorPublicKey)
+  void executeTransaction(byte[] executionContext, long forkNativeHandle)
       throws TransactionExecutionException, CloseFailuresException {
+    ExecutionContext context = parseExecutionContext(executionContext);
+    CallerKind callerKind;
+    int callerServiceId;
+    HashCode hash = null;
+    PublicKey authorPk = null;
+    switch (context.getCallerCase()) {
+      case TRANSACTION: {
+        TransactionCaller caller = context.getTransaction();
+        callerKind = CallerKind.TRANSACTION;
+        callerServiceId = TRANSACTION_CALLER_ID;
+        hash = HashCode.fromBytes(caller.getMessageHash().toByteArray());
+        authorPk = PublicKey.fromBytes(caller.getAuthorPk().toByteArray());
+        break;
+      }
+      case SERVICE: {
+        ServiceCaller caller = context.getService();
+        callerKind = CallerKind.SERVICE;
+        callerServiceId = caller.getInstanceId();
+        break;
+      }
+      case BLOCKCHAIN: {
+        callerKind = CallerKind.BLOCKCHAIN;
+        callerServiceId = ServiceRuntime.BLOCKCHAIN_CALLER_ID;
+        break;
+      }
+      default:
+        throw new IllegalArgumentException("Unsupported caller: " + context.getCallerCase());
+    }
 
     try (Cleaner cleaner = new Cleaner("executeTransaction")) {
       Fork fork = viewFactory.createFork(forkNativeHandle, cleaner);
-      HashCode hash = HashCode.fromBytes(txMessageHash);
-      PublicKey authorPk = PublicKey.fromBytes(authorPublicKey);
 
-      serviceRuntime.executeTransaction(serviceId, interfaceName, txId, arguments, fork,
+      serviceRuntime.executeTransaction(context.getServiceId(), context.getInterfaceName(),
+          context.getMethodId(), context.getArguments().toByteArray(), fork, callerKind,
           callerServiceId, hash, authorPk);
     } catch (CloseFailuresException e) {
       handleCloseFailure(e);
     }
   }
 
+  private static ExecutionContext parseExecutionContext(byte[] executionContext) {
+    try {
+      return ExecutionContext.parseFrom(executionContext);
+    } catch (InvalidProtocolBufferException e) {
+      logger.error(e);
+      throw new IllegalArgumentException(e);
+    }
+  }
+
   /**
    * Returns the state hashes of this runtime. The state hashes are serialized in protobuf,
    * see {@link ServiceRuntimeStateHashes} for message specification.
@@ -236,22 +291,32 @@ public class ServiceRuntimeAdapter {
    * @param snapshotHandle a handle to the native snapshot object
    * @param validatorId a validator id. Negative if this node is not a validator
    * @param height the current blockchain height
+   * @return the actions requested by the services and the metrics of their handlers
+   *     as a serialized {@link AfterCommitResult} protobuf message
    * @throws CloseFailuresException if there was a failure in destroying some native peers
    * @see ServiceRuntime#afterCommit(BlockCommittedEvent)
    */
-  void afterCommit(long snapshotHandle, int validatorId, long height)
+  byte[] afterCommit(long snapshotHandle, int validatorId, long height)
       throws CloseFailuresException {
     try (Cleaner cleaner = new Cleaner("afterCommit")) {
       Snapshot snapshot = viewFactory.createSnapshot(snapshotHandle, cleaner);
       OptionalInt optionalValidatorId = validatorId >= 0
           ? OptionalInt.of(validatorId)
           : OptionalInt.empty();
+      RuntimeMailbox mailbox = new RuntimeMailbox();
       BlockCommittedEvent event =
-          BlockCommittedEventImpl.valueOf(snapshot, optionalValidatorId, height);
+          BlockCommittedEventImpl.valueOf(snapshot, optionalValidatorId, height, mailbox);
 
-      serviceRuntime.afterCommit(event);
+      List<ServiceCallMetrics> serviceCalls = serviceRuntime.afterCommit(event);
+      return AfterCommitResult.newBuilder()
+          .setActions(mailbox.getActions())
+          .addAllServiceCalls(serviceCalls)
+          .build()
+          .toByteArray();
     } catch (CloseFailuresException e) {
       handleCloseFailure(e);
+      // unreachable, ^ throws
+      return null;
     }
   }
 
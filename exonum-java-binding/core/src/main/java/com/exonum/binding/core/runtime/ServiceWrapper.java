@@ -29,6 +29,7 @@ import com.exonum.binding.core.service.Service;
 import com.exonum.binding.core.service.TransactionConverter;
 import com.exonum.binding.core.storage.database.Fork;
 import com.exonum.binding.core.storage.database.Snapshot;
+import com.exonum.binding.core.transaction.CallerKind;
 import com.exonum.binding.core.transaction.Transaction;
 import com.exonum.binding.core.transaction.TransactionContext;
 import com.exonum.binding.core.transaction.TransactionExecutionException;
@@ -162,10 +163,13 @@ final class ServiceWrapper {
     // Check the service implements Configurable
     checkArgument(service instanceof Configurable, "Service (%s) doesn't implement Configurable",
         getName());
-    // Check the caller is the supervisor
-    checkArgument(callerServiceId == SUPERVISOR_SERVICE_ID, "Invalid caller service id (%s). "
-        + "Operations in Configurable interface may only be invoked by the supervisor service (%s)",
-        callerServiceId, SUPERVISOR_SERVICE_ID);
+    // Check the caller is the blockchain or the supervisor
+    CallerKind callerKind = context.getCallerKind();
+    checkArgument(callerKind == CallerKind.BLOCKCHAIN
+        || (callerKind == CallerKind.SERVICE && callerServiceId == SUPERVISOR_SERVICE_ID),
+        "Invalid caller (%s, service id=%s). Operations in Configurable interface may only be "
+            + "invoked by the blockchain or the supervisor service (%s)",
+        callerKind, callerServiceId, SUPERVISOR_SERVICE_ID);
     // Invoke the Configurable operation
     Configurable configurable = (Configurable) service;
     Fork fork = context.getFork();
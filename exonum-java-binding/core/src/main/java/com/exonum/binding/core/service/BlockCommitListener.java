@@ -0,0 +1,43 @@
+/*
+ * Copyright 2019 The Exonum Team
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+package com.exonum.binding.core.service;
+
+import com.exonum.binding.common.hash.HashCode;
+
+/**
+ * A listener of the block commit events of the node.
+ *
+ * <p>The listeners are notified on a dedicated thread, after the services
+ * have processed the block in their {@linkplain Service#afterCommit(BlockCommittedEvent)
+ * after commit handlers}. Therefore, a slow listener does not delay the processing
+ * of the blocks, but delays the notification of other listeners. Exceptions thrown
+ * by the listener are logged. If the listeners are too slow to keep up with the blocks,
+ * the oldest pending notifications are dropped.
+ *
+ * @see Node#addBlockCommitListener(BlockCommitListener)
+ */
+@FunctionalInterface
+public interface BlockCommitListener {
+
+  /**
+   * Handles the commit of the block.
+   *
+   * @param height the height of the committed block
+   * @param blockHash the hash of the committed block
+   */
+  void onBlockCommitted(long height, HashCode blockHash);
+}
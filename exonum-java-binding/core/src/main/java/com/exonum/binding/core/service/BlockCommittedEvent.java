@@ -44,4 +44,11 @@ public interface BlockCommittedEvent {
    * as of the block at the current {@linkplain #getHeight() height}.
    */
   Snapshot getSnapshot();
+
+  /**
+   * Returns the mailbox allowing the service to request actions from the Exonum dispatcher,
+   * e.g., the deployment of a service artifact. The mailbox is valid only during the invocation
+   * of the after commit handler.
+   */
+  Mailbox getMailbox();
 }
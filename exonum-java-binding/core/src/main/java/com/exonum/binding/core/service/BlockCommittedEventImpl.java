@@ -29,10 +29,11 @@ public abstract class BlockCommittedEventImpl implements BlockCommittedEvent {
    * @param snapshot a snapshot of the blockchain state
    * @param validatorId a validator id. {@code OptionalInt.empty()} if this node is not a validator
    * @param height the current blockchain height
+   * @param mailbox a mailbox accepting the actions requested by the service
    */
   public static BlockCommittedEventImpl valueOf(
-      Snapshot snapshot, OptionalInt validatorId, long height) {
-    return new AutoValue_BlockCommittedEventImpl(snapshot, validatorId, height);
+      Snapshot snapshot, OptionalInt validatorId, long height, Mailbox mailbox) {
+    return new AutoValue_BlockCommittedEventImpl(snapshot, validatorId, height, mailbox);
   }
 
   @Override
@@ -44,4 +45,7 @@ public abstract class BlockCommittedEventImpl implements BlockCommittedEvent {
   @Override
   public abstract long getHeight();
 
+  @Override
+  public abstract Mailbox getMailbox();
+
 }
@@ -0,0 +1,30 @@
+/*
+ * Copyright 2019 The Exonum Team
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+package com.exonum.binding.core.service;
+
+/**
+ * Indicates that a signed transaction message could not be
+ * {@linkplain Node#submitSignedTransaction(byte[]) submitted} because it is malformed:
+ * for example, it cannot be parsed, has an invalid signature, or does not contain
+ * a transaction.
+ */
+public final class InvalidTransactionMessageException extends RuntimeException {
+
+  public InvalidTransactionMessageException(String message) {
+    super(message);
+  }
+}
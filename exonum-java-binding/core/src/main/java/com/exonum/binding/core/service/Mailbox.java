@@ -0,0 +1,42 @@
+/*
+ * Copyright 2019 The Exonum Team
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+package com.exonum.binding.core.service;
+
+import com.exonum.binding.core.runtime.ServiceArtifactId;
+
+/**
+ * A mailbox allowing services to request actions to be performed by the Exonum dispatcher
+ * after the block is committed.
+ *
+ * <p>The requested actions are performed asynchronously, after the
+ * {@linkplain Service#afterCommit(BlockCommittedEvent) after commit handlers} of all services
+ * complete. As the handler is invoked on each node independently, the services must coordinate
+ * the requests (e.g., through transactions) to make sure the network reaches consensus
+ * on their outcome.
+ *
+ * @see BlockCommittedEvent#getMailbox()
+ */
+public interface Mailbox {
+
+  /**
+   * Requests the deployment of a service artifact.
+   *
+   * @param artifactId the id of the artifact to deploy
+   * @param deploySpec the runtime-specific deploy specification of the artifact
+   */
+  void requestArtifactDeploy(ServiceArtifactId artifactId, byte[] deploySpec);
+}
@@ -0,0 +1,42 @@
+/*
+ * Copyright 2019 The Exonum Team
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+package com.exonum.binding.core.service;
+
+import static com.google.common.base.Preconditions.checkNotNull;
+
+import com.exonum.binding.common.hash.HashCode;
+
+/**
+ * Adapts a block commit listener to the native code, which calls
+ * {@link #onBlockCommitted(long, byte[])}.
+ */
+final class NativeBlockCommitListener {
+
+  private final BlockCommitListener listener;
+
+  NativeBlockCommitListener(BlockCommitListener listener) {
+    this.listener = checkNotNull(listener);
+  }
+
+  /**
+   * Notifies the listener of the committed block. Called by the native code.
+   */
+  @SuppressWarnings("unused")  // native API
+  void onBlockCommitted(long height, byte[] blockHash) {
+    listener.onBlockCommitted(height, HashCode.fromBytes(blockHash));
+  }
+}
@@ -16,16 +16,33 @@
 
 package com.exonum.binding.core.service;
 
+import com.exonum.binding.common.blockchain.TransactionLocation;
+import com.exonum.binding.common.crypto.CryptoFunctions;
 import com.exonum.binding.common.crypto.PublicKey;
 import com.exonum.binding.common.hash.HashCode;
+import com.exonum.binding.common.message.TransactionMessage;
+import com.exonum.binding.core.blockchain.Block;
+import com.exonum.binding.core.blockchain.BlockProof;
 import com.exonum.binding.core.blockchain.Blockchain;
 import com.exonum.binding.core.storage.database.Snapshot;
 import com.exonum.binding.core.transaction.RawTransaction;
+import com.exonum.core.messages.Blockchain.Config;
+import com.exonum.core.messages.Blockchain.ValidatorKeys;
+import com.exonum.core.messages.Runtime.ExecutionStatus;
+import com.google.common.collect.ImmutableList;
+import com.google.protobuf.ByteString;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.Optional;
+import java.util.OptionalInt;
 import java.util.function.Function;
 
 /**
  * An Exonum node context. Allows to add transactions to Exonum network
  * and get a snapshot of the database state.
+ *
+ * <p>The network state of the node, e.g., its connected peers, is not available
+ * to the services.
  */
 public interface Node {
 
@@ -52,6 +69,55 @@ public interface Node {
    */
   HashCode submitTransaction(RawTransaction rawTransaction);
 
+  /**
+   * Submits the transaction message, signed by some external key pair, into Exonum network.
+   * Unlike {@link #submitTransaction(RawTransaction)}, the message is submitted unchanged,
+   * therefore, the author of the transaction is the signer of the message.
+   *
+   * <p>The default implementation throws {@link UnsupportedOperationException}.
+   *
+   * @param signedMessage the transaction message in binary format; must have a valid signature
+   * @return hash of the transaction message
+   * @throws InvalidTransactionMessageException if the message is malformed or its signature
+   *     is not valid
+   * @throws TransactionSubmissionException if the transaction belongs to an unknown service,
+   *     or cannot be submitted
+   * @see com.exonum.binding.common.message.TransactionMessage#toBytes()
+   */
+  default HashCode submitSignedTransaction(byte[] signedMessage) {
+    throw new UnsupportedOperationException(
+        "Submission of signed transactions is not supported by " + this);
+  }
+
+  /**
+   * Submits the given transactions into Exonum network, signing them with
+   * the {@linkplain #getPublicKey() node service key}. Unlike
+   * {@link #submitTransaction(RawTransaction)}, a failure to submit some transaction
+   * does not prevent the submission of the others: it is reported in the result
+   * corresponding to that transaction.
+   *
+   * <p>The default implementation submits the transactions one by one.
+   *
+   * @param rawTransactions the transactions to submit
+   * @return the results of the submission of each transaction, in the same order
+   *     as the transactions
+   * @throws NullPointerException if any transaction is null
+   * @see #submitTransaction(RawTransaction)
+   */
+  default List<TransactionSubmissionResult> submitTransactions(
+      List<RawTransaction> rawTransactions) {
+    List<TransactionSubmissionResult> results = new ArrayList<>(rawTransactions.size());
+    for (RawTransaction transaction : rawTransactions) {
+      try {
+        HashCode hash = submitTransaction(transaction);
+        results.add(TransactionSubmissionResult.success(hash));
+      } catch (TransactionSubmissionException e) {
+        results.add(TransactionSubmissionResult.failure(e.getErrorCode(), e.getMessage()));
+      }
+    }
+    return results;
+  }
+
   /**
    * Performs the given function with a snapshot of the current database state.
    *
@@ -61,6 +127,203 @@ public interface Node {
    */
   <ResultT> ResultT withSnapshot(Function<Snapshot, ResultT> snapshotFunction);
 
+  /**
+   * Returns the height of the latest committed block.
+   *
+   * <p>The default implementation reads it from the {@linkplain Blockchain core schema}
+   * using a {@linkplain #withSnapshot(Function) snapshot}.
+   *
+   * @throws RuntimeException if the "genesis block" was not created
+   * @see Blockchain#getHeight()
+   */
+  default long getBlockchainHeight() {
+    return withSnapshot(s -> Blockchain.newInstance(s).getHeight());
+  }
+
+  /**
+   * Returns the block at the given height.
+   *
+   * @param height the height of the block; must be non-negative and less than or equal to
+   *     the current {@linkplain #getBlockchainHeight() blockchain height}
+   * @throws IndexOutOfBoundsException if the height is not valid
+   * @see Blockchain#getBlock(long)
+   */
+  default Block getBlock(long height) {
+    return withSnapshot(s -> Blockchain.newInstance(s).getBlock(height));
+  }
+
+  /**
+   * Returns the block at the given height together with the precommit messages
+   * of the validators that authenticate it.
+   *
+   * <p>Note that the node keeps only the latest database state. Therefore, the proofs
+   * of the index contents created from a {@linkplain #withSnapshot(Function) snapshot}
+   * are anchored to the block at the current {@linkplain #getBlockchainHeight() height}.
+   * The block proofs of the earlier blocks allow to verify the blocks themselves:
+   * for example, their state hashes and transactions.
+   *
+   * <p>The default implementation throws {@link UnsupportedOperationException}.
+   *
+   * @param height the height of the block
+   * @return the block proof, or {@code Optional.empty()} if there is no block at the given
+   *     height
+   * @throws IllegalArgumentException if the height is negative
+   * @see BlockProof#parseFrom(byte[])
+   */
+  default Optional<BlockProof> getBlockProof(long height) {
+    throw new UnsupportedOperationException("Block proofs are not supported by " + this);
+  }
+
+  /**
+   * Returns the hashes of the transactions committed in the block at the given height,
+   * in the order of their execution.
+   *
+   * @param height the height of the block; must be non-negative and less than or equal to
+   *     the current {@linkplain #getBlockchainHeight() blockchain height}
+   * @throws IllegalArgumentException if the height is not valid
+   * @see Blockchain#getBlockTransactions(long)
+   */
+  default List<HashCode> getBlockTransactions(long height) {
+    return withSnapshot(s ->
+        ImmutableList.copyOf(Blockchain.newInstance(s).getBlockTransactions(height)));
+  }
+
+  /**
+   * Returns the position inside the blockchain of the transaction with the given message hash.
+   *
+   * @return the transaction location, or {@code Optional.empty()} if this transaction
+   *     is unknown or was not yet committed
+   * @see Blockchain#getTxLocation(HashCode)
+   */
+  default Optional<TransactionLocation> getTxLocation(HashCode messageHash) {
+    return withSnapshot(s -> Blockchain.newInstance(s).getTxLocation(messageHash));
+  }
+
+  /**
+   * Returns the execution result of the transaction with the given message hash.
+   *
+   * @return the transaction execution result, or {@code Optional.empty()} if this transaction
+   *     is unknown or was not yet committed
+   * @see Blockchain#getTxResult(HashCode)
+   */
+  default Optional<ExecutionStatus> getTxResult(HashCode messageHash) {
+    return withSnapshot(s -> Blockchain.newInstance(s).getTxResult(messageHash));
+  }
+
+  /**
+   * Returns the current consensus configuration of the network.
+   *
+   * @throws RuntimeException if the "genesis block" was not created
+   * @see Blockchain#getConsensusConfiguration()
+   */
+  default Config getConsensusConfiguration() {
+    return withSnapshot(s -> Blockchain.newInstance(s).getConsensusConfiguration());
+  }
+
+  /**
+   * Returns the keys of the validators in the current consensus configuration,
+   * indexed by the validator id.
+   *
+   * @throws RuntimeException if the "genesis block" was not created
+   * @see #getConsensusConfiguration()
+   */
+  default List<ValidatorKeys> getValidatorKeys() {
+    return getConsensusConfiguration().getValidatorKeysList();
+  }
+
+  /**
+   * Returns the id of this node in the current set of validators; or {@link OptionalInt#empty()}
+   * if this node is not a validator, but an auditor. The id of the node is the index of its
+   * {@linkplain #getValidatorKeys() keys}, which include its {@linkplain #getPublicKey()
+   * service key}.
+   *
+   * @throws RuntimeException if the "genesis block" was not created
+   */
+  default OptionalInt getValidatorId() {
+    ByteString serviceKey = ByteString.copyFrom(getPublicKey().toBytes());
+    List<ValidatorKeys> validatorKeys = getValidatorKeys();
+    for (int i = 0; i < validatorKeys.size(); i++) {
+      if (validatorKeys.get(i).getServiceKey().getData().equals(serviceKey)) {
+        return OptionalInt.of(i);
+      }
+    }
+    return OptionalInt.empty();
+  }
+
+  /**
+   * Returns true if this node is a validator in the current consensus configuration;
+   * false if it is an auditor.
+   *
+   * @throws RuntimeException if the "genesis block" was not created
+   * @see #getValidatorId()
+   */
+  default boolean isValidator() {
+    return getValidatorId().isPresent();
+  }
+
+  /**
+   * Returns the number of transactions in the pool of unconfirmed transactions of this node.
+   * Note that the pool is volatile even between the block commits.
+   *
+   * @see Blockchain#getTransactionPoolSize()
+   */
+  default long getTransactionPoolSize() {
+    return withSnapshot(s -> Blockchain.newInstance(s).getTransactionPoolSize());
+  }
+
+  /**
+   * Returns true if the transaction with the given message hash is in the pool of unconfirmed
+   * transactions of this node. Note that the pool is volatile even between the block commits.
+   *
+   * @param messageHash the hash of the transaction message
+   * @see Blockchain#getTransactionPool()
+   */
+  default boolean isInTransactionPool(HashCode messageHash) {
+    return withSnapshot(s -> Blockchain.newInstance(s).getTransactionPool().contains(messageHash));
+  }
+
+  /**
+   * Returns the transaction with the given message hash from the pool of unconfirmed
+   * transactions of this node.
+   *
+   * @param messageHash the hash of the transaction message
+   * @return the transaction message, or {@code Optional.empty()} if there is no such transaction
+   *     in the pool: for example, it is unknown or already committed
+   */
+  default Optional<TransactionMessage> getPendingTransaction(HashCode messageHash) {
+    return withSnapshot(s -> {
+      Blockchain blockchain = Blockchain.newInstance(s);
+      if (!blockchain.getTransactionPool().contains(messageHash)) {
+        return Optional.empty();
+      }
+      return Optional.of(blockchain.getTxMessages().get(messageHash));
+    });
+  }
+
+  /**
+   * Returns the status of the transaction with the given message hash: whether it is unknown,
+   * in the pool of unconfirmed transactions, or committed.
+   *
+   * @param messageHash the hash of the transaction message, as returned by
+   *     {@link #submitTransaction(RawTransaction)}
+   */
+  default TransactionStatus getTransactionStatus(HashCode messageHash) {
+    return withSnapshot(s -> {
+      Blockchain blockchain = Blockchain.newInstance(s);
+      Optional<TransactionLocation> location = blockchain.getTxLocation(messageHash);
+      if (location.isPresent()) {
+        ExecutionStatus executionStatus = blockchain.getTxResult(messageHash)
+            .orElseThrow(() -> new IllegalStateException(
+                "No execution status of the committed transaction " + messageHash));
+        return TransactionStatus.committed(location.get(), executionStatus);
+      } else if (blockchain.getTransactionPool().contains(messageHash)) {
+        return TransactionStatus.inPool();
+      } else {
+        return TransactionStatus.unknown();
+      }
+    });
+  }
+
   /**
    * Returns the service public key of this node. The corresponding private key is used
    * for signing transactions in {@link #submitTransaction(RawTransaction)}.
@@ -68,4 +331,67 @@ public interface Node {
    * <p>This key is stored under "service_public_key" key in the node configuration file.
    */
   PublicKey getPublicKey();
+
+  /**
+   * Signs the given message with the service private key of this node, producing
+   * an Ed25519 signature. The signature can be verified with the
+   * {@linkplain #getPublicKey() node service public key}.
+   *
+   * <p>Use it to produce attestations of some data off-chain; for submitting
+   * transactions, use {@link #submitTransaction(RawTransaction)}.
+   *
+   * <p>The default implementation throws {@link UnsupportedOperationException}.
+   *
+   * @param message the message to sign
+   * @return the signature of the message
+   */
+  default byte[] sign(byte[] message) {
+    throw new UnsupportedOperationException("Signing is not supported by " + this);
+  }
+
+  /**
+   * Verifies that the given signature of the message is made with the service private key
+   * of this node.
+   *
+   * <p>The default implementation verifies it with the
+   * {@linkplain #getPublicKey() node service public key}.
+   *
+   * @param message the signed message
+   * @param signature the signature of the message
+   * @return true if the signature is valid; false otherwise
+   * @see #sign(byte[])
+   */
+  default boolean verify(byte[] message, byte[] signature) {
+    return CryptoFunctions.ed25519().verify(message, signature, getPublicKey());
+  }
+
+  /**
+   * Registers the listener of the block commit events. The listener is notified of each block
+   * committed after its registration, on a dedicated thread. If the listeners are too slow
+   * to keep up with the blocks, the oldest pending notifications are dropped.
+   *
+   * <p>The default implementation throws {@link UnsupportedOperationException}.
+   *
+   * @param listener the listener to register
+   * @throws IllegalArgumentException if the listener is already registered
+   * @see #removeBlockCommitListener(BlockCommitListener)
+   */
+  default void addBlockCommitListener(BlockCommitListener listener) {
+    throw new UnsupportedOperationException(
+        "Block commit listeners are not supported by " + this);
+  }
+
+  /**
+   * Removes the listener of the block commit events. The listener might still be notified
+   * of the blocks committed before its removal.
+   *
+   * <p>The default implementation throws {@link UnsupportedOperationException}.
+   *
+   * @param listener the listener to remove
+   * @return true if the listener was registered; false otherwise
+   */
+  default boolean removeBlockCommitListener(BlockCommitListener listener) {
+    throw new UnsupportedOperationException(
+        "Block commit listeners are not supported by " + this);
+  }
 }
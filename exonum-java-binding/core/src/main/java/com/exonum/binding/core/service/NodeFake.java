@@ -19,15 +19,21 @@ package com.exonum.binding.core.service;
 import static com.exonum.binding.common.crypto.CryptoFunctions.Ed25519.PUBLIC_KEY_BYTES;
 import static com.exonum.binding.common.hash.Hashing.DEFAULT_HASH_SIZE_BYTES;
 import static com.google.common.base.Preconditions.checkNotNull;
+import static com.google.common.base.Preconditions.checkState;
 
+import com.exonum.binding.common.crypto.CryptoFunctions;
+import com.exonum.binding.common.crypto.KeyPair;
+import com.exonum.binding.common.crypto.PrivateKey;
 import com.exonum.binding.common.crypto.PublicKey;
 import com.exonum.binding.common.hash.HashCode;
+import com.exonum.binding.common.message.TransactionMessage;
 import com.exonum.binding.core.proxy.Cleaner;
 import com.exonum.binding.core.proxy.CloseFailuresException;
 import com.exonum.binding.core.storage.database.Snapshot;
 import com.exonum.binding.core.storage.database.TemporaryDb;
 import com.exonum.binding.core.transaction.RawTransaction;
 import java.util.function.Function;
+import javax.annotation.Nullable;
 import org.apache.logging.log4j.LogManager;
 import org.apache.logging.log4j.Logger;
 
@@ -63,6 +69,9 @@ public final class NodeFake implements Node {
 
   private final PublicKey publicKey;
 
+  @Nullable
+  private final PrivateKey privateKey;
+
   /**
    * Creates a new node fake with the given database and an empty public key.
    *
@@ -79,8 +88,24 @@ public final class NodeFake implements Node {
    * @param publicKey a public key of the node
    */
   public NodeFake(TemporaryDb database, PublicKey publicKey) {
+    this(database, publicKey, null);
+  }
+
+  /**
+   * Creates a new node fake with the given database and the service key pair,
+   * which is used to {@linkplain #sign(byte[]) sign} messages.
+   *
+   * @param database a database to provide snapshots of
+   * @param keyPair a service key pair of the node
+   */
+  public NodeFake(TemporaryDb database, KeyPair keyPair) {
+    this(database, keyPair.getPublicKey(), keyPair.getPrivateKey());
+  }
+
+  private NodeFake(TemporaryDb database, PublicKey publicKey, @Nullable PrivateKey privateKey) {
     this.database = checkNotNull(database);
     this.publicKey = publicKey;
+    this.privateKey = privateKey;
   }
 
   /**
@@ -95,6 +120,23 @@ public final class NodeFake implements Node {
     return HashCode.fromBytes(new byte[DEFAULT_HASH_SIZE_BYTES]);
   }
 
+  /**
+   * Returns the hash of the given message, ignoring it. The signature of the message
+   * is <em>not</em> verified.
+   *
+   * @param signedMessage a signed transaction message to send
+   * @throws InvalidTransactionMessageException if the message cannot be parsed
+   */
+  @Override
+  public HashCode submitSignedTransaction(byte[] signedMessage) {
+    try {
+      return TransactionMessage.fromBytes(signedMessage).hash();
+    } catch (IllegalArgumentException e) {
+      throw new InvalidTransactionMessageException("Invalid transaction message: "
+          + e.getMessage());
+    }
+  }
+
   @Override
   public <ResultT> ResultT withSnapshot(Function<Snapshot, ResultT> snapshotFunction) {
     try (Cleaner cleaner = new Cleaner("NodeFake#withSnapshot")) {
@@ -111,6 +153,18 @@ public final class NodeFake implements Node {
     return publicKey;
   }
 
+  /**
+   * Signs the message with the private key of the node fake.
+   *
+   * @throws IllegalStateException if this node fake was created without a key pair
+   */
+  @Override
+  public byte[] sign(byte[] message) {
+    checkState(privateKey != null, "No private key: create the node fake with a key pair "
+        + "to sign messages");
+    return CryptoFunctions.ed25519().signMessage(message, privateKey);
+  }
+
   /**
    * Returns the underlying database.
    */
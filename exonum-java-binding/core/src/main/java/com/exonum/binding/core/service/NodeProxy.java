@@ -16,14 +16,36 @@
 
 package com.exonum.binding.core.service;
 
+import static com.exonum.binding.common.serialization.StandardSerializers.protobuf;
+import static com.google.common.base.Preconditions.checkArgument;
+import static com.google.common.base.Preconditions.checkNotNull;
+
+import com.exonum.binding.common.blockchain.TransactionLocation;
 import com.exonum.binding.common.crypto.PublicKey;
 import com.exonum.binding.common.hash.HashCode;
+import com.exonum.binding.common.message.TransactionMessage;
+import com.exonum.binding.common.serialization.Serializer;
+import com.exonum.binding.core.blockchain.Block;
+import com.exonum.binding.core.blockchain.BlockProof;
+import com.exonum.binding.core.blockchain.serialization.BlockSerializer;
+import com.exonum.binding.core.blockchain.serialization.TransactionLocationSerializer;
 import com.exonum.binding.core.proxy.AbstractCloseableNativeProxy;
 import com.exonum.binding.core.proxy.Cleaner;
 import com.exonum.binding.core.proxy.CloseFailuresException;
+import com.exonum.binding.core.service.NodeProtos.CommittedTransaction;
+import com.exonum.binding.core.service.TransactionSubmissionException.ErrorCode;
 import com.exonum.binding.core.storage.database.Snapshot;
 import com.exonum.binding.core.transaction.RawTransaction;
 import com.exonum.binding.core.util.LibraryLoader;
+import com.exonum.core.messages.Blockchain.Config;
+import com.exonum.core.messages.Runtime.ExecutionStatus;
+import com.google.common.collect.ImmutableList;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.Map;
+import java.util.Optional;
+import java.util.OptionalInt;
+import java.util.concurrent.ConcurrentHashMap;
 import java.util.function.Function;
 import org.apache.logging.log4j.LogManager;
 import org.apache.logging.log4j.Logger;
@@ -40,6 +62,15 @@ public final class NodeProxy extends AbstractCloseableNativeProxy implements Nod
 
   private static final Logger logger = LogManager.getLogger(NodeProxy.class);
 
+  private static final Serializer<ExecutionStatus> EXECUTION_STATUS_SERIALIZER =
+      protobuf(ExecutionStatus.class);
+  private static final Serializer<Config> CONSENSUS_CONFIG_SERIALIZER = protobuf(Config.class);
+  private static final Serializer<NodeProtos.TransactionStatus> TRANSACTION_STATUS_SERIALIZER =
+      protobuf(NodeProtos.TransactionStatus.class);
+
+  private final Map<BlockCommitListener, Long> blockCommitListenerIds =
+      new ConcurrentHashMap<>();
+
   /**
    * Creates a proxy of a node. Native code owns the node,
    * and, therefore, shall destroy the object.
@@ -77,6 +108,297 @@ public final class NodeProxy extends AbstractCloseableNativeProxy implements Nod
   private static native byte[] nativeSubmit(long nodeHandle, byte[] payload, int serviceId,
       int transactionId);
 
+  /**
+   * {@inheritDoc}
+   *
+   * <p>The message is verified by the native code.
+   *
+   * @throws IllegalStateException if the node proxy is closed
+   */
+  @Override
+  public HashCode submitSignedTransaction(byte[] signedMessage) {
+    checkNotNull(signedMessage);
+    byte[] txMessageHash = nativeSubmitSigned(getNativeHandle(), signedMessage);
+    return HashCode.fromBytes(txMessageHash);
+  }
+
+  /**
+   * Submits a signed transaction message into the network.
+   *
+   * @param nodeHandle a native handle to the native node object
+   * @param signedMessage a serialized signed transaction message
+   */
+  private static native byte[] nativeSubmitSigned(long nodeHandle, byte[] signedMessage);
+
+  /**
+   * {@inheritDoc}
+   *
+   * <p>This implementation signs and submits all the transactions in a single native call.
+   *
+   * @throws IllegalStateException if the node proxy is closed
+   */
+  @Override
+  public List<TransactionSubmissionResult> submitTransactions(
+      List<RawTransaction> rawTransactions) {
+    int size = rawTransactions.size();
+    int[] serviceIds = new int[size];
+    int[] transactionIds = new int[size];
+    byte[][] payloads = new byte[size][];
+    for (int i = 0; i < size; i++) {
+      RawTransaction transaction = rawTransactions.get(i);
+      serviceIds[i] = transaction.getServiceId();
+      transactionIds[i] = transaction.getTransactionId();
+      payloads[i] = transaction.getPayload();
+    }
+
+    String[] errors = new String[size];
+    int[] errorCodes = new int[size];
+    byte[][] txMessageHashes = nativeSubmitBatch(getNativeHandle(), serviceIds, transactionIds,
+        payloads, errors, errorCodes);
+
+    List<TransactionSubmissionResult> results = new ArrayList<>(size);
+    for (int i = 0; i < size; i++) {
+      TransactionSubmissionResult result = (txMessageHashes[i] != null)
+          ? TransactionSubmissionResult.success(HashCode.fromBytes(txMessageHashes[i]))
+          : TransactionSubmissionResult.failure(ErrorCode.fromCode(errorCodes[i]), errors[i]);
+      results.add(result);
+    }
+    return results;
+  }
+
+  /**
+   * Submits the transactions into the network.
+   *
+   * @param nodeHandle a native handle to the native node object
+   * @param serviceIds the identifiers of the services of each transaction
+   * @param transactionIds the identifiers of each transaction
+   * @param payloads the serialized payloads of each transaction
+   * @param errors an array to which the descriptions of the errors are written,
+   *     at the positions of the transactions that could not be submitted
+   * @param errorCodes an array to which the {@linkplain ErrorCode#getCode() codes} of the errors
+   *     are written, at the positions of the transactions that could not be submitted
+   * @return the hashes of the transaction messages; or nulls at the positions
+   *     of the transactions that could not be submitted
+   * @throws IllegalArgumentException if the arrays have different lengths
+   */
+  private static native byte[][] nativeSubmitBatch(long nodeHandle, int[] serviceIds,
+      int[] transactionIds, byte[][] payloads, String[] errors, int[] errorCodes);
+
+  /**
+   * {@inheritDoc}
+   *
+   * @throws IllegalStateException if the node proxy is closed
+   */
+  @Override
+  public long getBlockchainHeight() {
+    return nativeGetHeight(getNativeHandle());
+  }
+
+  private static native long nativeGetHeight(long nodeHandle);
+
+  /**
+   * {@inheritDoc}
+   *
+   * @throws IllegalStateException if the node proxy is closed
+   */
+  @Override
+  public Block getBlock(long height) {
+    byte[] block = (height >= 0) ? nativeGetBlock(getNativeHandle(), height) : null;
+    if (block == null) {
+      throw new IndexOutOfBoundsException("Block height (" + height + ") is out of range [0, "
+          + getBlockchainHeight() + "]");
+    }
+    return BlockSerializer.INSTANCE.fromBytes(block);
+  }
+
+  /**
+   * Returns the block at the given height as a serialized protobuf message;
+   * or null if there is no such block.
+   */
+  private static native byte[] nativeGetBlock(long nodeHandle, long height);
+
+  /**
+   * {@inheritDoc}
+   *
+   * @throws IllegalStateException if the node proxy is closed
+   */
+  @Override
+  public Optional<BlockProof> getBlockProof(long height) {
+    checkArgument(height >= 0, "Height shouldn't be negative, but was %s", height);
+    byte[] blockProof = nativeGetBlockProof(getNativeHandle(), height);
+    return Optional.ofNullable(blockProof)
+        .map(BlockProof::parseFrom);
+  }
+
+  /**
+   * Returns the block proof at the given height as a serialized {@code exonum.BlockProof}
+   * protobuf message; or null if there is no such block.
+   */
+  private static native byte[] nativeGetBlockProof(long nodeHandle, long height);
+
+  /**
+   * {@inheritDoc}
+   *
+   * @throws IllegalStateException if the node proxy is closed
+   */
+  @Override
+  public List<HashCode> getBlockTransactions(long height) {
+    checkArgument(height >= 0, "Height shouldn't be negative, but was %s", height);
+    byte[][] txHashes = nativeGetBlockTransactions(getNativeHandle(), height);
+    checkArgument(txHashes != null, "Height should be less or equal compared to "
+        + "blockchain height, but was %s", height);
+    ImmutableList.Builder<HashCode> result = ImmutableList.builderWithExpectedSize(
+        txHashes.length);
+    for (byte[] txHash : txHashes) {
+      result.add(HashCode.fromBytes(txHash));
+    }
+    return result.build();
+  }
+
+  /**
+   * Returns the hashes of the transactions in the block at the given height;
+   * or null if there is no such block.
+   */
+  private static native byte[][] nativeGetBlockTransactions(long nodeHandle, long height);
+
+  /**
+   * {@inheritDoc}
+   *
+   * @throws IllegalStateException if the node proxy is closed
+   */
+  @Override
+  public Optional<TransactionLocation> getTxLocation(HashCode messageHash) {
+    byte[] location = nativeGetTxLocation(getNativeHandle(), messageHash.asBytes());
+    return Optional.ofNullable(location)
+        .map(TransactionLocationSerializer.INSTANCE::fromBytes);
+  }
+
+  /**
+   * Returns the location of the transaction as a serialized protobuf message;
+   * or null if the transaction is not committed.
+   */
+  private static native byte[] nativeGetTxLocation(long nodeHandle, byte[] messageHash);
+
+  /**
+   * {@inheritDoc}
+   *
+   * @throws IllegalStateException if the node proxy is closed
+   */
+  @Override
+  public Optional<ExecutionStatus> getTxResult(HashCode messageHash) {
+    byte[] status = nativeGetTxResult(getNativeHandle(), messageHash.asBytes());
+    return Optional.ofNullable(status)
+        .map(EXECUTION_STATUS_SERIALIZER::fromBytes);
+  }
+
+  /**
+   * Returns the execution result of the transaction as a serialized protobuf message;
+   * or null if the transaction is not committed.
+   */
+  private static native byte[] nativeGetTxResult(long nodeHandle, byte[] messageHash);
+
+  /**
+   * {@inheritDoc}
+   *
+   * @throws IllegalStateException if the node proxy is closed
+   */
+  @Override
+  public Config getConsensusConfiguration() {
+    byte[] config = nativeGetConsensusConfiguration(getNativeHandle());
+    return CONSENSUS_CONFIG_SERIALIZER.fromBytes(config);
+  }
+
+  private static native byte[] nativeGetConsensusConfiguration(long nodeHandle);
+
+  /**
+   * {@inheritDoc}
+   *
+   * @throws IllegalStateException if the node proxy is closed
+   */
+  @Override
+  public OptionalInt getValidatorId() {
+    int validatorId = nativeGetValidatorId(getNativeHandle());
+    return (validatorId >= 0) ? OptionalInt.of(validatorId) : OptionalInt.empty();
+  }
+
+  /**
+   * Returns the id of this node if it is a validator; or -1 otherwise.
+   */
+  private static native int nativeGetValidatorId(long nodeHandle);
+
+  /**
+   * {@inheritDoc}
+   *
+   * @throws IllegalStateException if the node proxy is closed
+   */
+  @Override
+  public long getTransactionPoolSize() {
+    return nativeGetTransactionPoolSize(getNativeHandle());
+  }
+
+  private static native long nativeGetTransactionPoolSize(long nodeHandle);
+
+  /**
+   * {@inheritDoc}
+   *
+   * @throws IllegalStateException if the node proxy is closed
+   */
+  @Override
+  public boolean isInTransactionPool(HashCode messageHash) {
+    return nativeIsInTransactionPool(getNativeHandle(), messageHash.asBytes());
+  }
+
+  private static native boolean nativeIsInTransactionPool(long nodeHandle, byte[] messageHash);
+
+  /**
+   * {@inheritDoc}
+   *
+   * @throws IllegalStateException if the node proxy is closed
+   */
+  @Override
+  public Optional<TransactionMessage> getPendingTransaction(HashCode messageHash) {
+    byte[] transaction = nativeGetPendingTransaction(getNativeHandle(), messageHash.asBytes());
+    return Optional.ofNullable(transaction)
+        .map(TransactionMessage::fromBytes);
+  }
+
+  /**
+   * Returns the transaction from the pool as a serialized signed message;
+   * or null if there is no such transaction in the pool.
+   */
+  private static native byte[] nativeGetPendingTransaction(long nodeHandle, byte[] messageHash);
+
+  /**
+   * {@inheritDoc}
+   *
+   * @throws IllegalStateException if the node proxy is closed
+   */
+  @Override
+  public TransactionStatus getTransactionStatus(HashCode messageHash) {
+    byte[] status = nativeGetTransactionStatus(getNativeHandle(), messageHash.asBytes());
+    NodeProtos.TransactionStatus statusProto = TRANSACTION_STATUS_SERIALIZER.fromBytes(status);
+    switch (statusProto.getStatusCase()) {
+      case IN_POOL:
+        return TransactionStatus.inPool();
+      case COMMITTED:
+        CommittedTransaction committed = statusProto.getCommitted();
+        TransactionLocation location = TransactionLocation.valueOf(committed.getHeight(),
+            committed.getPositionInBlock());
+        ExecutionStatus executionStatus = EXECUTION_STATUS_SERIALIZER.fromBytes(
+            committed.getExecutionStatus().toByteArray());
+        return TransactionStatus.committed(location, executionStatus);
+      case STATUS_NOT_SET:
+        return TransactionStatus.unknown();
+      default:
+        throw new AssertionError("Unknown transaction status: " + statusProto);
+    }
+  }
+
+  /**
+   * Returns the status of the transaction as a serialized {@link NodeProtos.TransactionStatus}.
+   */
+  private static native byte[] nativeGetTransactionStatus(long nodeHandle, byte[] messageHash);
+
   /**
    * {@inheritDoc}
    *
@@ -110,6 +432,65 @@ public final class NodeProxy extends AbstractCloseableNativeProxy implements Nod
 
   private native byte[] nativeGetPublicKey(long nativeHandle);
 
+  /**
+   * {@inheritDoc}
+   *
+   * <p>The private key never leaves the native code.
+   *
+   * @throws IllegalStateException if the node proxy is closed
+   */
+  @Override
+  public byte[] sign(byte[] message) {
+    checkNotNull(message);
+    return nativeSign(getNativeHandle(), message);
+  }
+
+  private static native byte[] nativeSign(long nodeHandle, byte[] message);
+
+  /**
+   * {@inheritDoc}
+   *
+   * @throws IllegalStateException if the node proxy is closed
+   */
+  @Override
+  public boolean verify(byte[] message, byte[] signature) {
+    checkNotNull(message);
+    checkNotNull(signature);
+    return nativeVerify(getNativeHandle(), message, signature);
+  }
+
+  private static native boolean nativeVerify(long nodeHandle, byte[] message, byte[] signature);
+
+  /**
+   * {@inheritDoc}
+   *
+   * @throws IllegalStateException if the node proxy is closed
+   */
+  @Override
+  public void addBlockCommitListener(BlockCommitListener listener) {
+    NativeBlockCommitListener nativeListener = new NativeBlockCommitListener(listener);
+    blockCommitListenerIds.compute(listener, (l, id) -> {
+      checkArgument(id == null, "The listener %s is already registered", l);
+      return nativeAddBlockCommitListener(getNativeHandle(), nativeListener);
+    });
+  }
+
+  private static native long nativeAddBlockCommitListener(long nodeHandle,
+      NativeBlockCommitListener listener);
+
+  /**
+   * {@inheritDoc}
+   *
+   * @throws IllegalStateException if the node proxy is closed
+   */
+  @Override
+  public boolean removeBlockCommitListener(BlockCommitListener listener) {
+    Long id = blockCommitListenerIds.remove(listener);
+    return id != null && nativeRemoveBlockCommitListener(getNativeHandle(), id);
+  }
+
+  private static native boolean nativeRemoveBlockCommitListener(long nodeHandle, long listenerId);
+
   @Override
   protected void disposeInternal() {
     nativeFree(getNativeHandle());
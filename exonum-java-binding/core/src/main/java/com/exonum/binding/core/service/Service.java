@@ -19,6 +19,7 @@ package com.exonum.binding.core.service;
 import com.exonum.binding.common.hash.HashCode;
 import com.exonum.binding.core.storage.database.Fork;
 import com.exonum.binding.core.storage.database.Snapshot;
+import com.exonum.binding.core.storage.indices.ProofEntryIndexProxy;
 import com.exonum.binding.core.storage.indices.ProofListIndexProxy;
 import com.exonum.binding.core.storage.indices.ProofMapIndexProxy;
 import io.vertx.ext.web.Router;
@@ -72,6 +73,7 @@ public interface Service {
    * @param snapshot a snapshot of the blockchain state. Not valid after this method returns
    * @see ProofListIndexProxy#getIndexHash()
    * @see ProofMapIndexProxy#getIndexHash()
+   * @see ProofEntryIndexProxy#getIndexHash()
    */
   List<HashCode> getStateHashes(Snapshot snapshot);
 
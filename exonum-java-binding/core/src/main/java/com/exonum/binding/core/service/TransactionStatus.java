@@ -0,0 +1,101 @@
+/*
+ * Copyright 2019 The Exonum Team
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+package com.exonum.binding.core.service;
+
+import static com.google.common.base.Preconditions.checkNotNull;
+
+import com.exonum.binding.common.blockchain.TransactionLocation;
+import com.exonum.binding.common.hash.HashCode;
+import com.exonum.core.messages.Runtime.ExecutionError;
+import com.exonum.core.messages.Runtime.ExecutionStatus;
+import com.google.auto.value.AutoValue;
+import java.util.Optional;
+
+/**
+ * The status of a transaction, as known to the node.
+ *
+ * @see Node#getTransactionStatus(HashCode)
+ */
+@AutoValue
+public abstract class TransactionStatus {
+
+  /**
+   * A type of the transaction status.
+   */
+  public enum Type {
+    /** The transaction is unknown to the node. */
+    UNKNOWN,
+    /** The transaction is in the pool of unconfirmed transactions. */
+    IN_POOL,
+    /** The transaction is committed, either successfully or with an error. */
+    COMMITTED
+  }
+
+  /**
+   * Returns the type of the status.
+   */
+  public abstract Type getType();
+
+  /**
+   * Returns the location of the committed transaction in the blockchain;
+   * or {@link Optional#empty()} if the transaction is not committed.
+   */
+  public abstract Optional<TransactionLocation> getLocation();
+
+  /**
+   * Returns the error occurred during the execution of the committed transaction;
+   * or {@link Optional#empty()} if the transaction is not committed or was executed
+   * successfully.
+   */
+  public abstract Optional<ExecutionError> getExecutionError();
+
+  /**
+   * Returns true if the transaction is committed and was executed successfully.
+   */
+  public final boolean isCommittedSuccessfully() {
+    return getType() == Type.COMMITTED && !getExecutionError().isPresent();
+  }
+
+  /**
+   * Creates a status of a transaction unknown to the node.
+   */
+  public static TransactionStatus unknown() {
+    return new AutoValue_TransactionStatus(Type.UNKNOWN, Optional.empty(), Optional.empty());
+  }
+
+  /**
+   * Creates a status of a transaction in the pool of unconfirmed transactions.
+   */
+  public static TransactionStatus inPool() {
+    return new AutoValue_TransactionStatus(Type.IN_POOL, Optional.empty(), Optional.empty());
+  }
+
+  /**
+   * Creates a status of a committed transaction.
+   *
+   * @param location the location of the transaction in the blockchain
+   * @param executionStatus the result of the transaction execution
+   */
+  public static TransactionStatus committed(TransactionLocation location,
+      ExecutionStatus executionStatus) {
+    Optional<ExecutionError> error = executionStatus.hasError()
+        ? Optional.of(executionStatus.getError())
+        : Optional.empty();
+    return new AutoValue_TransactionStatus(Type.COMMITTED, Optional.of(checkNotNull(location)),
+        error);
+  }
+}
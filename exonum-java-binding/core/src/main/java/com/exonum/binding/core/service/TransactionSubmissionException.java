@@ -22,10 +22,82 @@ import com.exonum.binding.core.transaction.RawTransaction;
  * Indicates that a transaction could not be
  * {@linkplain Node#submitTransaction(RawTransaction) submitted}.
  * For example, the submitted transaction is not valid — belongs to an unknown service.
+ * The reason of the failure is available as an {@linkplain #getErrorCode() error code},
+ * allowing the caller to decide whether the submission may be retried.
  */
 public final class TransactionSubmissionException extends RuntimeException {
 
+  /**
+   * The reason of the submission failure.
+   */
+  public enum ErrorCode {
+    /** The reason of the failure is not specified. */
+    UNSPECIFIED(0),
+    /** The transaction belongs to an unknown service instance. Retrying will not help. */
+    UNKNOWN_SERVICE(1),
+    /**
+     * The service instance of the transaction is not active. The transaction may be
+     * submitted again once the service is active.
+     */
+    INACTIVE_SERVICE(2),
+    /** The transaction arguments exceed the maximum message size. Retrying will not help. */
+    ARGUMENTS_TOO_LARGE(3),
+    /** The transaction could not be broadcast to the network. It may be retried. */
+    BROADCAST_FAILED(4);
+
+    private final int code;
+
+    ErrorCode(int code) {
+      this.code = code;
+    }
+
+    /**
+     * Returns the numeric code of this error, as used by the native code.
+     */
+    public int getCode() {
+      return code;
+    }
+
+    /**
+     * Returns the error with the given numeric code.
+     *
+     * @throws IllegalArgumentException if there is no error with such code
+     */
+    public static ErrorCode fromCode(int code) {
+      for (ErrorCode errorCode : values()) {
+        if (errorCode.code == code) {
+          return errorCode;
+        }
+      }
+      throw new IllegalArgumentException("Unknown error code: " + code);
+    }
+  }
+
+  private final ErrorCode errorCode;
+
+  /**
+   * Creates an exception with an {@linkplain ErrorCode#UNSPECIFIED unspecified} error code.
+   */
   public TransactionSubmissionException(String message) {
+    this(ErrorCode.UNSPECIFIED, message);
+  }
+
+  public TransactionSubmissionException(ErrorCode errorCode, String message) {
     super(message);
+    this.errorCode = errorCode;
+  }
+
+  /**
+   * Creates an exception with the given numeric error code. Used by the native code.
+   */
+  TransactionSubmissionException(int errorCode, String message) {
+    this(ErrorCode.fromCode(errorCode), message);
+  }
+
+  /**
+   * Returns the reason of the submission failure.
+   */
+  public ErrorCode getErrorCode() {
+    return errorCode;
   }
 }
@@ -0,0 +1,86 @@
+/*
+ * Copyright 2019 The Exonum Team
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+package com.exonum.binding.core.service;
+
+import static com.google.common.base.Preconditions.checkNotNull;
+
+import com.exonum.binding.common.hash.HashCode;
+import com.exonum.binding.core.service.TransactionSubmissionException.ErrorCode;
+import com.exonum.binding.core.transaction.RawTransaction;
+import com.google.auto.value.AutoValue;
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * A result of the submission of a single transaction in a
+ * {@linkplain Node#submitTransactions(List) batch}: either the hash of the transaction message,
+ * or the code and the description of the error that prevented the submission.
+ */
+@AutoValue
+public abstract class TransactionSubmissionResult {
+
+  /**
+   * Returns the hash of the submitted transaction message; or {@link Optional#empty()}
+   * if the transaction could not be submitted.
+   */
+  public abstract Optional<HashCode> getTransactionHash();
+
+  /**
+   * Returns the reason of the submission failure; or {@link Optional#empty()}
+   * if the transaction was submitted successfully.
+   *
+   * @see TransactionSubmissionException#getErrorCode()
+   */
+  public abstract Optional<ErrorCode> getErrorCode();
+
+  /**
+   * Returns the description of the error that prevented the submission of the transaction;
+   * or {@link Optional#empty()} if it was submitted successfully.
+   */
+  public abstract Optional<String> getErrorDescription();
+
+  /**
+   * Returns true if the transaction was submitted successfully.
+   */
+  public final boolean isSuccessful() {
+    return getTransactionHash().isPresent();
+  }
+
+  /**
+   * Creates a result of a successful submission.
+   *
+   * @param transactionHash the hash of the submitted transaction message
+   */
+  public static TransactionSubmissionResult success(HashCode transactionHash) {
+    return new AutoValue_TransactionSubmissionResult(Optional.of(transactionHash),
+        Optional.empty(), Optional.empty());
+  }
+
+  /**
+   * Creates a result of a failed submission.
+   *
+   * @param errorCode the reason of the failure
+   * @param errorDescription the description of the error
+   * @see TransactionSubmissionException
+   * @see Node#submitTransaction(RawTransaction)
+   */
+  public static TransactionSubmissionResult failure(ErrorCode errorCode,
+      String errorDescription) {
+    return new AutoValue_TransactionSubmissionResult(Optional.empty(),
+        Optional.of(checkNotNull(errorCode)), Optional.of(checkNotNull(errorDescription)));
+  }
+}
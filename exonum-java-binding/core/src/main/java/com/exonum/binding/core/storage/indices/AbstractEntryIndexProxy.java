@@ -0,0 +1,124 @@
+/*
+ * Copyright 2019 The Exonum Team
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+package com.exonum.binding.core.storage.indices;
+
+import com.exonum.binding.common.serialization.CheckingSerializerDecorator;
+import com.exonum.binding.core.proxy.NativeHandle;
+import com.exonum.binding.core.storage.database.View;
+import java.util.NoSuchElementException;
+import java.util.Optional;
+
+/**
+ * An abstract class for entries, i.e., indices containing no or a single value.
+ *
+ * <p>Implements all operations on the entry value.
+ *
+ * @param <T> the type of an element in this entry
+ */
+abstract class AbstractEntryIndexProxy<T> extends AbstractIndexProxy {
+
+  final CheckingSerializerDecorator<T> serializer;
+
+  AbstractEntryIndexProxy(NativeHandle nativeHandle, IndexAddress address, View view,
+      CheckingSerializerDecorator<T> serializer) {
+    super(nativeHandle, address, view);
+    this.serializer = serializer;
+  }
+
+  /**
+   * Sets a new value of the entry, overwriting the previous value.
+   *
+   * @param value a value to set. Must not be null.
+   * @throws UnsupportedOperationException if the entry is read-only
+   * @throws IllegalStateException if the proxy is invalid
+   */
+  public void set(T value) {
+    notifyModified();
+    byte[] valueBytes = serializer.toBytes(value);
+    nativeSet(getNativeHandle(), valueBytes);
+  }
+
+  /**
+   * Returns true if this entry exists in the database.
+   *
+   * @throws IllegalStateException if the proxy is invalid.
+   */
+  public boolean isPresent() {
+    return nativeIsPresent(getNativeHandle());
+  }
+
+  /**
+   * If value is present in the entry, returns it, otherwise,
+   * throws {@link NoSuchElementException}.
+   *
+   * @return a non-null value
+   * @throws NoSuchElementException if a value is not present in the Entry
+   * @throws IllegalStateException if the proxy is invalid
+   * @throws IllegalArgumentException if the supplied serializer cannot decode the value
+   */
+  public T get() {
+    byte[] value = nativeGet(getNativeHandle());
+    if (value == null) {
+      throw new NoSuchElementException("No value in this entry");
+    }
+    return serializer.fromBytes(value);
+  }
+
+  /**
+   * Removes a value from this entry.
+   *
+   * @throws UnsupportedOperationException if the entry is read-only.
+   * @throws IllegalStateException if the proxy is invalid
+   */
+  public void remove() {
+    notifyModified();
+    nativeRemove(getNativeHandle());
+  }
+
+  /**
+   * Converts the entry to {@link java.util.Optional}.
+   *
+   * <p>Be aware that this method represents a state of the entry at the time
+   * of calling. And the returned value won't reflect the entry changes:
+   * <pre>
+   *  {@code
+   *    entry.set("foo");
+   *    Optional<String> optionalEntry = entry.toOptional();
+   *    entry.remove();
+   *    optionalEntry.get(); // -> returns "foo"
+   *  }
+   * </pre>
+   *
+   * @return {@code Optional.of(value)} if value is present in the entry,
+   *        otherwise returns {@code Optional.empty()}
+   */
+  public Optional<T> toOptional() {
+    if (isPresent()) {
+      return Optional.of(get());
+    } else {
+      return Optional.empty();
+    }
+  }
+
+  abstract void nativeSet(long nativeHandle, byte[] value);
+
+  abstract boolean nativeIsPresent(long nativeHandle);
+
+  abstract byte[] nativeGet(long nativeHandle);
+
+  abstract void nativeRemove(long nativeHandle);
+}
@@ -127,9 +127,9 @@ abstract class AbstractListIndexProxy<T> extends AbstractIndexProxy implements L
 
   @Override
   public final Iterator<T> iterator() {
-    return StorageIterators.createIterator(
+    return StorageIterators.createBatchingIterator(
         nativeCreateIter(getNativeHandle()),
-        this::nativeIterNext,
+        this::nativeIterNextBatch,
         this::nativeIterFree,
         dbView,
         modCounter,
@@ -163,7 +163,7 @@ abstract class AbstractListIndexProxy<T> extends AbstractIndexProxy implements L
 
   abstract long nativeCreateIter(long nativeHandle);
 
-  abstract byte[] nativeIterNext(long iterNativeHandle);
+  abstract byte[][] nativeIterNextBatch(long iterNativeHandle, int maxCount, int maxBytes);
 
   abstract void nativeIterFree(long iterNativeHandle);
 }
@@ -0,0 +1,137 @@
+/*
+ * Copyright 2019 The Exonum Team
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+package com.exonum.binding.core.storage.indices;
+
+import static com.google.common.base.Preconditions.checkArgument;
+
+import com.exonum.binding.core.proxy.AbstractNativeProxy;
+import com.exonum.binding.core.proxy.NativeHandle;
+import java.util.ConcurrentModificationException;
+import java.util.Optional;
+
+/**
+ * A fail-fast iterator that fetches the elements from the native iterator in batches,
+ * to reduce the number of native calls.
+ *
+ * <p>Each batch is an array of byte arrays, in which each element occupies the same number
+ * of consecutive byte arrays (e.g., a key and a value of a map entry).
+ *
+ * @param <E> type of elements returned by the iterator.
+ */
+final class BatchingRustIter<E> extends AbstractNativeProxy implements RustIter<E> {
+
+  /**
+   * The maximum number of elements in a batch.
+   */
+  static final int MAX_BATCH_SIZE = 256;
+
+  /**
+   * The size of the elements in bytes, after reaching which no more elements are added
+   * to a batch.
+   */
+  static final int MAX_BATCH_BYTES = 512 * 1024;
+
+  private static final byte[][] EMPTY_BATCH = new byte[0][];
+
+  private final NextBatchFunction nextBatchFunction;
+  private final int elementLength;
+  private final ElementDecoder<E> elementDecoder;
+  private final ModificationCounter modificationCounter;
+  private final Integer initialModCount;
+  private byte[][] batch = EMPTY_BATCH;
+  private int position = 0;
+  private boolean exhausted = false;
+
+  /**
+   * Creates a new iterator over a collection (index).
+   *
+   * @param nativeHandle nativeHandle of this iterator
+   * @param nextBatchFunction a function to call to get the next batch of elements
+   * @param elementLength the number of byte arrays each element occupies in a batch
+   * @param elementDecoder a function creating an element from the byte arrays in a batch
+   * @param modificationCounter a collection modification counter
+   */
+  BatchingRustIter(NativeHandle nativeHandle,
+                   NextBatchFunction nextBatchFunction,
+                   int elementLength,
+                   ElementDecoder<E> elementDecoder,
+                   ModificationCounter modificationCounter) {
+    super(nativeHandle);
+    checkArgument(elementLength > 0, "elementLength (%s) must be positive", elementLength);
+    this.nextBatchFunction = nextBatchFunction;
+    this.elementLength = elementLength;
+    this.elementDecoder = elementDecoder;
+    this.modificationCounter = modificationCounter;
+    this.initialModCount = modificationCounter.getCurrentValue();
+  }
+
+  @Override
+  public Optional<E> next() {
+    checkNotModified();
+    if (position == batch.length && !exhausted) {
+      fetchNextBatch();
+    }
+    if (exhausted) {
+      return Optional.empty();
+    }
+    E element = elementDecoder.decode(batch, position);
+    position += elementLength;
+    return Optional.of(element);
+  }
+
+  private void fetchNextBatch() {
+    batch = nextBatchFunction.nextBatch(getNativeHandle(), MAX_BATCH_SIZE, MAX_BATCH_BYTES);
+    position = 0;
+    exhausted = (batch.length == 0);
+  }
+
+  private void checkNotModified() {
+    if (modificationCounter.isModifiedSince(initialModCount)) {
+      throw new ConcurrentModificationException("The source collection "
+          + "has been modified during iteration");
+    }
+  }
+
+  /**
+   * A native function returning the next batch of elements.
+   */
+  @FunctionalInterface
+  interface NextBatchFunction {
+
+    /**
+     * Returns the next elements of the native iterator, or an empty array if the iteration
+     * is finished.
+     *
+     * @param iterNativeHandle a native handle of the iterator
+     * @param maxCount the maximum number of elements to return
+     * @param maxBytes the size of elements after reaching which no more elements are returned
+     */
+    byte[][] nextBatch(long iterNativeHandle, int maxCount, int maxBytes);
+  }
+
+  /**
+   * A function creating an element from the byte arrays in a batch.
+   */
+  @FunctionalInterface
+  interface ElementDecoder<E> {
+
+    /**
+     * Creates an element from the byte arrays in the batch, starting at the given position.
+     */
+    E decode(byte[][] batch, int position);
+  }
+}
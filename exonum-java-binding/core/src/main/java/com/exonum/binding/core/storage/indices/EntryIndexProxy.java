@@ -27,8 +27,6 @@ import com.exonum.binding.core.storage.database.Snapshot;
 import com.exonum.binding.core.storage.database.View;
 import com.exonum.binding.core.util.LibraryLoader;
 import com.google.protobuf.MessageLite;
-import java.util.NoSuchElementException;
-import java.util.Optional;
 
 /**
  * An Entry is a database index that can contain no or a single value.
@@ -49,14 +47,12 @@ import java.util.Optional;
  *
  * @see View
  */
-public final class EntryIndexProxy<T> extends AbstractIndexProxy {
+public final class EntryIndexProxy<T> extends AbstractEntryIndexProxy<T> {
 
   static {
     LibraryLoader.load();
   }
 
-  private final CheckingSerializerDecorator<T> serializer;
-
   /**
    * Creates a new Entry storing protobuf messages.
    *
@@ -127,100 +123,28 @@ public final class EntryIndexProxy<T> extends AbstractIndexProxy {
 
   private EntryIndexProxy(NativeHandle nativeHandle, IndexAddress address, View view,
       CheckingSerializerDecorator<T> serializer) {
-    super(nativeHandle, address, view);
-    this.serializer = serializer;
-  }
-
-  /**
-   * Sets a new value of the entry, overwriting the previous value.
-   *
-   * @param value a value to set. Must not be null.
-   * @throws UnsupportedOperationException if the entry is read-only
-   * @throws IllegalStateException if the proxy is invalid
-   */
-  public void set(T value) {
-    notifyModified();
-    byte[] valueBytes = serializer.toBytes(value);
-    nativeSet(getNativeHandle(), valueBytes);
-  }
-
-  /**
-   * Returns true if this entry exists in the database.
-   *
-   * @throws IllegalStateException if the proxy is invalid.
-   */
-  public boolean isPresent() {
-    return nativeIsPresent(getNativeHandle());
-  }
-
-  /**
-   * If value is present in the entry, returns it, otherwise,
-   * throws {@link NoSuchElementException}.
-   *
-   * @return a non-null value
-   * @throws NoSuchElementException if a value is not present in the Entry
-   * @throws IllegalStateException if the proxy is invalid
-   * @throws IllegalArgumentException if the supplied serializer cannot decode the value
-   */
-  public T get() {
-    byte[] value = nativeGet(getNativeHandle());
-    if (value == null) {
-      throw new NoSuchElementException("No value in this entry");
-    }
-    return serializer.fromBytes(value);
+    super(nativeHandle, address, view, serializer);
   }
 
   // TODO(dt): add getHash when you clarify why on Earth it returns a default (= zero) hash when
   // value is not present.
 
-  /**
-   * Removes a value from this entry.
-   *
-   * @throws UnsupportedOperationException if the entry is read-only.
-   * @throws IllegalStateException if the proxy is invalid
-   */
-  public void remove() {
-    notifyModified();
-    nativeRemove(getNativeHandle());
-  }
-
-  /**
-   * Converts the entry to {@link java.util.Optional}.
-   *
-   * <p>Be aware that this method represents a state of the entry at the time
-   * of calling. And the returned value won't reflect the entry changes:
-   * <pre>
-   *  {@code
-   *    entry.set("foo");
-   *    Optional<String> optionalEntry = entry.toOptional();
-   *    entry.remove();
-   *    optionalEntry.get(); // -> returns "foo"
-   *  }
-   * </pre>
-   *
-   * @return {@code Optional.of(value)} if value is present in the entry,
-   *        otherwise returns {@code Optional.empty()}
-   */
-  public Optional<T> toOptional() {
-    if (isPresent()) {
-      return Optional.of(get());
-    } else {
-      return Optional.empty();
-    }
-  }
-
   private static native long nativeCreate(String name, long viewNativeHandle);
 
-  private native void nativeSet(long nativeHandle, byte[] value);
+  @Override
+  native void nativeSet(long nativeHandle, byte[] value);
 
-  private native boolean nativeIsPresent(long nativeHandle);
+  @Override
+  native boolean nativeIsPresent(long nativeHandle);
 
-  private native byte[] nativeGet(long nativeHandle);
+  @Override
+  native byte[] nativeGet(long nativeHandle);
 
   @SuppressWarnings("unused")
   private native byte[] nativeGetHash(long nativeHandle);
 
-  private native void nativeRemove(long nativeHandle);
+  @Override
+  native void nativeRemove(long nativeHandle);
 
   private static native void nativeFree(long nativeHandle);
 }
@@ -0,0 +1,59 @@
+/*
+ * Copyright 2019 The Exonum Team
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+package com.exonum.binding.core.storage.indices;
+
+import com.google.auto.value.AutoValue;
+import com.google.protobuf.ByteString;
+import java.util.Optional;
+
+/**
+ * A proof of the value of a {@link ProofEntryIndexProxy}. It consists of the serialized value
+ * of the entry and a proof of the {@linkplain ProofEntryIndexProxy#getIndexHash() index hash}
+ * of the entry in the state aggregator of the blockchain: a proof map from the names of
+ * the aggregated indexes to their index hashes.
+ *
+ * <p>The value restores the index hash of the entry; the map proof restores
+ * the {@linkplain com.exonum.binding.core.blockchain.Block#getStateHash() blockchain state hash}
+ * from the index hash. Therefore, coupled with the
+ * {@linkplain com.exonum.binding.core.blockchain.BlockProof block proof} of the latest block,
+ * the entry proof allows light clients to verify the value of the entry.
+ *
+ * @see ProofEntryIndexProxy#getProof()
+ * @see com.exonum.binding.core.service.Node#getBlockProof(long)
+ */
+@AutoValue
+public abstract class EntryProof {
+
+  /**
+   * Returns the proof of the index hash of the entry in the state aggregator.
+   * The key of the proven map entry is the name of the entry index.
+   */
+  public abstract MapProof getIndexProof();
+
+  /**
+   * Returns the serialized value of the entry, or {@code Optional.empty()} if the entry
+   * has no value.
+   */
+  public abstract Optional<ByteString> getValue();
+
+  /**
+   * Creates a new entry proof.
+   */
+  public static EntryProof newInstance(MapProof indexProof, Optional<ByteString> value) {
+    return new AutoValue_EntryProof(indexProof, value);
+  }
+}
@@ -0,0 +1,91 @@
+/*
+ * Copyright 2019 The Exonum Team
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+package com.exonum.binding.core.storage.indices;
+
+import static com.google.common.base.Preconditions.checkNotNull;
+
+import java.util.Optional;
+import org.checkerframework.checker.nullness.qual.Nullable;
+
+/**
+ * Indicates that an index cannot be accessed at the given address. For example, the address
+ * already holds an index of another type, or the index name is not valid.
+ *
+ * <p>If the index types do not match, the exception carries the names of the
+ * {@linkplain #getExpectedType() expected} and {@linkplain #getActualType() actual}
+ * Exonum index types (e.g., "Map" or "ProofList").
+ */
+public final class IndexAccessException extends RuntimeException {
+
+  private final IndexAddress address;
+  @Nullable private final String expectedType;
+  @Nullable private final String actualType;
+
+  /**
+   * Creates an exception indicating that the index at the given address cannot be accessed.
+   *
+   * @param message the description of the error
+   * @param address the address of the index
+   * @param expectedType the name of the requested index type, if the types do not match
+   * @param actualType the name of the type of the index at the address,
+   *     if the types do not match
+   */
+  public IndexAccessException(String message, IndexAddress address,
+      @Nullable String expectedType, @Nullable String actualType) {
+    super(message);
+    this.address = checkNotNull(address);
+    this.expectedType = expectedType;
+    this.actualType = actualType;
+  }
+
+  /**
+   * Creates an exception with the index address given as its name and optional id in group.
+   * Used by the native code.
+   */
+  IndexAccessException(String message, String name, @Nullable byte[] idInGroup,
+      @Nullable String expectedType, @Nullable String actualType) {
+    this(message, toAddress(name, idInGroup), expectedType, actualType);
+  }
+
+  private static IndexAddress toAddress(String name, @Nullable byte[] idInGroup) {
+    return (idInGroup == null) ? IndexAddress.valueOf(name)
+        : IndexAddress.valueOf(name, idInGroup);
+  }
+
+  /**
+   * Returns the address of the index that cannot be accessed.
+   */
+  public IndexAddress getAddress() {
+    return address;
+  }
+
+  /**
+   * Returns the name of the requested index type if the address holds an index of another type;
+   * or an empty optional if the access failed for another reason.
+   */
+  public Optional<String> getExpectedType() {
+    return Optional.ofNullable(expectedType);
+  }
+
+  /**
+   * Returns the name of the type of the index at the address if it differs from the requested
+   * type; or an empty optional if the access failed for another reason.
+   */
+  public Optional<String> getActualType() {
+    return Optional.ofNullable(actualType);
+  }
+}
@@ -220,9 +220,9 @@ public final class KeySetIndexProxy<E> extends AbstractIndexProxy implements Ite
    */
   @Override
   public Iterator<E> iterator() {
-    return StorageIterators.createIterator(
+    return StorageIterators.createBatchingIterator(
         nativeCreateIterator(getNativeHandle()),
-        this::nativeIteratorNext,
+        this::nativeIteratorNextBatch,
         this::nativeIteratorFree,
         dbView,
         modCounter,
@@ -274,7 +274,8 @@ public final class KeySetIndexProxy<E> extends AbstractIndexProxy implements Ite
 
   private native long nativeCreateIterator(long nativeHandle);
 
-  private native byte[] nativeIteratorNext(long iterNativeHandle);
+  private native byte[][] nativeIteratorNextBatch(long iterNativeHandle, int maxCount,
+      int maxBytes);
 
   private native void nativeIteratorFree(long iterNativeHandle);
 
@@ -197,7 +197,7 @@ public final class ListIndexProxy<E> extends AbstractListIndexProxy<E> implement
   native long nativeCreateIter(long nativeHandle);
 
   @Override
-  native byte[] nativeIterNext(long iterNativeHandle);
+  native byte[][] nativeIterNextBatch(long iterNativeHandle, int maxCount, int maxBytes);
 
   @Override
   native void nativeIterFree(long iterNativeHandle);
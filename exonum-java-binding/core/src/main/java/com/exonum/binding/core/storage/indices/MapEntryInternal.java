@@ -27,7 +27,6 @@ final class MapEntryInternal {
   final byte[] key;
   final byte[] value;
 
-  @SuppressWarnings("unused")  // native API
   MapEntryInternal(byte[] key, byte[] value) {
     this.key = checkStorageKey(key);
     this.value = checkStorageValue(value);
@@ -17,7 +17,9 @@
 package com.exonum.binding.core.storage.indices;
 
 import com.exonum.binding.common.collect.MapEntry;
+import java.util.Collection;
 import java.util.Iterator;
+import java.util.LinkedHashMap;
 import java.util.Map;
 
 /**
@@ -83,6 +85,27 @@ public interface MapIndex<K, V> extends StorageIndex {
    */
   V get(K key);
 
+  /**
+   * Returns the values associated with the specified keys. Equivalent to a sequence
+   * of individual {@link #get} operations.
+   *
+   * @param keys storage keys
+   * @return a map of the keys that have a mapping in this map to their values;
+   *         it iterates over the keys in the order of the passed collection
+   * @throws NullPointerException if the passed collection is null or contains a null key
+   * @throws IllegalStateException if this map is not valid
+   */
+  default Map<K, V> getAll(Collection<? extends K> keys) {
+    Map<K, V> values = new LinkedHashMap<>();
+    for (K key : keys) {
+      V value = get(key);
+      if (value != null) {
+        values.put(key, value);
+      }
+    }
+    return values;
+  }
+
   /**
    * Removes the value mapped to the specified key from the map.
    * If there is no such mapping, has no effect.
@@ -92,6 +115,21 @@ public interface MapIndex<K, V> extends StorageIndex {
    * @throws UnsupportedOperationException if this map is read-only
    */
   void remove(K key);
+
+  /**
+   * Removes the values mapped to the specified keys from the map. Equivalent to a sequence
+   * of individual {@link #remove} operations.
+   *
+   * @param keys storage keys
+   * @throws NullPointerException if the passed collection is null or contains a null key
+   * @throws IllegalStateException if this map is not valid
+   * @throws UnsupportedOperationException if this map is read-only
+   */
+  default void removeAll(Collection<? extends K> keys) {
+    for (K key : keys) {
+      remove(key);
+    }
+  }
   
   /**
    * Returns an iterator over the map keys. The keys are ordered in lexicographical order.
@@ -27,8 +27,12 @@ import com.exonum.binding.core.proxy.NativeHandle;
 import com.exonum.binding.core.proxy.ProxyDestructor;
 import com.exonum.binding.core.storage.database.View;
 import com.exonum.binding.core.util.LibraryLoader;
+import com.google.common.collect.ImmutableList;
 import com.google.protobuf.MessageLite;
+import java.util.Collection;
 import java.util.Iterator;
+import java.util.LinkedHashMap;
+import java.util.List;
 import java.util.Map;
 import java.util.function.LongSupplier;
 
@@ -203,10 +207,16 @@ public final class MapIndexProxy<K, V> extends AbstractIndexProxy implements Map
   @Override
   public void putAll(Map<? extends K, ? extends V> sourceMap) {
     notifyModified();
-    long nativeHandle = getNativeHandle();
+    int numEntries = sourceMap.size();
+    byte[][] dbKeys = new byte[numEntries][];
+    byte[][] dbValues = new byte[numEntries][];
+    int i = 0;
     for (Map.Entry<? extends K, ? extends V> entry : sourceMap.entrySet()) {
-      putInternal(nativeHandle, entry.getKey(), entry.getValue());
+      dbKeys[i] = keySerializer.toBytes(entry.getKey());
+      dbValues[i] = valueSerializer.toBytes(entry.getValue());
+      i++;
     }
+    nativePutAll(getNativeHandle(), dbKeys, dbValues);
   }
 
   private void putInternal(long thisNativeHandle, K key, V value) {
@@ -222,6 +232,19 @@ public final class MapIndexProxy<K, V> extends AbstractIndexProxy implements Map
     return (dbValue == null) ? null : valueSerializer.fromBytes(dbValue);
   }
 
+  @Override
+  public Map<K, V> getAll(Collection<? extends K> keys) {
+    List<K> keyList = ImmutableList.copyOf(keys);
+    byte[][] dbValues = nativeGetAll(getNativeHandle(), keysToArray(keyList));
+    Map<K, V> values = new LinkedHashMap<>();
+    for (int i = 0; i < dbValues.length; i++) {
+      if (dbValues[i] != null) {
+        values.put(keyList.get(i), valueSerializer.fromBytes(dbValues[i]));
+      }
+    }
+    return values;
+  }
+
   @Override
   public void remove(K key) {
     notifyModified();
@@ -229,11 +252,23 @@ public final class MapIndexProxy<K, V> extends AbstractIndexProxy implements Map
     nativeRemove(getNativeHandle(), dbKey);
   }
 
+  @Override
+  public void removeAll(Collection<? extends K> keys) {
+    notifyModified();
+    nativeRemoveAll(getNativeHandle(), keysToArray(keys));
+  }
+
+  private byte[][] keysToArray(Collection<? extends K> keys) {
+    return keys.stream()
+        .map(keySerializer::toBytes)
+        .toArray(byte[][]::new);
+  }
+
   @Override
   public Iterator<K> keys() {
-    return StorageIterators.createIterator(
+    return StorageIterators.createBatchingIterator(
         nativeCreateKeysIter(getNativeHandle()),
-        this::nativeKeysIterNext,
+        this::nativeKeysIterNextBatch,
         this::nativeKeysIterFree,
         dbView,
         modCounter,
@@ -243,9 +278,9 @@ public final class MapIndexProxy<K, V> extends AbstractIndexProxy implements Map
 
   @Override
   public Iterator<V> values() {
-    return StorageIterators.createIterator(
+    return StorageIterators.createBatchingIterator(
         nativeCreateValuesIter(getNativeHandle()),
-        this::nativeValuesIterNext,
+        this::nativeValuesIterNextBatch,
         this::nativeValuesIterFree,
         dbView,
         modCounter,
@@ -255,19 +290,23 @@ public final class MapIndexProxy<K, V> extends AbstractIndexProxy implements Map
 
   @Override
   public Iterator<MapEntry<K, V>> entries() {
-    return StorageIterators.createIterator(
+    return StorageIterators.createBatchingPairIterator(
         nativeCreateEntriesIter(getNativeHandle()),
-        this::nativeEntriesIterNext,
+        this::nativeEntriesIterNextBatch,
         this::nativeEntriesIterFree,
         dbView,
         modCounter,
-        (entry) -> entry.toMapEntry(entry, keySerializer, valueSerializer)
+        (key, value) -> {
+          MapEntryInternal entry = new MapEntryInternal(key, value);
+          return entry.toMapEntry(entry, keySerializer, valueSerializer);
+        }
     );
   }
 
   private native long nativeCreateEntriesIter(long nativeHandle);
 
-  private native MapEntryInternal nativeEntriesIterNext(long iterNativeHandle);
+  private native byte[][] nativeEntriesIterNextBatch(long iterNativeHandle, int maxCount,
+      int maxBytes);
 
   private native void nativeEntriesIterFree(long iterNativeHandle);
 
@@ -286,19 +325,27 @@ public final class MapIndexProxy<K, V> extends AbstractIndexProxy implements Map
 
   private native void nativePut(long nativeHandle, byte[] key, byte[] value);
 
+  private native void nativePutAll(long nativeHandle, byte[][] keys, byte[][] values);
+
   private native byte[] nativeGet(long nativeHandle, byte[] key);
 
+  private native byte[][] nativeGetAll(long nativeHandle, byte[][] keys);
+
   private native void nativeRemove(long nativeHandle, byte[] key);
 
+  private native void nativeRemoveAll(long nativeHandle, byte[][] keys);
+
   private native long nativeCreateKeysIter(long nativeHandle);
 
-  private native byte[] nativeKeysIterNext(long iterNativeHandle);
+  private native byte[][] nativeKeysIterNextBatch(long iterNativeHandle, int maxCount,
+      int maxBytes);
 
   private native void nativeKeysIterFree(long iterNativeHandle);
 
   private native long nativeCreateValuesIter(long nativeHandle);
 
-  private native byte[] nativeValuesIterNext(long iterNativeHandle);
+  private native byte[][] nativeValuesIterNextBatch(long iterNativeHandle, int maxCount,
+      int maxBytes);
 
   private native void nativeValuesIterFree(long iterNativeHandle);
 
@@ -0,0 +1,199 @@
+/*
+ * Copyright 2019 The Exonum Team
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+package com.exonum.binding.core.storage.indices;
+
+import static com.google.common.base.Preconditions.checkState;
+
+import com.exonum.binding.common.hash.HashCode;
+import com.exonum.binding.common.serialization.CheckingSerializerDecorator;
+import com.exonum.binding.common.serialization.Serializer;
+import com.exonum.binding.common.serialization.StandardSerializers;
+import com.exonum.binding.core.proxy.Cleaner;
+import com.exonum.binding.core.proxy.NativeHandle;
+import com.exonum.binding.core.proxy.ProxyDestructor;
+import com.exonum.binding.core.storage.database.Fork;
+import com.exonum.binding.core.storage.database.Snapshot;
+import com.exonum.binding.core.storage.database.View;
+import com.exonum.binding.core.util.LibraryLoader;
+import com.google.protobuf.ByteString;
+import com.google.protobuf.InvalidProtocolBufferException;
+import com.google.protobuf.MessageLite;
+import java.util.Optional;
+
+/**
+ * A proof entry is a Merkelized database index that can contain no or a single value.
+ * Unlike {@link EntryIndexProxy}, it has an {@linkplain #getIndexHash() index hash},
+ * which can be included into the service state hashes, so that the entry value is
+ * authenticated by the blockchain state hash. The {@linkplain #getProof() proof} of the entry
+ * value allows light clients to verify it.
+ *
+ * <p>A proof entry is analogous to {@link java.util.Optional}, but provides modifying
+ * ("destructive") operations when created with a {@link Fork}.
+ * Such methods are specified to throw {@link UnsupportedOperationException} if
+ * the entry is created with a {@link Snapshot} — a read-only database view.
+ *
+ * <p>All method arguments are non-null by default.
+ *
+ * <p>This class is not thread-safe and and its instances shall not be shared between threads.
+ *
+ * <p>When the view goes out of scope, this entry is destroyed. Subsequent use of the closed entry
+ * is prohibited and will result in {@link IllegalStateException}.
+ *
+ * @param <T> the type of an element in this entry
+ *
+ * @see View
+ * @see com.exonum.binding.core.service.Service#getStateHashes
+ */
+public final class ProofEntryIndexProxy<T> extends AbstractEntryIndexProxy<T> {
+
+  static {
+    LibraryLoader.load();
+  }
+
+  /**
+   * Creates a new proof entry storing protobuf messages.
+   *
+   * @param name a unique alphanumeric non-empty identifier of the entry in the underlying storage:
+   *             [a-zA-Z0-9_]
+   * @param view a database view. Must be valid.
+   *             If a view is read-only, "destructive" operations are not permitted.
+   * @param elementType the class of an element-protobuf message
+   * @param <E> the type of entry; must be a protobuf message
+   *     that has a static {@code #parseFrom(byte[])} method
+   *
+   * @throws IllegalArgumentException if the name is empty
+   * @throws IllegalStateException if the view proxy is invalid
+   */
+  public static <E extends MessageLite> ProofEntryIndexProxy<E> newInstance(
+      String name, View view, Class<E> elementType) {
+    return newInstance(name, view, StandardSerializers.protobuf(elementType));
+  }
+
+  /**
+   * Creates a new proof entry.
+   *
+   * @param name a unique alphanumeric non-empty identifier of the entry in the underlying storage:
+   *             [a-zA-Z0-9_]
+   * @param view a database view. Must be valid.
+   *             If a view is read-only, "destructive" operations are not permitted.
+   * @param serializer an entry serializer
+   *
+   * @throws IllegalArgumentException if the name is empty
+   * @throws IllegalStateException if the view proxy is invalid
+   * @see StandardSerializers
+   */
+  public static <E> ProofEntryIndexProxy<E> newInstance(
+      String name, View view, Serializer<E> serializer) {
+    IndexAddress address = IndexAddress.valueOf(name);
+    return view.findOpenIndex(address)
+        .map(ProofEntryIndexProxy::<E>checkCachedInstance)
+        .orElseGet(() -> newProofEntryIndexProxy(address, view, serializer));
+  }
+
+  @SuppressWarnings("unchecked") // The compiler is correct: the cache is not type-safe: ECR-3387
+  private static <E> ProofEntryIndexProxy<E> checkCachedInstance(StorageIndex cachedIndex) {
+    StoragePreconditions.checkIndexType(cachedIndex, ProofEntryIndexProxy.class);
+    return (ProofEntryIndexProxy<E>) cachedIndex;
+  }
+
+  private static <E> ProofEntryIndexProxy<E> newProofEntryIndexProxy(IndexAddress address,
+      View view, Serializer<E> serializer) {
+    CheckingSerializerDecorator<E> s = CheckingSerializerDecorator.from(serializer);
+
+    NativeHandle entryNativeHandle = createNativeEntry(address.getName(), view);
+
+    ProofEntryIndexProxy<E> entry =
+        new ProofEntryIndexProxy<>(entryNativeHandle, address, view, s);
+    view.registerIndex(entry);
+    return entry;
+  }
+
+
+  private static NativeHandle createNativeEntry(String name, View view) {
+    long viewNativeHandle = view.getViewNativeHandle();
+    NativeHandle entryNativeHandle = new NativeHandle(nativeCreate(name, viewNativeHandle));
+
+    Cleaner cleaner = view.getCleaner();
+    ProxyDestructor.newRegistered(cleaner, entryNativeHandle, ProofEntryIndexProxy.class,
+        ProofEntryIndexProxy::nativeFree);
+    return entryNativeHandle;
+  }
+
+  private ProofEntryIndexProxy(NativeHandle nativeHandle, IndexAddress address, View view,
+      CheckingSerializerDecorator<T> serializer) {
+    super(nativeHandle, address, view, serializer);
+  }
+
+  /**
+   * Returns the index hash which represents the complete state of this entry.
+   * Any modifications to the entry affect the index hash.
+   *
+   * @throws IllegalStateException if the proxy is invalid
+   */
+  public HashCode getIndexHash() {
+    return HashCode.fromBytes(nativeGetIndexHash(getNativeHandle()));
+  }
+
+  /**
+   * Returns a proof of the value of this entry, which is anchored to the blockchain state hash
+   * of the database snapshot of this entry.
+   *
+   * <p>The proof can be created only from a {@link Snapshot}, as the state aggregator
+   * is updated only when the changes are merged into the database.
+   *
+   * @throws IllegalStateException if the entry is created with a {@link Fork};
+   *     or if the proxy is invalid
+   * @see EntryProof
+   */
+  public EntryProof getProof() {
+    checkState(!dbView.canModify(), "Proofs can be created only from a snapshot, but was %s",
+        dbView);
+    byte[] indexProof = nativeGetIndexProof(getName(), dbView.getViewNativeHandle());
+    byte[] value = nativeGet(getNativeHandle());
+    return EntryProof.newInstance(decodeProofMessage(indexProof),
+        Optional.ofNullable(value).map(ByteString::copyFrom));
+  }
+
+  private static MapProof decodeProofMessage(byte[] proofMessage) {
+    try {
+      return MapProof.parseFrom(proofMessage);
+    } catch (InvalidProtocolBufferException e) {
+      // Must never happen with correct native code
+      throw new IllegalStateException("Non-decodable proof message", e);
+    }
+  }
+
+  private static native long nativeCreate(String name, long viewNativeHandle);
+
+  @Override
+  native void nativeSet(long nativeHandle, byte[] value);
+
+  @Override
+  native boolean nativeIsPresent(long nativeHandle);
+
+  @Override
+  native byte[] nativeGet(long nativeHandle);
+
+  private native byte[] nativeGetIndexHash(long nativeHandle);
+
+  private static native byte[] nativeGetIndexProof(String name, long viewNativeHandle);
+
+  @Override
+  native void nativeRemove(long nativeHandle);
+
+  private static native void nativeFree(long nativeHandle);
+}
@@ -256,7 +256,7 @@ public final class ProofListIndexProxy<E> extends AbstractListIndexProxy<E>
   native long nativeCreateIter(long nativeHandle);
 
   @Override
-  native byte[] nativeIterNext(long iterNativeHandle);
+  native byte[][] nativeIterNextBatch(long iterNativeHandle, int maxCount, int maxBytes);
 
   @Override
   native void nativeIterFree(long iterNativeHandle);
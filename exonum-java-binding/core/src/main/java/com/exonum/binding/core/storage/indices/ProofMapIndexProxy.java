@@ -28,10 +28,12 @@ import com.exonum.binding.core.proxy.Cleaner;
 import com.exonum.binding.core.proxy.NativeHandle;
 import com.exonum.binding.core.proxy.ProxyDestructor;
 import com.exonum.binding.core.storage.database.View;
+import com.google.common.collect.ImmutableList;
 import com.google.common.collect.Lists;
 import com.google.protobuf.InvalidProtocolBufferException;
 import java.util.Collection;
 import java.util.Iterator;
+import java.util.LinkedHashMap;
 import java.util.List;
 import java.util.Map;
 import java.util.function.LongSupplier;
@@ -285,12 +287,20 @@ public final class ProofMapIndexProxy<K, V> extends AbstractIndexProxy implement
   @Override
   public void putAll(Map<? extends K, ? extends V> sourceMap) {
     notifyModified();
-    long nativeHandle = getNativeHandle();
+    int numEntries = sourceMap.size();
+    byte[][] dbKeys = new byte[numEntries][];
+    byte[][] dbValues = new byte[numEntries][];
+    int i = 0;
     for (Map.Entry<? extends K, ? extends V> entry : sourceMap.entrySet()) {
-      putInternal(nativeHandle, entry.getKey(), entry.getValue());
+      dbKeys[i] = keySerializer.toBytes(entry.getKey());
+      dbValues[i] = valueSerializer.toBytes(entry.getValue());
+      i++;
     }
+    nativePutAll(getNativeHandle(), dbKeys, dbValues);
   }
 
+  private native void nativePutAll(long nativeHandle, byte[][] keys, byte[][] values);
+
   private void putInternal(long nativeHandle, K key, V value) {
     byte[] dbKey = keySerializer.toBytes(key);
     byte[] dbValue = valueSerializer.toBytes(value);
@@ -308,6 +318,21 @@ public final class ProofMapIndexProxy<K, V> extends AbstractIndexProxy implement
 
   private native byte[] nativeGet(long nativeHandle, byte[] key);
 
+  @Override
+  public Map<K, V> getAll(Collection<? extends K> keys) {
+    List<K> keyList = ImmutableList.copyOf(keys);
+    byte[][] dbValues = nativeGetAll(getNativeHandle(), keysToArray(keyList));
+    Map<K, V> values = new LinkedHashMap<>();
+    for (int i = 0; i < dbValues.length; i++) {
+      if (dbValues[i] != null) {
+        values.put(keyList.get(i), valueSerializer.fromBytes(dbValues[i]));
+      }
+    }
+    return values;
+  }
+
+  private native byte[][] nativeGetAll(long nativeHandle, byte[][] keys);
+
   /**
    * Returns a proof that there are values mapped to the specified keys or that there are no such
    * mappings.
@@ -400,11 +425,19 @@ public final class ProofMapIndexProxy<K, V> extends AbstractIndexProxy implement
 
   private native void nativeRemove(long nativeHandle, byte[] key);
 
+  @Override
+  public void removeAll(Collection<? extends K> keys) {
+    notifyModified();
+    nativeRemoveAll(getNativeHandle(), keysToArray(keys));
+  }
+
+  private native void nativeRemoveAll(long nativeHandle, byte[][] keys);
+
   @Override
   public Iterator<K> keys() {
-    return StorageIterators.createIterator(
+    return StorageIterators.createBatchingIterator(
         nativeCreateKeysIter(getNativeHandle()),
-        this::nativeKeysIterNext,
+        this::nativeKeysIterNextBatch,
         this::nativeKeysIterFree,
         dbView,
         modCounter,
@@ -414,15 +447,16 @@ public final class ProofMapIndexProxy<K, V> extends AbstractIndexProxy implement
 
   private native long nativeCreateKeysIter(long nativeHandle);
 
-  private native byte[] nativeKeysIterNext(long iterNativeHandle);
+  private native byte[][] nativeKeysIterNextBatch(long iterNativeHandle, int maxCount,
+      int maxBytes);
 
   private native void nativeKeysIterFree(long iterNativeHandle);
 
   @Override
   public Iterator<V> values() {
-    return StorageIterators.createIterator(
+    return StorageIterators.createBatchingIterator(
         nativeCreateValuesIter(getNativeHandle()),
-        this::nativeValuesIterNext,
+        this::nativeValuesIterNextBatch,
         this::nativeValuesIterFree,
         dbView,
         modCounter,
@@ -432,25 +466,30 @@ public final class ProofMapIndexProxy<K, V> extends AbstractIndexProxy implement
 
   private native long nativeCreateValuesIter(long nativeHandle);
 
-  private native byte[] nativeValuesIterNext(long iterNativeHandle);
+  private native byte[][] nativeValuesIterNextBatch(long iterNativeHandle, int maxCount,
+      int maxBytes);
 
   private native void nativeValuesIterFree(long iterNativeHandle);
 
   @Override
   public Iterator<MapEntry<K, V>> entries() {
-    return StorageIterators.createIterator(
+    return StorageIterators.createBatchingPairIterator(
         nativeCreateEntriesIter(getNativeHandle()),
-        this::nativeEntriesIterNext,
+        this::nativeEntriesIterNextBatch,
         this::nativeEntriesIterFree,
         dbView,
         modCounter,
-        (entry) -> entry.toMapEntry(entry, keySerializer, valueSerializer)
+        (key, value) -> {
+          MapEntryInternal entry = new MapEntryInternal(key, value);
+          return entry.toMapEntry(entry, keySerializer, valueSerializer);
+        }
     );
   }
 
   private native long nativeCreateEntriesIter(long nativeHandle);
 
-  private native MapEntryInternal nativeEntriesIterNext(long iterNativeHandle);
+  private native byte[][] nativeEntriesIterNextBatch(long iterNativeHandle, int maxCount,
+      int maxBytes);
 
   private native void nativeEntriesIterFree(long iterNativeHandle);
 
@@ -0,0 +1,371 @@
+/*
+ * Copyright 2019 The Exonum Team
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+package com.exonum.binding.core.storage.indices;
+
+import static com.exonum.binding.core.storage.indices.StoragePreconditions.checkElementIndex;
+import static com.exonum.binding.core.storage.indices.StoragePreconditions.checkIndexType;
+import static com.google.common.base.Preconditions.checkArgument;
+
+import com.exonum.binding.common.serialization.CheckingSerializerDecorator;
+import com.exonum.binding.common.serialization.Serializer;
+import com.exonum.binding.common.serialization.StandardSerializers;
+import com.exonum.binding.core.proxy.Cleaner;
+import com.exonum.binding.core.proxy.NativeHandle;
+import com.exonum.binding.core.proxy.ProxyDestructor;
+import com.exonum.binding.core.storage.database.View;
+import com.exonum.binding.core.util.LibraryLoader;
+import com.google.protobuf.MessageLite;
+import java.util.Iterator;
+import java.util.Optional;
+import java.util.Spliterator;
+import java.util.Spliterators;
+import java.util.function.LongSupplier;
+import java.util.stream.Stream;
+import java.util.stream.StreamSupport;
+
+/**
+ * A sparse list index proxy is a list of elements that may have gaps between them.
+ * Elements may be added to the end of the list, and replaced or removed at any index.
+ * The removal of an element leaves a gap at its index; the indices of the other elements
+ * do not change.
+ *
+ * <p>The list has a <em>capacity</em> — the index following the last added element,
+ * and a <em>size</em> — the number of its elements, not counting the gaps.
+ *
+ * <p>This list implementation does not permit null elements.
+ *
+ * <p>The "destructive" methods of the list, i.e., those that change its contents,
+ * are specified to throw {@link UnsupportedOperationException} if
+ * this list has been created with a read-only database view.
+ *
+ * <p>All method arguments are non-null by default.
+ *
+ * <p>This class is not thread-safe and and its instances shall not be shared between threads.
+ *
+ * <p>When the view goes out of scope, this list is destroyed. Subsequent use of the closed list
+ * is prohibited and will result in {@link IllegalStateException}.
+ *
+ * @param <E> the type of elements in this list
+ * @see View
+ */
+public final class SparseListIndexProxy<E> extends AbstractIndexProxy implements Iterable<E> {
+
+  static {
+    LibraryLoader.load();
+  }
+
+  private static final int BASE_SPLITERATOR_CHARACTERISTICS =
+      Spliterator.NONNULL | Spliterator.ORDERED;
+
+  private final CheckingSerializerDecorator<E> serializer;
+
+  /**
+   * Creates a new sparse list storing protobuf messages.
+   *
+   * @param name a unique alphanumeric non-empty identifier of this list in the underlying storage:
+   *             [a-zA-Z0-9_]
+   * @param view a database view. Must be valid.
+   *             If a view is read-only, "destructive" operations are not permitted.
+   * @param elementType the class of an element-protobuf message
+   * @param <E> the type of elements in this list; must be a protobuf message
+   *     that has a public static {@code #parseFrom(byte[])} method
+   * @throws IllegalStateException if the view is not valid
+   * @throws IllegalArgumentException if the name is empty
+   */
+  public static <E extends MessageLite> SparseListIndexProxy<E> newInstance(
+      String name, View view, Class<E> elementType) {
+    return newInstance(name, view, StandardSerializers.protobuf(elementType));
+  }
+
+  /**
+   * Creates a new sparse list proxy.
+   *
+   * @param name a unique alphanumeric non-empty identifier of this list in the underlying storage:
+   *             [a-zA-Z0-9_]
+   * @param view a database view. Must be valid.
+   *             If a view is read-only, "destructive" operations are not permitted.
+   * @param serializer a serializer of elements
+   * @param <E> the type of elements in this list
+   * @throws IllegalStateException if the view is not valid
+   * @throws IllegalArgumentException if the name is empty
+   * @see StandardSerializers
+   */
+  public static <E> SparseListIndexProxy<E> newInstance(
+      String name, View view, Serializer<E> serializer) {
+    IndexAddress address = IndexAddress.valueOf(name);
+    long viewNativeHandle = view.getViewNativeHandle();
+    LongSupplier nativeListConstructor = () -> nativeCreate(name, viewNativeHandle);
+
+    return getOrCreate(address, view, serializer, nativeListConstructor);
+  }
+
+  /**
+   * Creates a new sparse list in a <a href="package-summary.html#families">collection group</a>
+   * with the given name.
+   *
+   * <p>See a <a href="package-summary.html#families-limitations">caveat</a> on index identifiers.
+   *
+   * @param groupName a name of the collection group
+   * @param listId an identifier of this collection in the group, see the caveats
+   * @param view a database view
+   * @param serializer a serializer of list elements
+   * @param <E> the type of elements in this list
+   * @return a new list proxy
+   * @throws IllegalStateException if the view is not valid
+   * @throws IllegalArgumentException if the name or index id is empty
+   * @see StandardSerializers
+   */
+  public static <E> SparseListIndexProxy<E> newInGroupUnsafe(String groupName, byte[] listId,
+      View view, Serializer<E> serializer) {
+    IndexAddress address = IndexAddress.valueOf(groupName, listId);
+    long viewNativeHandle = view.getViewNativeHandle();
+    LongSupplier nativeListConstructor =
+        () -> nativeCreateInGroup(groupName, listId, viewNativeHandle);
+
+    return getOrCreate(address, view, serializer, nativeListConstructor);
+  }
+
+  private static <E> SparseListIndexProxy<E> getOrCreate(IndexAddress address, View view,
+      Serializer<E> serializer, LongSupplier nativeListConstructor) {
+    return view.findOpenIndex(address)
+        .map(SparseListIndexProxy::<E>checkCachedInstance)
+        .orElseGet(() -> newSparseListProxy(address, view, serializer, nativeListConstructor));
+  }
+
+  @SuppressWarnings("unchecked") // The compiler is correct: the cache is not type-safe: ECR-3387
+  private static <E> SparseListIndexProxy<E> checkCachedInstance(StorageIndex cachedIndex) {
+    checkIndexType(cachedIndex, SparseListIndexProxy.class);
+    return (SparseListIndexProxy<E>) cachedIndex;
+  }
+
+  private static <E> SparseListIndexProxy<E> newSparseListProxy(IndexAddress address, View view,
+      Serializer<E> serializer, LongSupplier nativeListConstructor) {
+    CheckingSerializerDecorator<E> s = CheckingSerializerDecorator.from(serializer);
+
+    NativeHandle listNativeHandle = createNativeList(view, nativeListConstructor);
+
+    SparseListIndexProxy<E> list = new SparseListIndexProxy<>(listNativeHandle, address, view, s);
+    view.registerIndex(list);
+    return list;
+  }
+
+  private static NativeHandle createNativeList(View view, LongSupplier nativeListConstructor) {
+    NativeHandle listNativeHandle = new NativeHandle(nativeListConstructor.getAsLong());
+
+    Cleaner cleaner = view.getCleaner();
+    ProxyDestructor.newRegistered(cleaner, listNativeHandle, SparseListIndexProxy.class,
+        SparseListIndexProxy::nativeFree);
+    return listNativeHandle;
+  }
+
+  private SparseListIndexProxy(NativeHandle nativeHandle, IndexAddress address, View view,
+      CheckingSerializerDecorator<E> serializer) {
+    super(nativeHandle, address, view);
+    this.serializer = serializer;
+  }
+
+  /**
+   * Adds a new element to the end of the list, at the index equal to its capacity.
+   *
+   * @param e an element to add
+   * @throws IllegalStateException if this list is not valid
+   * @throws UnsupportedOperationException if this list is read-only
+   */
+  public void add(E e) {
+    notifyModified();
+    byte[] dbElement = serializer.toBytes(e);
+    nativeAdd(getNativeHandle(), dbElement);
+  }
+
+  /**
+   * Replaces the element at the given index, or fills the gap at that index, with the given
+   * element.
+   *
+   * @param index an index at which to put the element, must be in the range
+   *     [0, {@linkplain #capacity() capacity})
+   * @param e an element to put
+   * @throws IndexOutOfBoundsException if the index is invalid
+   * @throws IllegalStateException if this list is not valid
+   * @throws UnsupportedOperationException if this list is read-only
+   */
+  public void set(long index, E e) {
+    checkElementIndex(index, capacity());
+    notifyModified();
+    byte[] dbElement = serializer.toBytes(e);
+    nativeSet(getNativeHandle(), index, dbElement);
+  }
+
+  /**
+   * Returns the element at the given index, or {@code Optional.empty()} if there is no element
+   * at that index.
+   *
+   * @param index an index of the element, must be non-negative
+   * @throws IllegalArgumentException if the index is negative
+   * @throws IllegalStateException if this list is not valid
+   */
+  public Optional<E> get(long index) {
+    checkArgument(index >= 0, "index (%s) is negative", index);
+    byte[] e = nativeGet(getNativeHandle(), index);
+    return Optional.ofNullable(e)
+        .map(serializer::fromBytes);
+  }
+
+  /**
+   * Removes the element at the given index, leaving a gap in its place.
+   * Does nothing if there is no element at that index.
+   *
+   * @param index an index of the element, must be non-negative
+   * @return the removed element, or {@code Optional.empty()} if there was no element
+   *     at that index
+   * @throws IllegalArgumentException if the index is negative
+   * @throws IllegalStateException if this list is not valid
+   * @throws UnsupportedOperationException if this list is read-only
+   */
+  public Optional<E> remove(long index) {
+    checkArgument(index >= 0, "index (%s) is negative", index);
+    notifyModified();
+    byte[] e = nativeRemove(getNativeHandle(), index);
+    return Optional.ofNullable(e)
+        .map(serializer::fromBytes);
+  }
+
+  /**
+   * Removes all of the elements from this list. The list will be empty and have zero capacity
+   * after this method returns.
+   *
+   * @throws IllegalStateException if this list is not valid
+   * @throws UnsupportedOperationException if this list is read-only
+   */
+  public void clear() {
+    notifyModified();
+    nativeClear(getNativeHandle());
+  }
+
+  /**
+   * Returns true if this list has no elements.
+   *
+   * @throws IllegalStateException if this list is not valid
+   */
+  public boolean isEmpty() {
+    return nativeIsEmpty(getNativeHandle());
+  }
+
+  /**
+   * Returns the number of elements in this list, not counting the gaps.
+   *
+   * @throws IllegalStateException if this list is not valid
+   */
+  public long size() {
+    return nativeSize(getNativeHandle());
+  }
+
+  /**
+   * Returns the capacity of this list: the index following the last added element.
+   *
+   * @throws IllegalStateException if this list is not valid
+   */
+  public long capacity() {
+    return nativeCapacity(getNativeHandle());
+  }
+
+  /**
+   * Returns an iterator over the indices of the elements of this list, in ascending order.
+   *
+   * @throws IllegalStateException if this list is not valid
+   */
+  public Iterator<Long> indices() {
+    return StorageIterators.createBatchingIterator(
+        nativeCreateIndexIter(getNativeHandle()),
+        this::nativeIndexIterNextBatch,
+        this::nativeIndexIterFree,
+        dbView,
+        modCounter,
+        StandardSerializers.fixed64()::fromBytes);
+  }
+
+  /**
+   * Returns an iterator over the elements of this list, in the order of their indices.
+   *
+   * @throws IllegalStateException if this list is not valid
+   */
+  @Override
+  public Iterator<E> iterator() {
+    return StorageIterators.createBatchingIterator(
+        nativeCreateValueIter(getNativeHandle()),
+        this::nativeValueIterNextBatch,
+        this::nativeValueIterFree,
+        dbView,
+        modCounter,
+        serializer::fromBytes);
+  }
+
+  /**
+   * Returns a stream of the elements of this list, in the order of their indices.
+   *
+   * @throws IllegalStateException if this list is not valid
+   */
+  public Stream<E> stream() {
+    return StreamSupport.stream(
+        Spliterators.spliteratorUnknownSize(iterator(), streamCharacteristics()),
+        false);
+  }
+
+  private int streamCharacteristics() {
+    if (dbView.canModify()) {
+      return BASE_SPLITERATOR_CHARACTERISTICS;
+    } else {
+      return BASE_SPLITERATOR_CHARACTERISTICS | Spliterator.IMMUTABLE;
+    }
+  }
+
+  private static native long nativeCreate(String listName, long viewNativeHandle);
+
+  private static native long nativeCreateInGroup(String groupName, byte[] listId,
+      long viewNativeHandle);
+
+  private static native void nativeFree(long nativeHandle);
+
+  private native void nativeAdd(long nativeHandle, byte[] e);
+
+  private native void nativeSet(long nativeHandle, long index, byte[] e);
+
+  private native byte[] nativeGet(long nativeHandle, long index);
+
+  private native byte[] nativeRemove(long nativeHandle, long index);
+
+  private native void nativeClear(long nativeHandle);
+
+  private native boolean nativeIsEmpty(long nativeHandle);
+
+  private native long nativeSize(long nativeHandle);
+
+  private native long nativeCapacity(long nativeHandle);
+
+  private native long nativeCreateIndexIter(long nativeHandle);
+
+  private native byte[][] nativeIndexIterNextBatch(long iterNativeHandle, int maxCount,
+      int maxBytes);
+
+  private native void nativeIndexIterFree(long iterNativeHandle);
+
+  private native long nativeCreateValueIter(long nativeHandle);
+
+  private native byte[][] nativeValueIterNextBatch(long iterNativeHandle, int maxCount,
+      int maxBytes);
+
+  private native void nativeValueIterFree(long iterNativeHandle);
+}
@@ -20,50 +20,91 @@ import com.exonum.binding.core.proxy.Cleaner;
 import com.exonum.binding.core.proxy.NativeHandle;
 import com.exonum.binding.core.proxy.ProxyDestructor;
 import com.exonum.binding.core.storage.database.View;
-import com.google.common.collect.Iterators;
+import com.exonum.binding.core.storage.indices.BatchingRustIter.ElementDecoder;
+import com.exonum.binding.core.storage.indices.BatchingRustIter.NextBatchFunction;
 import java.util.Iterator;
+import java.util.function.BiFunction;
 import java.util.function.Function;
 import java.util.function.LongConsumer;
-import java.util.function.LongFunction;
 
 final class StorageIterators {
 
   /**
-   * Creates a new iterator over an index.
+   * Creates a new iterator over an index, which fetches the elements from the native iterator
+   * in batches. Each element is represented by a single array of bytes in a batch.
    *
-   * <p>The returned iterator is a {@link ConfigurableRustIter}
+   * <p>The returned iterator is a {@link BatchingRustIter}
    * wrapped in a {@link RustIterAdapter}.
    *
    * @param nativeHandle nativeHandle of this iterator
-   * @param nextFunction a function to call to get the next item
+   * @param nextBatchFunction a function to call to get the next batch of items
    * @param disposeOperation an operation to call to destroy the corresponding native iterator
    * @param collectionView a database view of the collection over which to iterate
    * @param modificationCounter a modification counter of the collection
-   * @param transformingFunction a function to apply to elements returned by native iterator
-   *                             (usually, to an array of bytes)
+   * @param transformingFunction a function to apply to an array of bytes representing an element
    */
-  static <ElementT, NativeT> Iterator<ElementT> createIterator(
+  static <ElementT> Iterator<ElementT> createBatchingIterator(
       long nativeHandle,
-      LongFunction<NativeT> nextFunction,
+      NextBatchFunction nextBatchFunction,
       LongConsumer disposeOperation,
       View collectionView,
       ModificationCounter modificationCounter,
-      Function<? super NativeT, ? extends ElementT> transformingFunction) {
+      Function<byte[], ? extends ElementT> transformingFunction) {
+    return createBatchingIterator(nativeHandle, nextBatchFunction, disposeOperation,
+        collectionView, modificationCounter, 1,
+        (batch, i) -> transformingFunction.apply(batch[i]));
+  }
 
+  /**
+   * Creates a new iterator over an index, which fetches the elements from the native iterator
+   * in batches. Each element is represented by a pair of arrays of bytes in a batch
+   * (e.g., a key and a value).
+   *
+   * <p>The returned iterator is a {@link BatchingRustIter}
+   * wrapped in a {@link RustIterAdapter}.
+   *
+   * @param nativeHandle nativeHandle of this iterator
+   * @param nextBatchFunction a function to call to get the next batch of items
+   * @param disposeOperation an operation to call to destroy the corresponding native iterator
+   * @param collectionView a database view of the collection over which to iterate
+   * @param modificationCounter a modification counter of the collection
+   * @param transformingFunction a function to apply to a pair of arrays of bytes
+   *                             representing an element
+   */
+  static <ElementT> Iterator<ElementT> createBatchingPairIterator(
+      long nativeHandle,
+      NextBatchFunction nextBatchFunction,
+      LongConsumer disposeOperation,
+      View collectionView,
+      ModificationCounter modificationCounter,
+      BiFunction<byte[], byte[], ? extends ElementT> transformingFunction) {
+    return createBatchingIterator(nativeHandle, nextBatchFunction, disposeOperation,
+        collectionView, modificationCounter, 2,
+        (batch, i) -> transformingFunction.apply(batch[i], batch[i + 1]));
+  }
+
+  private static <ElementT> Iterator<ElementT> createBatchingIterator(
+      long nativeHandle,
+      NextBatchFunction nextBatchFunction,
+      LongConsumer disposeOperation,
+      View collectionView,
+      ModificationCounter modificationCounter,
+      int elementLength,
+      ElementDecoder<ElementT> elementDecoder) {
     // Register the destructor first.
     NativeHandle handle = new NativeHandle(nativeHandle);
     Cleaner cleaner = collectionView.getCleaner();
     cleaner.add(new ProxyDestructor(handle, RustIter.class, disposeOperation));
 
-    Iterator<NativeT> iterator = new RustIterAdapter<>(
-        new ConfigurableRustIter<>(
+    return new RustIterAdapter<>(
+        new BatchingRustIter<>(
             handle,
-            nextFunction,
+            nextBatchFunction,
+            elementLength,
+            elementDecoder,
             modificationCounter
         )
     );
-
-    return Iterators.transform(iterator, transformingFunction::apply);
   }
 
   private StorageIterators() {}
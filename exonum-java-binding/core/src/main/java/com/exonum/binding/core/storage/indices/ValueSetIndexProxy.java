@@ -38,7 +38,6 @@ import java.util.Spliterators;
 import java.util.function.LongSupplier;
 import java.util.stream.Stream;
 import java.util.stream.StreamSupport;
-import javax.annotation.Nullable;
 
 /**
  * A value set is an index that contains no duplicate elements (values).
@@ -239,9 +238,9 @@ public final class ValueSetIndexProxy<E> extends AbstractIndexProxy
    * @throws IllegalStateException if this set is not valid
    */
   public Iterator<HashCode> hashes() {
-    return StorageIterators.createIterator(
+    return StorageIterators.createBatchingIterator(
         nativeCreateHashIterator(getNativeHandle()),
-        this::nativeHashIteratorNext,
+        this::nativeHashIteratorNextBatch,
         this::nativeHashIteratorFree,
         dbView,
         modCounter,
@@ -257,18 +256,19 @@ public final class ValueSetIndexProxy<E> extends AbstractIndexProxy
    */
   @Override
   public Iterator<Entry<E>> iterator() {
-    return StorageIterators.createIterator(
+    return StorageIterators.createBatchingPairIterator(
         nativeCreateIterator(getNativeHandle()),
-        this::nativeIteratorNext,
+        this::nativeIteratorNextBatch,
         this::nativeIteratorFree,
         dbView,
         modCounter,
-        (e) -> Entry.fromInternal(e, serializer));
+        (hash, value) -> Entry.fromInternal(new EntryInternal(hash, value), serializer));
   }
 
   private native long nativeCreateIterator(long nativeHandle);
 
-  private native EntryInternal nativeIteratorNext(long iterNativeHandle);
+  private native byte[][] nativeIteratorNextBatch(long iterNativeHandle, int maxCount,
+      int maxBytes);
 
   private native void nativeIteratorFree(long iterNativeHandle);
 
@@ -334,13 +334,12 @@ public final class ValueSetIndexProxy<E> extends AbstractIndexProxy
   }
 
   /**
-   * An internal entry: native API.
+   * An internal entry, created from the native representation.
    */
   private static class EntryInternal {
     final byte[] hash;
     final byte[] value;
 
-    @SuppressWarnings("unused")  // native API
     private EntryInternal(byte[] hash, byte[] value) {
       this.hash = checkNotNull(hash);
       this.value = checkStorageValue(value);
@@ -388,8 +387,8 @@ public final class ValueSetIndexProxy<E> extends AbstractIndexProxy
 
   private native long nativeCreateHashIterator(long nativeHandle);
 
-  @Nullable
-  private native byte[] nativeHashIteratorNext(long iterNativeHandle);
+  private native byte[][] nativeHashIteratorNextBatch(long iterNativeHandle, int maxCount,
+      int maxBytes);
 
   private native void nativeHashIteratorFree(long iterNativeHandle);
 
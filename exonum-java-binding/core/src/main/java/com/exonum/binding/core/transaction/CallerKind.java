@@ -0,0 +1,38 @@
+/*
+ * Copyright 2019 The Exonum Team
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+package com.exonum.binding.core.transaction;
+
+/**
+ * The kind of the caller of a service method.
+ *
+ * @see TransactionContext#getCallerKind()
+ */
+public enum CallerKind {
+  /**
+   * The method is invoked by a transaction message, signed by its author.
+   */
+  TRANSACTION,
+  /**
+   * The method is invoked by another service, e.g., the supervisor.
+   */
+  SERVICE,
+  /**
+   * The method is invoked by the blockchain core itself, e.g., to apply a configuration
+   * change.
+   */
+  BLOCKCHAIN
+}
@@ -16,10 +16,13 @@
 
 package com.exonum.binding.core.transaction;
 
+import static com.google.common.base.Preconditions.checkState;
+
 import com.exonum.binding.common.crypto.PublicKey;
 import com.exonum.binding.common.hash.HashCode;
 import com.exonum.binding.core.storage.database.Fork;
 import com.google.auto.value.AutoValue;
+import javax.annotation.Nullable;
 
 /**
  * Default implementation of the transaction context.
@@ -27,9 +30,34 @@ import com.google.auto.value.AutoValue;
 @AutoValue
 abstract class InternalTransactionContext implements TransactionContext {
 
-  public static InternalTransactionContext newInstance(Fork fork, HashCode hash,
-                                                       PublicKey authorPk, String serviceName,
-                                                       int serviceId) {
-    return new AutoValue_InternalTransactionContext(fork, hash, authorPk, serviceName, serviceId);
+  public static InternalTransactionContext newInstance(Fork fork, @Nullable HashCode hash,
+                                                       @Nullable PublicKey authorPk,
+                                                       String serviceName, int serviceId,
+                                                       CallerKind callerKind) {
+    return new AutoValue_InternalTransactionContext(fork, serviceName, serviceId, callerKind,
+        hash, authorPk);
+  }
+
+  @Nullable
+  abstract HashCode getNullableMessageHash();
+
+  @Nullable
+  abstract PublicKey getNullableAuthorPk();
+
+  @Override
+  public HashCode getTransactionMessageHash() {
+    checkTransactionCaller();
+    return getNullableMessageHash();
+  }
+
+  @Override
+  public PublicKey getAuthorPk() {
+    checkTransactionCaller();
+    return getNullableAuthorPk();
+  }
+
+  private void checkTransactionCaller() {
+    checkState(getCallerKind() == CallerKind.TRANSACTION,
+        "The method is invoked by %s, not by a transaction", getCallerKind());
   }
 }
@@ -17,6 +17,7 @@
 package com.exonum.binding.core.transaction;
 
 import static com.google.common.base.Preconditions.checkNotNull;
+import static com.google.common.base.Preconditions.checkState;
 
 import com.exonum.binding.common.crypto.CryptoFunctions;
 import com.exonum.binding.common.crypto.PublicKey;
@@ -43,6 +44,9 @@ public interface TransactionContext {
    * carried the payload from which the transaction was {@linkplain TransactionConverter created}.
    * Each transaction message is uniquely identified by its hash; the messages are persisted
    * in the {@linkplain Blockchain#getTxMessages() blockchain} and can be fetched by this hash.
+   *
+   * @throws IllegalStateException if the method is not invoked by a transaction
+   *     (see {@link #getCallerKind()})
    */
   HashCode getTransactionMessageHash();
 
@@ -50,9 +54,19 @@ public interface TransactionContext {
    * Returns public key of the transaction author. The corresponding transaction message
    * is guaranteed to have a correct {@link CryptoFunctions#ed25519()} signature
    * with this public key.
+   *
+   * @throws IllegalStateException if the method is not invoked by a transaction
+   *     (see {@link #getCallerKind()})
    */
   PublicKey getAuthorPk();
 
+  /**
+   * Returns the kind of the caller of the method. Only the methods invoked by transactions
+   * have the {@linkplain #getTransactionMessageHash() message hash} and
+   * the {@linkplain #getAuthorPk() author}.
+   */
+  CallerKind getCallerKind();
+
   /**
    * Returns the name of the service instance.
    *
@@ -83,6 +97,7 @@ public interface TransactionContext {
     private PublicKey authorPk;
     private String serviceName;
     private Integer serviceId;
+    private CallerKind callerKind = CallerKind.TRANSACTION;
 
     /**
      * Sets database fork for the context.
@@ -124,12 +139,27 @@ public interface TransactionContext {
       return this;
     }
 
+    /**
+     * Sets the kind of the caller for the context. The default is
+     * {@link CallerKind#TRANSACTION}.
+     */
+    public Builder callerKind(CallerKind callerKind) {
+      this.callerKind = checkNotNull(callerKind);
+      return this;
+    }
+
     /**
      * Creates the transaction context instance.
+     *
+     * @throws IllegalStateException if the message hash or the author are set for the context
+     *     of a method not invoked by a transaction
      */
     public TransactionContext build() {
+      checkState(callerKind == CallerKind.TRANSACTION || (hash == null && authorPk == null),
+          "The message hash and the author are only applicable to transactions, "
+              + "but the caller is %s", callerKind);
       return InternalTransactionContext.newInstance(fork, hash, authorPk, serviceName,
-          checkNotNull(serviceId));
+          checkNotNull(serviceId), callerKind);
     }
 
     private Builder() {}
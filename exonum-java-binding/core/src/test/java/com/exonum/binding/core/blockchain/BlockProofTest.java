@@ -0,0 +1,66 @@
+/*
+ * Copyright 2019 The Exonum Team
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+package com.exonum.binding.core.blockchain;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+
+import com.exonum.binding.common.hash.HashCode;
+import com.exonum.binding.core.blockchain.serialization.BlockSerializer;
+import com.exonum.binding.core.service.NodeProtos;
+import com.exonum.core.messages.Consensus.SignedMessage;
+import com.exonum.core.messages.Types;
+import com.google.protobuf.ByteString;
+import org.junit.jupiter.api.Test;
+
+class BlockProofTest {
+
+  @Test
+  void parseFrom() {
+    Block block = Blocks.withProperHash(Block.builder()
+        .proposerId(1)
+        .height(10)
+        .numTransactions(2)
+        .blockHash(HashCode.fromString("ab"))
+        .previousBlockHash(HashCode.fromString("bc"))
+        .txRootHash(HashCode.fromString("cd"))
+        .stateHash(HashCode.fromString("de"))
+        .build());
+    SignedMessage precommit = SignedMessage.newBuilder()
+        .setPayload(ByteString.copyFromUtf8("precommit"))
+        .setSignature(Types.Signature.newBuilder()
+            .setData(ByteString.copyFrom(new byte[64])))
+        .build();
+    byte[] blockProofBytes = NodeProtos.BlockProof.newBuilder()
+        .setBlock(ByteString.copyFrom(BlockSerializer.INSTANCE.toBytes(block)))
+        .addPrecommits(precommit.toByteString())
+        .build()
+        .toByteArray();
+
+    BlockProof blockProof = BlockProof.parseFrom(blockProofBytes);
+
+    assertThat(blockProof.getBlock()).isEqualTo(block);
+    assertThat(blockProof.getPrecommits()).containsExactly(precommit);
+  }
+
+  @Test
+  void parseFromInvalidMessage() {
+    byte[] invalidBlockProof = {1, 2, 3};
+
+    assertThrows(IllegalArgumentException.class, () -> BlockProof.parseFrom(invalidBlockProof));
+  }
+}
@@ -89,6 +89,11 @@ class CoreSchemaIntegrationTest {
     });
   }
 
+  @Test
+  void getTransactionPoolSize() {
+    assertSchema((schema) -> assertThat(schema.getTransactionPoolSize()).isZero());
+  }
+
   private static void assertSchema(Consumer<CoreSchema> assertion) {
     try (TemporaryDb db = TemporaryDb.newInstance(); Cleaner cleaner = new Cleaner()) {
       Snapshot view = db.createSnapshot(cleaner);
@@ -22,17 +22,31 @@ import static org.junit.jupiter.api.Assertions.assertThrows;
 import static org.junit.jupiter.api.Assertions.assertTrue;
 import static org.mockito.ArgumentMatchers.any;
 import static org.mockito.ArgumentMatchers.eq;
+import static org.mockito.Mockito.doAnswer;
 import static org.mockito.Mockito.mock;
 import static org.mockito.Mockito.verify;
 import static org.mockito.Mockito.when;
 
+import com.exonum.binding.common.crypto.PublicKey;
+import com.exonum.binding.common.hash.HashCode;
 import com.exonum.binding.core.proxy.Cleaner;
 import com.exonum.binding.core.proxy.CloseFailuresException;
+import com.exonum.binding.core.runtime.ServiceRuntimeProtos.AfterCommitResult;
+import com.exonum.binding.core.runtime.ServiceRuntimeProtos.BlockchainCaller;
+import com.exonum.binding.core.runtime.ServiceRuntimeProtos.ExecutionContext;
+import com.exonum.binding.core.runtime.ServiceRuntimeProtos.MailboxActions;
+import com.exonum.binding.core.runtime.ServiceRuntimeProtos.ServiceCallMetrics;
+import com.exonum.binding.core.runtime.ServiceRuntimeProtos.ServiceCaller;
+import com.exonum.binding.core.runtime.ServiceRuntimeProtos.StartDeploy;
+import com.exonum.binding.core.runtime.ServiceRuntimeProtos.TransactionCaller;
 import com.exonum.binding.core.service.BlockCommittedEvent;
 import com.exonum.binding.core.storage.database.Fork;
 import com.exonum.binding.core.storage.database.Snapshot;
+import com.exonum.binding.core.transaction.CallerKind;
 import com.exonum.core.messages.Runtime.ArtifactId;
 import com.exonum.core.messages.Runtime.InstanceSpec;
+import com.google.common.collect.ImmutableList;
+import com.google.protobuf.ByteString;
 import org.junit.jupiter.api.BeforeEach;
 import org.junit.jupiter.api.Test;
 import org.junit.jupiter.api.extension.ExtendWith;
@@ -46,6 +60,9 @@ class ServiceRuntimeAdapterTest {
   private static final long SNAPSHOT_HANDLE = 0x0A;
   private static final long HEIGHT = 1;
   private static final int VALIDATOR_ID = 1;
+  private static final int SERVICE_ID = 1;
+  private static final String INTERFACE_NAME = "";
+  private static final int METHOD_ID = 3;
 
   @Mock
   private ServiceRuntime serviceRuntime;
@@ -85,6 +102,15 @@ class ServiceRuntimeAdapterTest {
     assertTrue(serviceRuntimeAdapter.isArtifactDeployed(artifactName));
   }
 
+  @Test
+  void unloadArtifact() {
+    String artifactName = "com.acme:foo:1.3.2";
+
+    serviceRuntimeAdapter.unloadArtifact(artifactName);
+
+    verify(serviceRuntime).unloadArtifact(ServiceArtifactId.newJavaId(artifactName));
+  }
+
   @Test
   void deployArtifactWrongSpec() {
     String id = "com.acme:foo:1.2.3";
@@ -127,6 +153,96 @@ class ServiceRuntimeAdapterTest {
     verify(serviceRuntime).startAddingService(fork, expected, configuration);
   }
 
+  @Test
+  void executeTransactionByTransactionMessage() throws Exception {
+    long forkHandle = 0x110b;
+    Fork fork = mock(Fork.class);
+    when(viewFactory.createFork(eq(forkHandle), any(Cleaner.class)))
+        .thenReturn(fork);
+
+    HashCode txMessageHash = HashCode.fromBytes(bytes(0x01, 0x02));
+    PublicKey authorPk = PublicKey.fromBytes(bytes(0x03, 0x04));
+    byte[] arguments = bytes(1, 2, 3);
+    byte[] executionContext = executionContextBuilder(arguments)
+        .setTransaction(TransactionCaller.newBuilder()
+            .setMessageHash(ByteString.copyFrom(txMessageHash.asBytes()))
+            .setAuthorPk(ByteString.copyFrom(authorPk.toBytes())))
+        .build()
+        .toByteArray();
+
+    serviceRuntimeAdapter.executeTransaction(executionContext, forkHandle);
+
+    verify(serviceRuntime).executeTransaction(SERVICE_ID, INTERFACE_NAME, METHOD_ID, arguments,
+        fork, CallerKind.TRANSACTION, ServiceRuntimeAdapter.TRANSACTION_CALLER_ID, txMessageHash,
+        authorPk);
+  }
+
+  @Test
+  void executeTransactionByService() throws Exception {
+    long forkHandle = 0x110b;
+    Fork fork = mock(Fork.class);
+    when(viewFactory.createFork(eq(forkHandle), any(Cleaner.class)))
+        .thenReturn(fork);
+
+    int callerServiceId = 2;
+    byte[] arguments = bytes(1, 2, 3);
+    byte[] executionContext = executionContextBuilder(arguments)
+        .setService(ServiceCaller.newBuilder()
+            .setInstanceId(callerServiceId))
+        .build()
+        .toByteArray();
+
+    serviceRuntimeAdapter.executeTransaction(executionContext, forkHandle);
+
+    verify(serviceRuntime).executeTransaction(SERVICE_ID, INTERFACE_NAME, METHOD_ID, arguments,
+        fork, CallerKind.SERVICE, callerServiceId, null, null);
+  }
+
+  @Test
+  void executeTransactionByBlockchain() throws Exception {
+    long forkHandle = 0x110b;
+    Fork fork = mock(Fork.class);
+    when(viewFactory.createFork(eq(forkHandle), any(Cleaner.class)))
+        .thenReturn(fork);
+
+    byte[] arguments = bytes(1, 2, 3);
+    byte[] executionContext = executionContextBuilder(arguments)
+        .setBlockchain(BlockchainCaller.getDefaultInstance())
+        .build()
+        .toByteArray();
+
+    serviceRuntimeAdapter.executeTransaction(executionContext, forkHandle);
+
+    verify(serviceRuntime).executeTransaction(SERVICE_ID, INTERFACE_NAME, METHOD_ID, arguments,
+        fork, CallerKind.BLOCKCHAIN, ServiceRuntime.BLOCKCHAIN_CALLER_ID, null, null);
+  }
+
+  @Test
+  void executeTransactionNoCaller() {
+    byte[] executionContext = executionContextBuilder(bytes(1, 2, 3))
+        .build()
+        .toByteArray();
+
+    assertThrows(IllegalArgumentException.class,
+        () -> serviceRuntimeAdapter.executeTransaction(executionContext, 0x110b));
+  }
+
+  @Test
+  void executeTransactionInvalidContext() {
+    byte[] executionContext = bytes("Some rubbish");
+
+    assertThrows(IllegalArgumentException.class,
+        () -> serviceRuntimeAdapter.executeTransaction(executionContext, 0x110b));
+  }
+
+  private static ExecutionContext.Builder executionContextBuilder(byte[] arguments) {
+    return ExecutionContext.newBuilder()
+        .setServiceId(SERVICE_ID)
+        .setInterfaceName(INTERFACE_NAME)
+        .setMethodId(METHOD_ID)
+        .setArguments(ByteString.copyFrom(arguments));
+  }
+
   @Test
   void beforeCommit() throws CloseFailuresException {
     int serviceId = 1;
@@ -172,4 +288,44 @@ class ServiceRuntimeAdapterTest {
     assertThat(event.getHeight()).isEqualTo(HEIGHT);
     assertThat(event.getValidatorId()).isEmpty();
   }
+
+  @Test
+  void afterCommitReturnsRequestedActions() throws Exception {
+    when(viewFactory.createSnapshot(eq(SNAPSHOT_HANDLE), any(Cleaner.class)))
+        .thenReturn(snapshot);
+    ServiceArtifactId artifactId = ServiceArtifactId.newJavaId("com.acme:foo:1.2.3");
+    byte[] deploySpec = bytes(1, 2);
+    doAnswer(invocation -> {
+      BlockCommittedEvent event = invocation.getArgument(0);
+      event.getMailbox().requestArtifactDeploy(artifactId, deploySpec);
+      return null;
+    }).when(serviceRuntime).afterCommit(any(BlockCommittedEvent.class));
+
+    byte[] resultBytes = serviceRuntimeAdapter.afterCommit(SNAPSHOT_HANDLE, VALIDATOR_ID, HEIGHT);
+
+    MailboxActions actions = AfterCommitResult.parseFrom(resultBytes).getActions();
+    assertThat(actions.getActionsCount()).isEqualTo(1);
+    StartDeploy startDeploy = actions.getActions(0).getStartDeploy();
+    assertThat(startDeploy.getRuntimeId()).isEqualTo(artifactId.getRuntimeId());
+    assertThat(startDeploy.getArtifactName()).isEqualTo(artifactId.getName());
+    assertThat(startDeploy.getDeploySpec().toByteArray()).isEqualTo(deploySpec);
+  }
+
+  @Test
+  void afterCommitReturnsServiceCallMetrics() throws Exception {
+    when(viewFactory.createSnapshot(eq(SNAPSHOT_HANDLE), any(Cleaner.class)))
+        .thenReturn(snapshot);
+    ServiceCallMetrics serviceCall = ServiceCallMetrics.newBuilder()
+        .setInstanceId(1)
+        .setDurationNanos(1000)
+        .setFailed(true)
+        .build();
+    when(serviceRuntime.afterCommit(any(BlockCommittedEvent.class)))
+        .thenReturn(ImmutableList.of(serviceCall));
+
+    byte[] resultBytes = serviceRuntimeAdapter.afterCommit(SNAPSHOT_HANDLE, VALIDATOR_ID, HEIGHT);
+
+    AfterCommitResult result = AfterCommitResult.parseFrom(resultBytes);
+    assertThat(result.getServiceCallsList()).isEqualTo(ImmutableList.of(serviceCall));
+  }
 }
@@ -36,6 +36,7 @@ import com.exonum.binding.common.crypto.PublicKey;
 import com.exonum.binding.common.hash.HashCode;
 import com.exonum.binding.core.proxy.Cleaner;
 import com.exonum.binding.core.proxy.CloseFailuresException;
+import com.exonum.binding.core.runtime.ServiceRuntimeProtos.ServiceCallMetrics;
 import com.exonum.binding.core.runtime.ServiceRuntimeProtos.ServiceRuntimeStateHashes;
 import com.exonum.binding.core.runtime.ServiceRuntimeProtos.ServiceStateHashes;
 import com.exonum.binding.core.service.BlockCommittedEvent;
@@ -45,6 +46,7 @@ import com.exonum.binding.core.storage.database.Database;
 import com.exonum.binding.core.storage.database.Fork;
 import com.exonum.binding.core.storage.database.Snapshot;
 import com.exonum.binding.core.storage.database.TemporaryDb;
+import com.exonum.binding.core.transaction.CallerKind;
 import com.exonum.binding.core.transaction.TransactionContext;
 import com.google.common.collect.ImmutableMap;
 import com.google.protobuf.ByteString;
@@ -160,6 +162,15 @@ class ServiceRuntimeIntegrationTest {
     assertFalse(serviceRuntime.isArtifactDeployed(serviceId));
   }
 
+  @Test
+  void unloadArtifact() {
+    ServiceArtifactId serviceId = ServiceArtifactId.newJavaId("com.acme:foo-service:1.0.0");
+
+    serviceRuntime.unloadArtifact(serviceId);
+
+    verify(serviceLoader).unloadService(serviceId);
+  }
+
   @Test
   void startAddingService() {
     Node node = mock(Node.class);
@@ -381,7 +392,7 @@ class ServiceRuntimeIntegrationTest {
             .build();
 
         serviceRuntime.executeTransaction(TEST_ID, interfaceName, txId, arguments, fork,
-            callerServiceId, TEST_HASH, TEST_PUBLIC_KEY);
+            CallerKind.TRANSACTION, callerServiceId, TEST_HASH, TEST_PUBLIC_KEY);
 
         verify(serviceWrapper).executeTransaction(interfaceName, txId, arguments,
             callerServiceId, expectedContext);
@@ -409,7 +420,7 @@ class ServiceRuntimeIntegrationTest {
 
         Exception e = assertThrows(IllegalArgumentException.class,
             () -> serviceRuntime.executeTransaction(serviceId, DEFAULT_INTERFACE_NAME, txId,
-                arguments, fork, 0, TEST_HASH, TEST_PUBLIC_KEY));
+                arguments, fork, CallerKind.TRANSACTION, 0, TEST_HASH, TEST_PUBLIC_KEY));
 
         assertThat(e).hasMessageContaining(String.valueOf(serviceId));
       }
@@ -447,7 +458,11 @@ class ServiceRuntimeIntegrationTest {
             .build();
 
         ServiceRuntimeStateHashes runtimeStateHashes = serviceRuntime.getStateHashes(s);
-        assertThat(runtimeStateHashes).isEqualTo(expected);
+        assertThat(runtimeStateHashes.getServiceStateHashesList())
+            .isEqualTo(expected.getServiceStateHashesList());
+        assertThat(runtimeStateHashes.getServiceCallsList())
+            .extracting(ServiceCallMetrics::getInstanceId)
+            .containsExactly(TEST_ID);
       }
     }
 
@@ -483,9 +498,13 @@ class ServiceRuntimeIntegrationTest {
     void afterCommitSingleService() {
       BlockCommittedEvent event = mock(BlockCommittedEvent.class);
 
-      serviceRuntime.afterCommit(event);
+      List<ServiceCallMetrics> serviceCalls = serviceRuntime.afterCommit(event);
 
       verify(serviceWrapper).afterCommit(event);
+      assertThat(serviceCalls).hasSize(1);
+      ServiceCallMetrics serviceCall = serviceCalls.get(0);
+      assertThat(serviceCall.getInstanceId()).isEqualTo(TEST_ID);
+      assertFalse(serviceCall.getFailed());
     }
 
     @Test
@@ -495,9 +514,28 @@ class ServiceRuntimeIntegrationTest {
           .afterCommit(event);
 
       // Notify of block commit event — the service runtime must swallow the exception
-      serviceRuntime.afterCommit(event);
+      List<ServiceCallMetrics> serviceCalls = serviceRuntime.afterCommit(event);
 
       verify(serviceWrapper).afterCommit(event);
+      assertThat(serviceCalls).hasSize(1);
+      assertTrue(serviceCalls.get(0).getFailed());
+    }
+
+    @Test
+    void afterCommitFailuresAreCounted() {
+      BlockCommittedEvent event = mock(BlockCommittedEvent.class);
+      doThrow(RuntimeException.class).when(serviceWrapper)
+          .afterCommit(event);
+
+      serviceRuntime.afterCommit(event);
+      serviceRuntime.afterCommit(event);
+
+      assertThat(serviceRuntime.getAfterCommitFailures(TEST_ID)).isEqualTo(2L);
+    }
+
+    @Test
+    void afterCommitFailuresOfUnknownService() {
+      assertThat(serviceRuntime.getAfterCommitFailures(TEST_ID + 1)).isZero();
     }
   }
 
@@ -576,7 +614,15 @@ class ServiceRuntimeIntegrationTest {
         // Request the state hashes
         ServiceRuntimeStateHashes runtimeStateHashes = serviceRuntime.getStateHashes(s);
         ServiceRuntimeStateHashes expectedStateHashes = expectedBuilder.build();
-        assertThat(runtimeStateHashes).isEqualTo(expectedStateHashes);
+        assertThat(runtimeStateHashes.getServiceStateHashesList())
+            .isEqualTo(expectedStateHashes.getServiceStateHashesList());
+        // The metrics of each service must follow the order of the state hashes
+        List<Integer> expectedIds = expectedStateHashes.getServiceStateHashesList().stream()
+            .map(ServiceStateHashes::getInstanceId)
+            .collect(toList());
+        assertThat(runtimeStateHashes.getServiceCallsList())
+            .extracting(ServiceCallMetrics::getInstanceId)
+            .isEqualTo(expectedIds);
       }
     }
 
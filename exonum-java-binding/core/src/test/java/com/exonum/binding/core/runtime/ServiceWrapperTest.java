@@ -16,6 +16,7 @@
 
 package com.exonum.binding.core.runtime;
 
+import static com.exonum.binding.core.runtime.ServiceRuntime.BLOCKCHAIN_CALLER_ID;
 import static com.exonum.binding.core.runtime.ServiceWrapper.APPLY_CONFIGURATION_TX_ID;
 import static com.exonum.binding.core.runtime.ServiceWrapper.CONFIGURE_INTERFACE_NAME;
 import static com.exonum.binding.core.runtime.ServiceWrapper.DEFAULT_INTERFACE_NAME;
@@ -39,6 +40,7 @@ import com.exonum.binding.core.service.Node;
 import com.exonum.binding.core.service.Service;
 import com.exonum.binding.core.service.TransactionConverter;
 import com.exonum.binding.core.storage.database.Fork;
+import com.exonum.binding.core.transaction.CallerKind;
 import com.exonum.binding.core.transaction.Transaction;
 import com.exonum.binding.core.transaction.TransactionContext;
 import com.exonum.binding.core.transaction.TransactionExecutionException;
@@ -116,7 +118,7 @@ class ServiceWrapperTest {
     byte[] arguments = bytes(1, 2, 3);
 
     Fork fork = mock(Fork.class);
-    TransactionContext context = anyContext()
+    TransactionContext context = supervisorContext()
         .fork(fork)
         .build();
 
@@ -134,7 +136,7 @@ class ServiceWrapperTest {
     byte[] arguments = bytes(1, 2, 3);
 
     Fork fork = mock(Fork.class);
-    TransactionContext context = anyContext()
+    TransactionContext context = supervisorContext()
         .fork(fork)
         .build();
 
@@ -145,15 +147,33 @@ class ServiceWrapperTest {
     verify(service).applyConfiguration(fork, expected);
   }
 
+  @Test
+  void executeConfigurableOperationByBlockchain() throws TransactionExecutionException {
+    String interfaceName = CONFIGURE_INTERFACE_NAME;
+    int txId = APPLY_CONFIGURATION_TX_ID;
+    byte[] arguments = bytes(1, 2, 3);
+
+    Fork fork = mock(Fork.class);
+    TransactionContext context = serviceCallContext(CallerKind.BLOCKCHAIN)
+        .fork(fork)
+        .build();
+
+    serviceWrapper.executeTransaction(interfaceName, txId, arguments, BLOCKCHAIN_CALLER_ID,
+        context);
+
+    Configuration expected = new ServiceConfiguration(arguments);
+    verify(service).applyConfiguration(fork, expected);
+  }
+
   @ParameterizedTest
-  @ValueSource(ints = {-1, 1, 2})
+  @ValueSource(ints = {1, 2})
   void executeConfigurableOperationInvalidCallerId(int callerServiceId) {
     String interfaceName = CONFIGURE_INTERFACE_NAME;
     int txId = VERIFY_CONFIGURATION_TX_ID;
     byte[] arguments = bytes(1, 2, 3);
 
     Fork fork = mock(Fork.class);
-    TransactionContext context = anyContext()
+    TransactionContext context = serviceCallContext(CallerKind.SERVICE)
         .fork(fork)
         .build();
 
@@ -161,18 +181,35 @@ class ServiceWrapperTest {
         () -> serviceWrapper.executeTransaction(interfaceName, txId, arguments, callerServiceId,
             context));
 
-    assertThat(e.getMessage()).containsIgnoringCase("Invalid caller service id")
+    assertThat(e.getMessage()).containsIgnoringCase("Invalid caller")
         .contains(Integer.toString(callerServiceId))
         .contains(Integer.toString(SUPERVISOR_SERVICE_ID));
   }
 
+  @Test
+  void executeConfigurableOperationByTransaction() {
+    String interfaceName = CONFIGURE_INTERFACE_NAME;
+    int txId = VERIFY_CONFIGURATION_TX_ID;
+    byte[] arguments = bytes(1, 2, 3);
+    TransactionContext context = anyContext().build();
+
+    // A transaction must not be able to invoke the operations even if the caller service id
+    // matches the supervisor id
+    Exception e = assertThrows(IllegalArgumentException.class,
+        () -> serviceWrapper.executeTransaction(interfaceName, txId, arguments,
+            SUPERVISOR_SERVICE_ID, context));
+
+    assertThat(e.getMessage()).containsIgnoringCase("Invalid caller")
+        .contains(CallerKind.TRANSACTION.toString());
+  }
+
   @ParameterizedTest
   @ValueSource(ints = {-1, 2, 3})
   void executeUnknownConfigurableMethod(int txId) {
     String interfaceName = CONFIGURE_INTERFACE_NAME;
     byte[] arguments = bytes(1, 2, 3);
 
-    TransactionContext context = anyContext().build();
+    TransactionContext context = supervisorContext().build();
 
     IllegalArgumentException e = assertThrows(IllegalArgumentException.class,
         () -> serviceWrapper.executeTransaction(interfaceName, txId, arguments,
@@ -189,7 +226,7 @@ class ServiceWrapperTest {
     String interfaceName = CONFIGURE_INTERFACE_NAME;
     int txId = VERIFY_CONFIGURATION_TX_ID;
     byte[] arguments = bytes(1, 2, 3);
-    TransactionContext context = anyContext().build();
+    TransactionContext context = supervisorContext().build();
 
     Exception e = assertThrows(IllegalArgumentException.class,
         () -> serviceWrapper.executeTransaction(interfaceName, txId, arguments,
@@ -262,5 +299,17 @@ class ServiceWrapperTest {
         .fork(mock(Fork.class));
   }
 
+  private static TransactionContext.Builder supervisorContext() {
+    return serviceCallContext(CallerKind.SERVICE);
+  }
+
+  private static TransactionContext.Builder serviceCallContext(CallerKind callerKind) {
+    return TransactionContext.builder()
+        .serviceName(TEST_SERVICE_NAME)
+        .serviceId(TEST_SERVICE_ID)
+        .callerKind(callerKind)
+        .fork(mock(Fork.class));
+  }
+
   private interface ConfigurableService extends Service, Configurable {}
 }
@@ -0,0 +1,37 @@
+/*
+ * Copyright 2019 The Exonum Team
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+package com.exonum.binding.core.service;
+
+import static org.mockito.Mockito.mock;
+import static org.mockito.Mockito.verify;
+
+import com.exonum.binding.common.hash.HashCode;
+import org.junit.jupiter.api.Test;
+
+class NativeBlockCommitListenerTest {
+
+  @Test
+  void onBlockCommittedNotifiesListener() {
+    BlockCommitListener listener = mock(BlockCommitListener.class);
+    NativeBlockCommitListener nativeListener = new NativeBlockCommitListener(listener);
+    HashCode blockHash = HashCode.fromString("a0b1");
+
+    nativeListener.onBlockCommitted(10L, blockHash.asBytes());
+
+    verify(listener).onBlockCommitted(10L, blockHash);
+  }
+}
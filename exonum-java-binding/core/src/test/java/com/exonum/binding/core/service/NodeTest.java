@@ -0,0 +1,154 @@
+/*
+ * Copyright 2019 The Exonum Team
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+package com.exonum.binding.core.service;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+import static org.mockito.Mockito.CALLS_REAL_METHODS;
+import static org.mockito.Mockito.mock;
+import static org.mockito.Mockito.when;
+
+import com.exonum.binding.common.crypto.CryptoFunction;
+import com.exonum.binding.common.crypto.CryptoFunctions;
+import com.exonum.binding.common.crypto.KeyPair;
+import com.exonum.binding.common.crypto.PublicKey;
+import com.exonum.binding.common.hash.HashCode;
+import com.exonum.binding.core.service.TransactionSubmissionException.ErrorCode;
+import com.exonum.binding.core.transaction.RawTransaction;
+import com.exonum.core.messages.Blockchain.Config;
+import com.exonum.core.messages.Blockchain.ValidatorKeys;
+import com.exonum.core.messages.Types;
+import com.google.common.collect.ImmutableList;
+import com.google.protobuf.ByteString;
+import java.util.List;
+import org.junit.jupiter.api.Test;
+
+class NodeTest {
+
+  @Test
+  void submitTransactionsSubmitsEachTransaction() {
+    Node node = mock(Node.class, CALLS_REAL_METHODS);
+    RawTransaction tx1 = rawTransaction(1);
+    RawTransaction tx2 = rawTransaction(2);
+    RawTransaction tx3 = rawTransaction(3);
+    HashCode hash1 = HashCode.fromInt(1);
+    HashCode hash3 = HashCode.fromInt(3);
+    String errorDescription = "Service 2 is not active";
+    when(node.submitTransaction(tx1)).thenReturn(hash1);
+    when(node.submitTransaction(tx2))
+        .thenThrow(new TransactionSubmissionException(ErrorCode.INACTIVE_SERVICE,
+            errorDescription));
+    when(node.submitTransaction(tx3)).thenReturn(hash3);
+
+    List<TransactionSubmissionResult> results =
+        node.submitTransactions(ImmutableList.of(tx1, tx2, tx3));
+
+    assertThat(results).containsExactly(
+        TransactionSubmissionResult.success(hash1),
+        TransactionSubmissionResult.failure(ErrorCode.INACTIVE_SERVICE, errorDescription),
+        TransactionSubmissionResult.success(hash3));
+  }
+
+  @Test
+  void submitTransactionsEmptyList() {
+    Node node = mock(Node.class, CALLS_REAL_METHODS);
+
+    List<TransactionSubmissionResult> results = node.submitTransactions(ImmutableList.of());
+
+    assertThat(results).isEmpty();
+  }
+
+  @Test
+  void verifyChecksSignatureWithPublicKey() {
+    CryptoFunction crypto = CryptoFunctions.ed25519();
+    KeyPair keyPair = crypto.generateKeyPair();
+    Node node = mock(Node.class, CALLS_REAL_METHODS);
+    when(node.getPublicKey()).thenReturn(keyPair.getPublicKey());
+    byte[] message = {1, 2, 3};
+    byte[] signature = crypto.signMessage(message, keyPair.getPrivateKey());
+
+    assertThat(node.verify(message, signature)).isTrue();
+    assertThat(node.verify(new byte[] {1, 2, 4}, signature)).isFalse();
+  }
+
+  @Test
+  void signIsUnsupportedByDefault() {
+    Node node = mock(Node.class, CALLS_REAL_METHODS);
+
+    assertThrows(UnsupportedOperationException.class, () -> node.sign(new byte[] {1, 2, 3}));
+  }
+
+  @Test
+  void submitSignedTransactionIsUnsupportedByDefault() {
+    Node node = mock(Node.class, CALLS_REAL_METHODS);
+
+    assertThrows(UnsupportedOperationException.class,
+        () -> node.submitSignedTransaction(new byte[] {1, 2, 3}));
+  }
+
+  @Test
+  void blockCommitListenersAreUnsupportedByDefault() {
+    Node node = mock(Node.class, CALLS_REAL_METHODS);
+    BlockCommitListener listener = (height, blockHash) -> { };
+
+    assertThrows(UnsupportedOperationException.class,
+        () -> node.addBlockCommitListener(listener));
+    assertThrows(UnsupportedOperationException.class,
+        () -> node.removeBlockCommitListener(listener));
+  }
+
+  @Test
+  void getValidatorIdOfValidator() {
+    Node node = mock(Node.class, CALLS_REAL_METHODS);
+    PublicKey serviceKey = PublicKey.fromHexString("ab02");
+    when(node.getPublicKey()).thenReturn(serviceKey);
+    when(node.getConsensusConfiguration()).thenReturn(configWithServiceKeys(
+        PublicKey.fromHexString("ab01"), serviceKey));
+
+    assertThat(node.getValidatorId()).hasValue(1);
+    assertThat(node.isValidator()).isTrue();
+  }
+
+  @Test
+  void getValidatorIdOfAuditor() {
+    Node node = mock(Node.class, CALLS_REAL_METHODS);
+    when(node.getPublicKey()).thenReturn(PublicKey.fromHexString("ab03"));
+    when(node.getConsensusConfiguration()).thenReturn(configWithServiceKeys(
+        PublicKey.fromHexString("ab01"), PublicKey.fromHexString("ab02")));
+
+    assertThat(node.getValidatorId()).isEmpty();
+    assertThat(node.isValidator()).isFalse();
+  }
+
+  private static Config configWithServiceKeys(PublicKey... serviceKeys) {
+    Config.Builder config = Config.newBuilder();
+    for (PublicKey serviceKey : serviceKeys) {
+      config.addValidatorKeys(ValidatorKeys.newBuilder()
+          .setServiceKey(Types.PublicKey.newBuilder()
+              .setData(ByteString.copyFrom(serviceKey.toBytes()))));
+    }
+    return config.build();
+  }
+
+  private static RawTransaction rawTransaction(int serviceId) {
+    return RawTransaction.newBuilder()
+        .serviceId(serviceId)
+        .transactionId(0)
+        .payload(new byte[] {1, 2, 3})
+        .build();
+  }
+}
@@ -0,0 +1,64 @@
+/*
+ * Copyright 2019 The Exonum Team
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+package com.exonum.binding.core.service;
+
+import static org.assertj.core.api.Assertions.assertThat;
+
+import com.exonum.binding.common.blockchain.ExecutionStatuses;
+import com.exonum.binding.common.blockchain.TransactionLocation;
+import com.exonum.binding.core.service.TransactionStatus.Type;
+import com.exonum.core.messages.Runtime.ExecutionStatus;
+import org.junit.jupiter.api.Test;
+
+class TransactionStatusTest {
+
+  private static final TransactionLocation LOCATION = TransactionLocation.valueOf(10, 1);
+
+  @Test
+  void committedSuccessfully() {
+    ExecutionStatus executionStatus = ExecutionStatuses.success();
+
+    TransactionStatus status = TransactionStatus.committed(LOCATION, executionStatus);
+
+    assertThat(status.getType()).isEqualTo(Type.COMMITTED);
+    assertThat(status.getLocation()).hasValue(LOCATION);
+    assertThat(status.getExecutionError()).isEmpty();
+    assertThat(status.isCommittedSuccessfully()).isTrue();
+  }
+
+  @Test
+  void committedWithError() {
+    ExecutionStatus executionStatus = ExecutionStatuses.serviceError(1, "Insufficient balance");
+
+    TransactionStatus status = TransactionStatus.committed(LOCATION, executionStatus);
+
+    assertThat(status.getType()).isEqualTo(Type.COMMITTED);
+    assertThat(status.getLocation()).hasValue(LOCATION);
+    assertThat(status.getExecutionError()).hasValue(executionStatus.getError());
+    assertThat(status.isCommittedSuccessfully()).isFalse();
+  }
+
+  @Test
+  void inPool() {
+    TransactionStatus status = TransactionStatus.inPool();
+
+    assertThat(status.getType()).isEqualTo(Type.IN_POOL);
+    assertThat(status.getLocation()).isEmpty();
+    assertThat(status.getExecutionError()).isEmpty();
+    assertThat(status.isCommittedSuccessfully()).isFalse();
+  }
+}
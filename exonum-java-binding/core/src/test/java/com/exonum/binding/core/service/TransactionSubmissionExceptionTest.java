@@ -0,0 +1,50 @@
+/*
+ * Copyright 2019 The Exonum Team
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+package com.exonum.binding.core.service;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+
+import com.exonum.binding.core.service.TransactionSubmissionException.ErrorCode;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.params.ParameterizedTest;
+import org.junit.jupiter.params.provider.EnumSource;
+
+class TransactionSubmissionExceptionTest {
+
+  @Test
+  void createWithMessageOnly() {
+    TransactionSubmissionException e = new TransactionSubmissionException("Failed");
+
+    assertThat(e.getErrorCode()).isEqualTo(ErrorCode.UNSPECIFIED);
+    assertThat(e).hasMessage("Failed");
+  }
+
+  @ParameterizedTest
+  @EnumSource(ErrorCode.class)
+  void createWithNativeCode(ErrorCode errorCode) {
+    TransactionSubmissionException e =
+        new TransactionSubmissionException(errorCode.getCode(), "Failed");
+
+    assertThat(e.getErrorCode()).isEqualTo(errorCode);
+  }
+
+  @Test
+  void errorCodeFromUnknownCode() {
+    assertThrows(IllegalArgumentException.class, () -> ErrorCode.fromCode(-1));
+  }
+}
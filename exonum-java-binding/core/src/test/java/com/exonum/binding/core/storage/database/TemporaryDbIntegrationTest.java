@@ -27,6 +27,8 @@ import static org.junit.jupiter.api.Assertions.assertThrows;
 import com.exonum.binding.common.serialization.StandardSerializers;
 import com.exonum.binding.core.proxy.Cleaner;
 import com.exonum.binding.core.proxy.CloseFailuresException;
+import com.exonum.binding.core.storage.indices.IndexAccessException;
+import com.exonum.binding.core.storage.indices.IndexAddress;
 import com.exonum.binding.core.storage.indices.ListIndex;
 import com.exonum.binding.core.storage.indices.ListIndexProxy;
 import com.exonum.binding.core.storage.indices.MapIndex;
@@ -34,6 +36,7 @@ import com.exonum.binding.core.storage.indices.MapIndexProxy;
 import com.exonum.binding.core.storage.indices.TestStorageItems;
 import com.exonum.binding.test.RequiresNativeLibrary;
 import java.util.List;
+import java.util.Optional;
 import org.junit.jupiter.api.Test;
 
 @RequiresNativeLibrary
@@ -159,6 +162,29 @@ class TemporaryDbIntegrationTest {
     }
   }
 
+  @Test
+  void accessingIndexOfAnotherTypeFails() throws CloseFailuresException {
+    try (TemporaryDb db = TemporaryDb.newInstance();
+        Cleaner cleaner = new Cleaner()) {
+      String name = "list";
+
+      // Create a list in the database
+      Fork fork = db.createFork(cleaner);
+      ListIndex<String> list = newList(name, fork);
+      list.add(V1);
+      db.merge(fork);
+
+      // Check it cannot be accessed as a map
+      Snapshot snapshot = db.createSnapshot(cleaner);
+      IndexAccessException e = assertThrows(IndexAccessException.class,
+          () -> newMap(name, snapshot));
+
+      assertThat(e.getAddress(), equalTo(IndexAddress.valueOf(name)));
+      assertThat(e.getExpectedType(), equalTo(Optional.of("Map")));
+      assertThat(e.getActualType(), equalTo(Optional.of("List")));
+    }
+  }
+
   private static ListIndex<String> newList(String name, View view) {
     return ListIndexProxy.newInstance(name, view, StandardSerializers.string());
   }
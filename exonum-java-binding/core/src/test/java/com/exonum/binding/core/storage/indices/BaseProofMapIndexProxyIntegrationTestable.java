@@ -163,6 +163,23 @@ abstract class BaseProofMapIndexProxyIntegrationTestable
     });
   }
 
+  @Test
+  void getAllReturnsPresentMappings() {
+    runTestWithView(database::createFork, (map) -> {
+      map.putAll(ImmutableMap.of(
+          key1, V1,
+          key3, V3
+      ));
+
+      Map<HashCode, String> values = map.getAll(ImmutableList.of(key1, key2, key3));
+
+      assertThat(values, equalTo(ImmutableMap.of(
+          key1, V1,
+          key3, V3
+      )));
+    });
+  }
+
   @Test
   void get() {
     runTestWithView(database::createFork, (map) -> {
@@ -324,6 +341,24 @@ abstract class BaseProofMapIndexProxyIntegrationTestable
     });
   }
 
+  @Test
+  void removeAll() {
+    runTestWithView(database::createFork, (map) -> {
+      map.putAll(ImmutableMap.of(
+          key1, V1,
+          key2, V2,
+          key3, V3
+      ));
+
+      map.removeAll(ImmutableList.of(key1, key2));
+
+      assertFalse(map.containsKey(key1));
+      assertFalse(map.containsKey(key2));
+      assertThat(map.get(key3), equalTo(V3));
+      assertThat(map, provesThatCorrect(absentEntry(key1), presentEntry(key3, V3)));
+    });
+  }
+
   @Test
   void removeFailsIfSnapshot() {
     runTestWithView(database::createSnapshot,
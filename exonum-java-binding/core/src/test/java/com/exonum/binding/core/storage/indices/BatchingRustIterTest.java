@@ -0,0 +1,118 @@
+/*
+ * Copyright 2019 The Exonum Team
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+package com.exonum.binding.core.storage.indices;
+
+import static com.exonum.binding.test.Bytes.bytes;
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+import static org.mockito.ArgumentMatchers.anyInt;
+import static org.mockito.ArgumentMatchers.anyLong;
+import static org.mockito.ArgumentMatchers.eq;
+import static org.mockito.Mockito.mock;
+import static org.mockito.Mockito.times;
+import static org.mockito.Mockito.verify;
+import static org.mockito.Mockito.when;
+
+import com.exonum.binding.core.proxy.NativeHandle;
+import com.exonum.binding.core.storage.indices.BatchingRustIter.NextBatchFunction;
+import com.google.common.collect.ImmutableList;
+import java.util.ConcurrentModificationException;
+import java.util.List;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.Test;
+
+class BatchingRustIterTest {
+
+  private static final int INITIAL_MOD_COUNT = 11;
+
+  private static final long DEFAULT_NATIVE_HANDLE = 0x05;
+
+  private static final byte[][] EMPTY_BATCH = new byte[0][];
+
+  private ModificationCounter modCounter;
+
+  private NextBatchFunction nextBatchFunction;
+
+  @BeforeEach
+  void setUp() {
+    modCounter = mock(ModificationCounter.class);
+    when(modCounter.getCurrentValue())
+        .thenReturn(INITIAL_MOD_COUNT);
+    nextBatchFunction = mock(NextBatchFunction.class);
+  }
+
+  @Test
+  void nextGoesThroughAllBatches() {
+    when(nextBatchFunction.nextBatch(anyLong(), anyInt(), anyInt()))
+        .thenReturn(new byte[][] {bytes(1), bytes(2)})
+        .thenReturn(new byte[][] {bytes(3)})
+        .thenReturn(EMPTY_BATCH);
+    BatchingRustIter<byte[]> iter = createIter(1, (batch, i) -> batch[i]);
+
+    List<byte[]> elements = ImmutableList.copyOf(new RustIterAdapter<>(iter));
+
+    assertThat(elements).containsExactly(bytes(1), bytes(2), bytes(3));
+    verify(nextBatchFunction, times(3)).nextBatch(eq(DEFAULT_NATIVE_HANDLE),
+        eq(BatchingRustIter.MAX_BATCH_SIZE), eq(BatchingRustIter.MAX_BATCH_BYTES));
+  }
+
+  @Test
+  void nextDecodesElementsOfSeveralParts() {
+    when(nextBatchFunction.nextBatch(anyLong(), anyInt(), anyInt()))
+        .thenReturn(new byte[][] {bytes(1), bytes(2), bytes(3), bytes(4)})
+        .thenReturn(EMPTY_BATCH);
+    BatchingRustIter<String> iter = createIter(2,
+        (batch, i) -> batch[i][0] + ":" + batch[i + 1][0]);
+
+    List<String> elements = ImmutableList.copyOf(new RustIterAdapter<>(iter));
+
+    assertThat(elements).containsExactly("1:2", "3:4");
+  }
+
+  @Test
+  void nextDoesNotFetchAfterExhausted() {
+    when(nextBatchFunction.nextBatch(anyLong(), anyInt(), anyInt()))
+        .thenReturn(EMPTY_BATCH);
+    BatchingRustIter<byte[]> iter = createIter(1, (batch, i) -> batch[i]);
+
+    assertThat(iter.next()).isEmpty();
+    assertThat(iter.next()).isEmpty();
+
+    verify(nextBatchFunction, times(1)).nextBatch(anyLong(), anyInt(), anyInt());
+  }
+
+  @Test
+  void nextFailsIfModifiedAfterFirstNext() {
+    when(nextBatchFunction.nextBatch(anyLong(), anyInt(), anyInt()))
+        .thenReturn(new byte[][] {bytes(1), bytes(2)});
+    BatchingRustIter<byte[]> iter = createIter(1, (batch, i) -> batch[i]);
+
+    iter.next();  // 1st must succeed
+
+    when(modCounter.isModifiedSince(eq(INITIAL_MOD_COUNT)))
+        .thenReturn(true);
+
+    // The second element is already fetched, but must not be returned
+    assertThrows(ConcurrentModificationException.class, iter::next);
+  }
+
+  private <E> BatchingRustIter<E> createIter(int elementLength,
+      BatchingRustIter.ElementDecoder<E> decoder) {
+    NativeHandle nh = new NativeHandle(DEFAULT_NATIVE_HANDLE);
+    return new BatchingRustIter<>(nh, nextBatchFunction, elementLength, decoder, modCounter);
+  }
+}
@@ -19,6 +19,7 @@ package com.exonum.binding.core.storage.indices;
 import static com.exonum.binding.core.storage.indices.MapEntries.putAll;
 import static com.exonum.binding.core.storage.indices.TestStorageItems.K1;
 import static com.exonum.binding.core.storage.indices.TestStorageItems.K2;
+import static com.exonum.binding.core.storage.indices.TestStorageItems.K3;
 import static com.exonum.binding.core.storage.indices.TestStorageItems.V1;
 import static com.exonum.binding.core.storage.indices.TestStorageItems.V2;
 import static com.exonum.binding.core.storage.indices.TestStorageItems.V3;
@@ -195,6 +196,41 @@ class MapIndexProxyIntegrationTest
     });
   }
 
+  @Test
+  void putAllShouldFailWithSnapshot() {
+    runTestWithView(database::createSnapshot, (map) -> {
+      assertThrows(UnsupportedOperationException.class,
+          () -> map.putAll(ImmutableMap.of(K1, V1)));
+    });
+  }
+
+  @Test
+  void getAllReturnsPresentMappings() {
+    runTestWithView(database::createFork, (map) -> {
+      map.putAll(ImmutableMap.of(
+          K1, V1,
+          K3, V3
+      ));
+
+      Map<String, String> values = map.getAll(ImmutableList.of(K3, K2, K1));
+
+      assertThat(values, equalTo(ImmutableMap.of(
+          K3, V3,
+          K1, V1
+      )));
+      assertThat(ImmutableList.copyOf(values.keySet()), equalTo(ImmutableList.of(K3, K1)));
+    });
+  }
+
+  @Test
+  void getAllReturnsEmptyMapIfNoKeys() {
+    runTestWithView(database::createSnapshot, (map) -> {
+      Map<String, String> values = map.getAll(ImmutableList.of());
+
+      assertTrue(values.isEmpty());
+    });
+  }
+
   @Test
   void getShouldReturnSuccessfullyPutEmptyValue() {
     runTestWithView(database::createFork, (map) -> {
@@ -297,6 +333,31 @@ class MapIndexProxyIntegrationTest
     });
   }
 
+  @Test
+  void removeAllSuccessfullyPutValues() {
+    runTestWithView(database::createFork, (map) -> {
+      map.putAll(ImmutableMap.of(
+          K1, V1,
+          K2, V2,
+          K3, V3
+      ));
+
+      map.removeAll(ImmutableList.of(K1, K3));
+
+      assertFalse(map.containsKey(K1));
+      assertThat(map.get(K2), equalTo(V2));
+      assertFalse(map.containsKey(K3));
+    });
+  }
+
+  @Test
+  void removeAllShouldFailWithSnapshot() {
+    runTestWithView(database::createSnapshot, (map) -> {
+      assertThrows(UnsupportedOperationException.class,
+          () -> map.removeAll(ImmutableList.of(K1)));
+    });
+  }
+
   @Test
   void keysShouldReturnEmptyIterIfNoEntries() {
     runTestWithView(database::createSnapshot, (map) -> {
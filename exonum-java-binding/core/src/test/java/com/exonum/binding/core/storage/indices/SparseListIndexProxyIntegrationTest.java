@@ -0,0 +1,196 @@
+/*
+ * Copyright 2019 The Exonum Team
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+package com.exonum.binding.core.storage.indices;
+
+import static com.exonum.binding.core.storage.indices.TestStorageItems.V1;
+import static com.exonum.binding.core.storage.indices.TestStorageItems.V2;
+import static com.exonum.binding.core.storage.indices.TestStorageItems.V3;
+import static java.util.stream.Collectors.toList;
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+
+import com.exonum.binding.common.serialization.StandardSerializers;
+import com.exonum.binding.core.proxy.Cleaner;
+import com.exonum.binding.core.storage.database.View;
+import com.google.common.collect.ImmutableList;
+import java.util.List;
+import java.util.function.Consumer;
+import java.util.function.Function;
+import org.junit.jupiter.api.Test;
+
+class SparseListIndexProxyIntegrationTest
+    extends BaseIndexProxyTestable<SparseListIndexProxy<String>> {
+
+  private static final String LIST_NAME = "test_sparse_list";
+
+  @Test
+  void newListIsEmpty() {
+    runTestWithView(database::createSnapshot, (list) -> {
+      assertThat(list.isEmpty()).isTrue();
+      assertThat(list.size()).isZero();
+      assertThat(list.capacity()).isZero();
+      assertThat(list.get(0)).isEmpty();
+    });
+  }
+
+  @Test
+  void addElements() {
+    runTestWithView(database::createFork, (list) -> {
+      list.add(V1);
+      list.add(V2);
+
+      assertThat(list.isEmpty()).isFalse();
+      assertThat(list.size()).isEqualTo(2);
+      assertThat(list.capacity()).isEqualTo(2);
+      assertThat(list.get(0)).hasValue(V1);
+      assertThat(list.get(1)).hasValue(V2);
+    });
+  }
+
+  @Test
+  void addFailsIfSnapshot() {
+    runTestWithView(database::createSnapshot,
+        (list) -> assertThrows(UnsupportedOperationException.class, () -> list.add(V1)));
+  }
+
+  @Test
+  void removeLeavesGap() {
+    runTestWithView(database::createFork, (list) -> {
+      list.add(V1);
+      list.add(V2);
+      list.add(V3);
+
+      assertThat(list.remove(1)).hasValue(V2);
+
+      assertThat(list.get(1)).isEmpty();
+      assertThat(list.get(2)).hasValue(V3);
+      assertThat(list.size()).isEqualTo(2);
+      assertThat(list.capacity()).isEqualTo(3);
+    });
+  }
+
+  @Test
+  void removeAbsentElement() {
+    runTestWithView(database::createFork, (list) -> {
+      list.add(V1);
+
+      assertThat(list.remove(5)).isEmpty();
+      assertThat(list.size()).isEqualTo(1);
+    });
+  }
+
+  @Test
+  void removeFailsIfSnapshot() {
+    runTestWithView(database::createSnapshot,
+        (list) -> assertThrows(UnsupportedOperationException.class, () -> list.remove(0)));
+  }
+
+  @Test
+  void setFillsGap() {
+    runTestWithView(database::createFork, (list) -> {
+      list.add(V1);
+      list.add(V2);
+      list.remove(0);
+
+      list.set(0, V3);
+
+      assertThat(list.get(0)).hasValue(V3);
+      assertThat(list.size()).isEqualTo(2);
+    });
+  }
+
+  @Test
+  void setFailsIfIndexNotLessThanCapacity() {
+    runTestWithView(database::createFork, (list) -> {
+      list.add(V1);
+
+      assertThrows(IndexOutOfBoundsException.class, () -> list.set(1, V2));
+    });
+  }
+
+  @Test
+  void clearResetsCapacity() {
+    runTestWithView(database::createFork, (list) -> {
+      list.add(V1);
+      list.add(V2);
+
+      list.clear();
+
+      assertThat(list.isEmpty()).isTrue();
+      assertThat(list.capacity()).isZero();
+    });
+  }
+
+  @Test
+  void iteratorsSkipGaps() {
+    runTestWithView(database::createFork, (list) -> {
+      list.add(V1);
+      list.add(V2);
+      list.add(V3);
+      list.remove(1);
+
+      List<Long> indices = ImmutableList.copyOf(list.indices());
+      assertThat(indices).containsExactly(0L, 2L);
+
+      List<String> elements = list.stream().collect(toList());
+      assertThat(elements).containsExactly(V1, V3);
+    });
+  }
+
+  /**
+   * Creates a view, a sparse list index and runs a test against the view and the list.
+   * Automatically closes the view and the list.
+   *
+   * @param viewFactory a function creating a database view
+   * @param listTest a test to run. Receives the created list as an argument.
+   */
+  private static void runTestWithView(Function<Cleaner, View> viewFactory,
+      Consumer<SparseListIndexProxy<String>> listTest) {
+    IndicesTests.runTestWithView(
+        viewFactory,
+        LIST_NAME,
+        SparseListIndexProxy::newInstance,
+        (view, list) -> listTest.accept(list)
+    );
+  }
+
+  @Override
+  SparseListIndexProxy<String> create(String name, View view) {
+    return SparseListIndexProxy.newInstance(name, view, StandardSerializers.string());
+  }
+
+  @Override
+  SparseListIndexProxy<String> createInGroup(String groupName, byte[] idInGroup, View view) {
+    return SparseListIndexProxy.newInGroupUnsafe(groupName, idInGroup, view,
+        StandardSerializers.string());
+  }
+
+  @Override
+  StorageIndex createOfOtherType(String name, View view) {
+    return ListIndexProxy.newInstance(name, view, StandardSerializers.string());
+  }
+
+  @Override
+  Object getAnyElement(SparseListIndexProxy<String> index) {
+    return index.get(0);
+  }
+
+  @Override
+  void update(SparseListIndexProxy<String> index) {
+    index.add(V1);
+  }
+}
@@ -18,12 +18,20 @@ package com.exonum.binding.fakeservice;
 
 import com.exonum.binding.core.runtime.ServiceInstanceSpec;
 import com.exonum.binding.core.service.AbstractService;
+import com.exonum.binding.core.service.Configurable;
+import com.exonum.binding.core.service.Configuration;
 import com.exonum.binding.core.service.Node;
+import com.exonum.binding.core.storage.database.Fork;
 import com.exonum.binding.core.storage.database.View;
+import com.exonum.binding.fakeservice.Transactions.PutTransactionArgs;
 import com.google.inject.Inject;
 import io.vertx.ext.web.Router;
 
-final class FakeService extends AbstractService {
+/**
+ * A fake service. Its configuration is an entry to put in the test map, in the same format
+ * as the arguments of the put transaction.
+ */
+final class FakeService extends AbstractService implements Configurable {
 
   @Inject
   FakeService(ServiceInstanceSpec instanceSpec) {
@@ -36,6 +44,18 @@ final class FakeService extends AbstractService {
     return new FakeSchema(name, view);
   }
 
+  @Override
+  public void verifyConfiguration(Fork fork, Configuration configuration) {
+    configuration.getAsMessage(PutTransactionArgs.class);
+  }
+
+  @Override
+  public void applyConfiguration(Fork fork, Configuration configuration) {
+    PutTransactionArgs entry = configuration.getAsMessage(PutTransactionArgs.class);
+    createDataSchema(fork).testMap()
+        .put(entry.getKey(), entry.getValue());
+  }
+
   @Override
   public void createPublicApiHandlers(Node node, Router router) {
     // No handlers
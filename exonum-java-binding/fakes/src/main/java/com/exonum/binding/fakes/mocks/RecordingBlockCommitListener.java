@@ -0,0 +1,48 @@
+/*
+ * Copyright 2019 The Exonum Team
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+package com.exonum.binding.fakes.mocks;
+
+import com.exonum.binding.common.hash.HashCode;
+import com.exonum.binding.core.service.BlockCommitListener;
+import java.util.concurrent.BlockingQueue;
+import java.util.concurrent.LinkedBlockingQueue;
+import java.util.concurrent.TimeUnit;
+
+/**
+ * A block commit listener recording the heights of the committed blocks.
+ */
+@SuppressWarnings("unused") // Used in native code
+public final class RecordingBlockCommitListener implements BlockCommitListener {
+
+  private final BlockingQueue<Long> heights = new LinkedBlockingQueue<>();
+
+  @Override
+  public void onBlockCommitted(long height, HashCode blockHash) {
+    heights.add(height);
+  }
+
+  /**
+   * Returns the height of the next recorded block, waiting up to the given timeout
+   * for the listener to be notified; or -1 if it is not notified in time.
+   *
+   * @param timeoutMillis the maximum time to wait, in milliseconds
+   */
+  public long pollHeight(long timeoutMillis) throws InterruptedException {
+    Long height = heights.poll(timeoutMillis, TimeUnit.MILLISECONDS);
+    return height == null ? -1 : height;
+  }
+}
@@ -0,0 +1,149 @@
+/*
+ * Copyright 2019 The Exonum Team
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+
+package com.exonum.binding.core.runtime;
+
+import static com.exonum.binding.app.ServiceRuntimeBootstrap.DEPENDENCY_REFERENCE_CLASSES;
+import static com.exonum.binding.common.serialization.StandardSerializers.string;
+import static com.exonum.binding.core.runtime.ServiceWrapper.APPLY_CONFIGURATION_TX_ID;
+import static com.exonum.binding.core.runtime.ServiceWrapper.CONFIGURE_INTERFACE_NAME;
+import static com.exonum.binding.core.runtime.ServiceWrapper.DEFAULT_INTERFACE_NAME;
+import static com.exonum.binding.test.TestArtifactInfo.ARTIFACT_DIR;
+import static com.exonum.binding.test.TestArtifactInfo.ARTIFACT_FILENAME;
+import static com.exonum.binding.test.TestArtifactInfo.ARTIFACT_ID;
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.mockito.Mockito.mock;
+
+import com.exonum.binding.core.proxy.Cleaner;
+import com.exonum.binding.core.runtime.ServiceRuntimeProtos.BlockchainCaller;
+import com.exonum.binding.core.runtime.ServiceRuntimeProtos.ExecutionContext;
+import com.exonum.binding.core.service.Node;
+import com.exonum.binding.core.storage.database.Fork;
+import com.exonum.binding.core.storage.database.TemporaryDb;
+import com.exonum.binding.core.storage.indices.MapIndex;
+import com.exonum.binding.core.storage.indices.MapIndexProxy;
+import com.exonum.binding.fakeservice.Transactions.PutTransactionArgs;
+import com.exonum.binding.test.RequiresNativeLibrary;
+import com.google.inject.Guice;
+import com.google.inject.Module;
+import com.google.protobuf.ByteString;
+import org.junit.jupiter.api.AfterEach;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.Test;
+
+/**
+ * Tests the service runtime adapter and the service runtime with a real service
+ * from the fake service artifact.
+ */
+@RequiresNativeLibrary
+class ServiceRuntimeAdapterIntegrationTest {
+
+  private static final String SERVICE_NAME = "service";
+  private static final int SERVICE_ID = 100;
+  private static final ServiceInstanceSpec INSTANCE_SPEC = ServiceInstanceSpec
+      .newInstance(SERVICE_NAME, SERVICE_ID, ARTIFACT_ID);
+  /** The id of the put transaction of the fake service. */
+  private static final int PUT_TX_ID = 0;
+  // Set 0 as a server port so that a random free port is assigned
+  private static final int SERVER_PORT = 0;
+
+  private ServiceRuntimeAdapter runtimeAdapter;
+  private ServiceRuntime serviceRuntime;
+
+  @BeforeEach
+  void setUp() throws ServiceLoadingException {
+    Module frameworkModule = new FrameworkModule(ARTIFACT_DIR, SERVER_PORT,
+        DEPENDENCY_REFERENCE_CLASSES);
+    runtimeAdapter = Guice.createInjector(frameworkModule)
+        .getInstance(ServiceRuntimeAdapter.class);
+    serviceRuntime = runtimeAdapter.getServiceRuntime();
+    serviceRuntime.initialize(mock(Node.class));
+    serviceRuntime.deployArtifact(ARTIFACT_ID, ARTIFACT_FILENAME);
+  }
+
+  @AfterEach
+  void tearDown() throws InterruptedException {
+    runtimeAdapter.shutdown();
+  }
+
+  @Test
+  void executeTransactionByBlockchain() throws Exception {
+    try (TemporaryDb database = TemporaryDb.newInstance();
+        Cleaner cleaner = new Cleaner()) {
+      Fork fork = database.createFork(cleaner);
+      startService(fork);
+
+      String key = "key";
+      String value = "value";
+      byte[] executionContext = blockchainCallContext(DEFAULT_INTERFACE_NAME, PUT_TX_ID,
+          putArguments(key, value));
+
+      runtimeAdapter.executeTransaction(executionContext, fork.getViewNativeHandle());
+
+      // Check the fake service transaction has been executed
+      assertThat(testMap(fork).get(key)).isEqualTo(value);
+    }
+  }
+
+  @Test
+  void applyConfigurationByBlockchain() throws Exception {
+    try (TemporaryDb database = TemporaryDb.newInstance();
+        Cleaner cleaner = new Cleaner()) {
+      Fork fork = database.createFork(cleaner);
+      startService(fork);
+
+      String key = "config-key";
+      String value = "config-value";
+      byte[] executionContext = blockchainCallContext(CONFIGURE_INTERFACE_NAME,
+          APPLY_CONFIGURATION_TX_ID, putArguments(key, value));
+
+      runtimeAdapter.executeTransaction(executionContext, fork.getViewNativeHandle());
+
+      // Check the fake service configuration has been applied
+      assertThat(testMap(fork).get(key)).isEqualTo(value);
+    }
+  }
+
+  private void startService(Fork fork) {
+    serviceRuntime.startAddingService(fork, INSTANCE_SPEC, new byte[0]);
+    serviceRuntime.commitService(INSTANCE_SPEC);
+  }
+
+  private static byte[] putArguments(String key, String value) {
+    return PutTransactionArgs.newBuilder()
+        .setKey(key)
+        .setValue(value)
+        .build()
+        .toByteArray();
+  }
+
+  private static byte[] blockchainCallContext(String interfaceName, int methodId,
+      byte[] arguments) {
+    return ExecutionContext.newBuilder()
+        .setServiceId(SERVICE_ID)
+        .setInterfaceName(interfaceName)
+        .setMethodId(methodId)
+        .setArguments(ByteString.copyFrom(arguments))
+        .setBlockchain(BlockchainCaller.getDefaultInstance())
+        .build()
+        .toByteArray();
+  }
+
+  private static MapIndex<String, String> testMap(Fork fork) {
+    return MapIndexProxy.newInstance(SERVICE_NAME + ".test-map", fork, string(), string());
+  }
+}
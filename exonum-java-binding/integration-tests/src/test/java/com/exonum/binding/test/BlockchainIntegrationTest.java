@@ -435,6 +435,11 @@ class BlockchainIntegrationTest {
         assertThat(transactionPool).isEmpty();
       });
     }
+
+    @Test
+    void getTransactionPoolSize() {
+      testKitTest((blockchain) -> assertThat(blockchain.getTransactionPoolSize()).isZero());
+    }
   }
 
   private void testKitTest(Consumer<Blockchain> test) {
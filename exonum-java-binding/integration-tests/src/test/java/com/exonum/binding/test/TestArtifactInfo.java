@@ -23,12 +23,12 @@ import com.google.common.base.Strings;
 import java.nio.file.Path;
 import java.nio.file.Paths;
 
-final class TestArtifactInfo {
+public final class TestArtifactInfo {
 
-  static final Path ARTIFACT_DIR = Paths.get(getRequiredProperty("it.artifactsDir"));
+  public static final Path ARTIFACT_DIR = Paths.get(getRequiredProperty("it.artifactsDir"));
   private static final String ARTIFACT_NAME = getRequiredProperty("it.artifactName");
-  static final ServiceArtifactId ARTIFACT_ID = ServiceArtifactId.newJavaId(ARTIFACT_NAME);
-  static final String ARTIFACT_FILENAME = getRequiredProperty("it.artifactFilename");
+  public static final ServiceArtifactId ARTIFACT_ID = ServiceArtifactId.newJavaId(ARTIFACT_NAME);
+  public static final String ARTIFACT_FILENAME = getRequiredProperty("it.artifactFilename");
 
   private static String getRequiredProperty(String key) {
     String property = System.getProperty(key);
@@ -21,6 +21,7 @@ import static com.exonum.binding.qaservice.ApiController.QaPaths.COUNTER_ID_PARA
 import static com.exonum.binding.qaservice.ApiController.QaPaths.GET_CONSENSUS_CONFIGURATION_PATH;
 import static com.exonum.binding.qaservice.ApiController.QaPaths.GET_COUNTER_PATH;
 import static com.exonum.binding.qaservice.ApiController.QaPaths.SUBMIT_INCREMENT_COUNTER_TX_PATH;
+import static com.exonum.binding.qaservice.ApiController.QaPaths.SUBMIT_TO_UNKNOWN_SERVICE_PATH;
 import static com.exonum.binding.qaservice.ApiController.QaPaths.SUBMIT_UNKNOWN_TX_PATH;
 import static com.exonum.binding.qaservice.ApiController.QaPaths.TIME_PATH;
 import static com.exonum.binding.qaservice.ApiController.QaPaths.VALIDATORS_TIMES_PATH;
@@ -35,6 +36,7 @@ import static java.net.HttpURLConnection.HTTP_NOT_FOUND;
 
 import com.exonum.binding.common.crypto.PublicKey;
 import com.exonum.binding.common.hash.HashCode;
+import com.exonum.binding.core.service.TransactionSubmissionException;
 import com.exonum.core.messages.Blockchain.Config;
 import com.google.common.annotations.VisibleForTesting;
 import com.google.common.base.Strings;
@@ -75,6 +77,7 @@ final class ApiController {
         ImmutableMap.<String, Handler<RoutingContext>>builder()
             .put(SUBMIT_INCREMENT_COUNTER_TX_PATH, this::submitIncrementCounter)
             .put(SUBMIT_UNKNOWN_TX_PATH, this::submitUnknownTx)
+            .put(SUBMIT_TO_UNKNOWN_SERVICE_PATH, this::submitToUnknownService)
             .put(GET_COUNTER_PATH, this::getCounter)
             .put(GET_CONSENSUS_CONFIGURATION_PATH, this::getConsensusConfiguration)
             .put(TIME_PATH, this::getTime)
@@ -100,6 +103,15 @@ final class ApiController {
     replyTxSubmitted(rc, txHash);
   }
 
+  private void submitToUnknownService(RoutingContext rc) {
+    try {
+      HashCode txHash = service.submitToUnknownService();
+      replyTxSubmitted(rc, txHash);
+    } catch (TransactionSubmissionException e) {
+      replyTxSubmissionFailed(rc, e);
+    }
+  }
+
   private void getCounter(RoutingContext rc) {
     HashCode counterId = getRequiredParameter(rc.request(), COUNTER_ID_PARAM, HashCode::fromString);
 
@@ -152,6 +164,18 @@ final class ApiController {
         .end(String.valueOf(transactionHash));
   }
 
+  /**
+   * Replies with the code of the submission error, so that the clients can tell
+   * the reason of the failure.
+   */
+  private void replyTxSubmissionFailed(RoutingContext rc, TransactionSubmissionException e) {
+    logger.info("Transaction submission failed:", e);
+    rc.response()
+        .setStatusCode(HTTP_BAD_REQUEST)
+        .putHeader(CONTENT_TYPE, "text/plain")
+        .end(e.getErrorCode().name());
+  }
+
   private String transactionLocationPath(HashCode txHash) {
     return "/api/explorer/v1/transactions/" + txHash;
   }
@@ -216,6 +240,8 @@ final class ApiController {
     static final String SUBMIT_INCREMENT_COUNTER_TX_PATH = "/submit-increment-counter";
     @VisibleForTesting
     static final String SUBMIT_UNKNOWN_TX_PATH = "/submit-unknown";
+    @VisibleForTesting
+    static final String SUBMIT_TO_UNKNOWN_SERVICE_PATH = "/submit-to-unknown-service";
     static final String COUNTER_ID_PARAM = "counterId";
     static final String GET_COUNTER_PATH = "/counter/:" + COUNTER_ID_PARAM;
     @VisibleForTesting
@@ -46,6 +46,16 @@ public interface QaService extends Service, Configurable {
    */
   HashCode submitUnknownTx();
 
+  /**
+   * Creates a new self-signed transaction to a service instance that does not exist
+   * and submits it through the {@link com.exonum.binding.core.service.Node}.
+   * Enables testing of the submission errors of {@link Node#submitTransaction(RawTransaction)}.
+   *
+   * @throws com.exonum.binding.core.service.TransactionSubmissionException always,
+   *     as the service instance is unknown
+   */
+  HashCode submitToUnknownService();
+
   Optional<Counter> getValue(HashCode counterId);
 
   Config getConsensusConfiguration();
@@ -76,6 +76,9 @@ public final class QaServiceImpl extends AbstractService implements QaService {
   @VisibleForTesting
   static final String AFTER_COMMIT_COUNTER_NAME = "after_commit_counter";
 
+  /** The id of a service instance that does not exist in the network. */
+  private static final int UNKNOWN_SERVICE_ID = Short.MAX_VALUE;
+
   @Nullable
   private Node node;
 
@@ -154,6 +157,11 @@ public final class QaServiceImpl extends AbstractService implements QaService {
     return submitTransaction(UnknownTx.newRawTransaction(getId()));
   }
 
+  @Override
+  public HashCode submitToUnknownService() {
+    return submitTransaction(UnknownTx.newRawTransaction(UNKNOWN_SERVICE_ID));
+  }
+
   @Override
   @SuppressWarnings("ConstantConditions")  // Node is not null.
   public Optional<Counter> getValue(HashCode counterId) {
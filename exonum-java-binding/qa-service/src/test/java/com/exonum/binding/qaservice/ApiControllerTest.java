@@ -19,6 +19,7 @@ package com.exonum.binding.qaservice;
 import static com.exonum.binding.common.hash.Hashing.sha256;
 import static com.exonum.binding.qaservice.ApiController.QaPaths.GET_CONSENSUS_CONFIGURATION_PATH;
 import static com.exonum.binding.qaservice.ApiController.QaPaths.SUBMIT_INCREMENT_COUNTER_TX_PATH;
+import static com.exonum.binding.qaservice.ApiController.QaPaths.SUBMIT_TO_UNKNOWN_SERVICE_PATH;
 import static com.exonum.binding.qaservice.ApiController.QaPaths.SUBMIT_UNKNOWN_TX_PATH;
 import static com.exonum.binding.qaservice.ApiController.QaPaths.TIME_PATH;
 import static com.exonum.binding.qaservice.ApiController.QaPaths.VALIDATORS_TIMES_PATH;
@@ -38,6 +39,8 @@ import com.exonum.binding.common.crypto.PublicKey;
 import com.exonum.binding.common.hash.HashCode;
 import com.exonum.binding.common.serialization.json.JsonSerializer;
 import com.exonum.binding.core.blockchain.serialization.CoreTypeAdapterFactory;
+import com.exonum.binding.core.service.TransactionSubmissionException;
+import com.exonum.binding.core.service.TransactionSubmissionException.ErrorCode;
 import com.exonum.core.messages.Blockchain.Config;
 import com.google.common.collect.ImmutableMap;
 import com.google.gson.Gson;
@@ -167,6 +170,22 @@ class ApiControllerTest {
         .send(checkCreatedTransaction(context, EXPECTED_TX_HASH));
   }
 
+  @Test
+  void submitToUnknownService(VertxTestContext context) {
+    when(qaService.submitToUnknownService())
+        .thenThrow(new TransactionSubmissionException(ErrorCode.UNKNOWN_SERVICE, "Unknown"));
+
+    post(SUBMIT_TO_UNKNOWN_SERVICE_PATH)
+        .send(context.succeeding(response -> context.verify(() -> {
+          assertThat(response.statusCode())
+              .isEqualTo(HTTP_BAD_REQUEST);
+          assertThat(response.bodyAsString())
+              .isEqualTo(ErrorCode.UNKNOWN_SERVICE.name());
+
+          context.completeNow();
+        })));
+  }
+
   @Test
   void getCounter(VertxTestContext context) {
     HashCode id = HASH_1;
@@ -18,8 +18,10 @@ package com.exonum.binding.qaservice;
 
 import static com.exonum.binding.common.hash.Hashing.sha256;
 import static com.exonum.binding.common.serialization.json.JsonSerializer.json;
+import static com.exonum.binding.core.service.TransactionSubmissionException.ErrorCode.UNKNOWN_SERVICE;
 import static com.exonum.binding.qaservice.ApiController.QaPaths.GET_CONSENSUS_CONFIGURATION_PATH;
 import static com.exonum.binding.qaservice.ApiController.QaPaths.SUBMIT_INCREMENT_COUNTER_TX_PATH;
+import static com.exonum.binding.qaservice.ApiController.QaPaths.SUBMIT_TO_UNKNOWN_SERVICE_PATH;
 import static com.exonum.binding.qaservice.ApiController.QaPaths.SUBMIT_UNKNOWN_TX_PATH;
 import static com.exonum.binding.qaservice.ApiController.QaPaths.TIME_PATH;
 import static com.exonum.binding.qaservice.ApiController.QaPaths.VALIDATORS_TIMES_PATH;
@@ -31,6 +33,7 @@ import static com.exonum.binding.qaservice.QaServiceImpl.DEFAULT_COUNTER_NAME;
 import static com.exonum.binding.qaservice.TransactionMessages.createCreateCounterTx;
 import static com.exonum.binding.qaservice.TransactionMessages.createIncrementCounterTx;
 import static com.exonum.binding.qaservice.TransactionMessages.createUnknownTx;
+import static java.net.HttpURLConnection.HTTP_BAD_REQUEST;
 import static java.net.HttpURLConnection.HTTP_CREATED;
 import static java.net.HttpURLConnection.HTTP_NOT_FOUND;
 import static java.net.HttpURLConnection.HTTP_OK;
@@ -223,6 +226,18 @@ class QaServiceImplTest {
           })));
     }
 
+    @Test
+    void submitToUnknownService(VertxTestContext context) {
+      post(SUBMIT_TO_UNKNOWN_SERVICE_PATH)
+          .send(context.succeeding(response -> context.verify(() -> {
+            // Check the node rejected the transaction with the corresponding error code
+            assertThat(response.statusCode()).isEqualTo(HTTP_BAD_REQUEST);
+            assertThat(response.bodyAsString()).isEqualTo(UNKNOWN_SERVICE.name());
+
+            context.completeNow();
+          })));
+    }
+
     @Test
     void getValue(TestKit testKit, VertxTestContext context) {
       // Create a counter